@@ -1,3 +1,4 @@
+use core::cmp::Reverse;
 use core::fmt::{self, Debug, Formatter};
 use core::iter::{FusedIterator, TrustedLen};
 
@@ -26,6 +27,21 @@ pub struct StaticHeapDrainSorted<'a, T: Ord, const N: usize> {
   pub(crate) inner: &'a mut StaticHeap<T, N>,
 }
 
+/// A "consuming" iterator over the elements of a [`StaticVec`](crate::StaticVec) that lazily
+/// yields them in ascending order.
+///
+/// This struct is created by the [`into_sorted_iter`] method on
+/// [`StaticVec`](crate::StaticVec), and is backed by a min-heap (that is, a [`StaticHeap`] of
+/// [`Reverse`](core::cmp::Reverse)-wrapped elements) which is popped from as needed. See its
+/// documentation for more.
+///
+/// [`into_sorted_iter`]: ../struct.StaticVec.html#method.into_sorted_iter
+/// [`StaticHeap`]: struct.StaticHeap.html
+#[derive(Clone)]
+pub struct StaticVecIntoIterSorted<T, const N: usize> {
+  pub(crate) inner: StaticHeap<Reverse<T>, N>,
+}
+
 impl<T: Ord, const N: usize> Iterator for StaticHeapIntoIterSorted<T, N> {
   type Item = T;
 
@@ -72,6 +88,50 @@ impl<T: Debug, const N: usize> Debug for StaticHeapIntoIterSorted<T, N> {
   }
 }
 
+impl<T: Ord, const N: usize> Iterator for StaticVecIntoIterSorted<T, N> {
+  type Item = T;
+
+  #[inline(always)]
+  fn next(&mut self) -> Option<T> {
+    self.inner.pop().map(|Reverse(value)| value)
+  }
+
+  #[inline(always)]
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    let exact = self.inner.len();
+    (exact, Some(exact))
+  }
+
+  #[inline(always)]
+  fn count(self) -> usize {
+    self.len()
+  }
+}
+
+impl<T: Ord, const N: usize> ExactSizeIterator for StaticVecIntoIterSorted<T, N> {
+  #[inline(always)]
+  fn len(&self) -> usize {
+    self.inner.len()
+  }
+
+  #[inline(always)]
+  fn is_empty(&self) -> bool {
+    self.inner.is_empty()
+  }
+}
+
+impl<T: Ord, const N: usize> FusedIterator for StaticVecIntoIterSorted<T, N> {}
+unsafe impl<T: Ord, const N: usize> TrustedLen for StaticVecIntoIterSorted<T, N> {}
+
+impl<T: Debug, const N: usize> Debug for StaticVecIntoIterSorted<T, N> {
+  #[inline(always)]
+  fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+    f.debug_tuple("StaticVecIntoIterSorted")
+      .field(&self.inner.data.as_slice())
+      .finish()
+  }
+}
+
 impl<T: Ord, const N: usize> Iterator for StaticHeapDrainSorted<'_, T, N> {
   type Item = T;
 
@@ -5,7 +5,9 @@ use serde::{Deserialize, Serialize};
 
 use self::heap_helpers::StaticHeapHole;
 pub use self::heap_helpers::StaticHeapPeekMut;
-pub use self::heap_iterators::{StaticHeapDrainSorted, StaticHeapIntoIterSorted};
+pub use self::heap_iterators::{
+  StaticHeapDrainSorted, StaticHeapIntoIterSorted, StaticVecIntoIterSorted,
+};
 use crate::iterators::{StaticVecDrain, StaticVecIterConst, StaticVecIterMut};
 use crate::StaticVec;
 
@@ -121,6 +123,59 @@ impl<T: Ord, const N: usize> StaticHeap<T, N> {
     }
   }
 
+  /// Creates a StaticHeap directly from a StaticVec that is already sorted in descending order,
+  /// which is trivially a valid max-heap layout. This has `O(1)` time complexity (as opposed to the
+  /// `O(n)` rebuild performed by the general `From<StaticVec<T, N>>` impl), as the existing
+  /// contents of `vec` are used as-is.
+  ///
+  /// Whether or not `vec` is actually sorted in descending order is checked with a
+  /// `debug_assert!`. In release builds, passing an unsorted `vec` will not result in undefined
+  /// behavior, but will leave the heap in a logically inconsistent state.
+  ///
+  /// # Examples
+  ///
+  /// Basic usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let heap = StaticHeap::from_sorted_descending(staticvec![7, 5, 5, 2, 1]);
+  /// assert_eq!(heap.into_iter_sorted().collect::<StaticVec<i32, 5>>(), [7, 5, 5, 2, 1]);
+  /// ```
+  #[inline(always)]
+  pub fn from_sorted_descending(vec: StaticVec<T, N>) -> StaticHeap<T, N> {
+    debug_assert!(
+      vec.windows(2).all(|w| w[0] >= w[1]),
+      "`StaticHeap::from_sorted_descending` was called with an unsorted `vec`!"
+    );
+    StaticHeap { data: vec }
+  }
+
+  /// Creates a StaticHeap from a StaticVec that is already sorted in ascending order. An
+  /// ascending layout does not satisfy the max-heap property, so `vec` is reversed in place
+  /// first, which is still cheaper than the full rebuild performed by the general
+  /// `From<StaticVec<T, N>>` impl as no comparisons are needed.
+  ///
+  /// Whether or not `vec` is actually sorted in ascending order is checked with a
+  /// `debug_assert!`. In release builds, passing an unsorted `vec` will not result in undefined
+  /// behavior, but will leave the heap in a logically inconsistent state.
+  ///
+  /// # Examples
+  ///
+  /// Basic usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let heap = StaticHeap::from_sorted_ascending(staticvec![1, 2, 5, 5, 7]);
+  /// assert_eq!(heap.into_iter_sorted().collect::<StaticVec<i32, 5>>(), [7, 5, 5, 2, 1]);
+  /// ```
+  #[inline(always)]
+  pub fn from_sorted_ascending(mut vec: StaticVec<T, N>) -> StaticHeap<T, N> {
+    debug_assert!(
+      vec.windows(2).all(|w| w[0] <= w[1]),
+      "`StaticHeap::from_sorted_ascending` was called with an unsorted `vec`!"
+    );
+    vec.reverse();
+    StaticHeap { data: vec }
+  }
+
   /// Returns a mutable reference to the greatest item in the StaticHeap, or
   /// `None` if it is empty.
   ///
@@ -436,6 +491,83 @@ impl<T: Ord, const N: usize> StaticHeap<T, N> {
     self.rebuild();
   }
 
+  /// Copies and pushes all elements, if any, of a slice onto the StaticHeap, while maintaining the
+  /// heap property. If the slice has a length greater than the StaticHeap's remaining capacity, any
+  /// contents after that point are ignored.
+  /// Locally requires that `T` implements [`Copy`](core::marker::Copy) to avoid soundness issues.
+  ///
+  /// # Examples
+  ///
+  /// Basic usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let mut heap = StaticHeap::<i32, 6>::from([3, 1]);
+  /// heap.extend_from_slice(&[5, 2, 4, 6, 7]);
+  /// assert_eq!(heap.len(), 6);
+  /// assert_eq!(heap.into_sorted_staticvec(), [1, 2, 3, 4, 5, 6]);
+  /// ```
+  #[inline]
+  pub fn extend_from_slice(&mut self, values: &[T])
+  where T: Copy {
+    let old_length = self.len();
+    self.data.extend_from_slice(values);
+    self.rebuild_tail(old_length);
+  }
+
+  /// Moves `self.remaining_capacity()` (or as many as available) items from the StaticVec `vec`
+  /// into the StaticHeap, while maintaining the heap property. The moved items (if any) will no
+  /// longer exist in `vec` afterwards, exactly as with [`StaticVec::append`].
+  ///
+  /// Unlike [`append`](crate::StaticHeap::append), this does not always rebuild the entire heap,
+  /// and will instead sift each new item up individually when that is likely to be cheaper.
+  ///
+  /// # Examples
+  ///
+  /// Basic usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let mut heap = StaticHeap::<i32, 6>::from([3, 1]);
+  /// let mut v = staticvec![5, 2, 4, 6, 7];
+  /// heap.append_staticvec(&mut v);
+  /// assert_eq!(v, [7]);
+  /// assert_eq!(heap.into_sorted_staticvec(), [1, 2, 3, 4, 5, 6]);
+  /// ```
+  #[inline]
+  pub fn append_staticvec<const N2: usize>(&mut self, vec: &mut StaticVec<T, N2>) {
+    let old_length = self.len();
+    self.data.append(vec);
+    self.rebuild_tail(old_length);
+  }
+
+  /// Restores the heap property after items have been pushed onto the end of the underlying
+  /// StaticVec starting at index `start`, either by rebuilding the whole heap or by sifting each
+  /// new item up, whichever is estimated to be cheaper.
+  #[inline]
+  fn rebuild_tail(&mut self, start: usize) {
+    let end = self.len();
+    if start == end {
+      return;
+    }
+    if Self::better_to_rebuild(start, end - start) {
+      self.rebuild();
+    } else {
+      for i in start..end {
+        self.sift_up(0, i);
+      }
+    }
+  }
+
+  /// The same heuristic used by `std::collections::BinaryHeap`: rebuilding costs about
+  /// `2 * (len + added)` comparisons, while sifting each added item up costs about
+  /// `added * log2(len)` comparisons in the worst case.
+  #[inline(always)]
+  const fn better_to_rebuild(len: usize, added: usize) -> bool {
+    if len < 2 {
+      return true;
+    }
+    2 * (len + added) < added * (usize::BITS - 1 - len.leading_zeros()) as usize
+  }
+
   /// Returns an iterator which retrieves elements in heap order.
   /// The retrieved elements are removed from the original heap.
   /// The remaining elements will be removed on drop in heap order.
@@ -4,7 +4,7 @@ use core::iter::{FusedIterator, TrustedLen, TrustedRandomAccessNoCoerce};
 use core::marker::{PhantomData, Send, Sync};
 use core::mem::{replace, size_of, MaybeUninit};
 use core::ptr;
-use core::slice::{from_raw_parts, from_raw_parts_mut};
+use core::slice::{from_raw_parts, from_raw_parts_mut, RChunksExact};
 
 use crate::utils::{distance_between, zst_ptr_add, zst_ptr_add_mut};
 use crate::StaticVec;
@@ -54,6 +54,29 @@ pub struct StaticVecDrain<'a, T: 'a, const N: usize> {
   pub(crate) vec: *mut StaticVec<T, N>,
 }
 
+/// An iterator over the inhabited area of a [`StaticVec`] in non-overlapping `&[T; C]` chunks,
+/// starting from the back. Instances of
+/// [`StaticVecArrayRChunks`](crate::iterators::StaticVecArrayRChunks) are created by the
+/// [`rarray_chunks`](crate::StaticVec::rarray_chunks) method on [`StaticVec`](crate::StaticVec).
+/// When the length of the [`StaticVec`] is not evenly divisible by `C`, the leftover elements at
+/// the front can be accessed through [`remainder`](StaticVecArrayRChunks::remainder).
+pub struct StaticVecArrayRChunks<'a, T: 'a, const C: usize> {
+  pub(crate) iter: RChunksExact<'a, T>,
+}
+
+/// An iterator over overlapping (or non-overlapping, depending on the step) windows of a fixed
+/// size into the inhabited area of a [`StaticVec`], advancing by a fixed step each time.
+/// Instances of [`StaticVecWindowsStep`](crate::iterators::StaticVecWindowsStep) are created by
+/// the [`windows_step`](crate::StaticVec::windows_step) method on [`StaticVec`](crate::StaticVec).
+/// Any trailing elements that are not part of a full window are not yielded.
+pub struct StaticVecWindowsStep<'a, T: 'a> {
+  pub(crate) slice: &'a [T],
+  pub(crate) start: usize,
+  pub(crate) remaining: usize,
+  pub(crate) size: usize,
+  pub(crate) step: usize,
+}
+
 /// A "splicing" iterator, analogous to [`vec::Splice`](alloc::vec::Splice).
 /// Instances of [`StaticVecSplice`](crate::iterators::StaticVecSplice) are created
 /// by the [`splice`](crate::StaticVec::splice) method on [`StaticVec`](crate::StaticVec).
@@ -89,6 +112,21 @@ impl<'a, T: 'a, const N: usize> StaticVecIterConst<'a, T, N> {
 
   /// Returns an immutable slice consisting of the elements in the range between the iterator's
   /// `start` and `end` pointers.
+  ///
+  /// As with [`Iter::as_slice`](core::slice::Iter::as_slice), the returned slice borrows from the
+  /// original [`StaticVec`] with the full `'a` lifetime rather than from the iterator itself, so it
+  /// remains valid after the iterator has been advanced further or dropped.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let v = staticvec![1, 2, 3, 4];
+  /// let mut it = v.iter();
+  /// it.next();
+  /// let s = it.as_slice();
+  /// drop(it);
+  /// assert_eq!(s, &[2, 3, 4]);
+  /// ```
   #[inline(always)]
   pub const fn as_slice(&self) -> &'a [T] {
     // Safety: `start` is never null. This function will "at worst" return an empty slice.
@@ -293,6 +331,27 @@ impl<'a, T: 'a, const N: usize> StaticVecIterMut<'a, T, N> {
     // Safety: `start` is never null. This function will "at worst" return an empty slice.
     unsafe { from_raw_parts(self.start, distance_between(self.end, self.start)) }
   }
+
+  /// Consumes the iterator, returning a mutable slice consisting of the elements in the range
+  /// between its `start` and `end` pointers, with the full `'a` lifetime of the original borrow.
+  /// This is analogous to [`IterMut::into_slice`](core::slice::IterMut::into_slice).
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let mut v = staticvec![1, 2, 3, 4];
+  /// let mut it = v.iter_mut();
+  /// it.next();
+  /// let s = it.into_slice();
+  /// s[0] = 9;
+  /// assert_eq!(v, [1, 9, 3, 4]);
+  /// ```
+  #[inline(always)]
+  pub const fn into_slice(self) -> &'a mut [T] {
+    // Safety: `start` is never null, and since the iterator is consumed here nothing else can
+    // alias the returned slice. This function will "at worst" return an empty slice.
+    unsafe { from_raw_parts_mut(self.start, distance_between(self.end, self.start)) }
+  }
 }
 
 impl<'a, T: 'a, const N: usize> Iterator for StaticVecIterMut<'a, T, N> {
@@ -467,7 +526,8 @@ impl<T, const N: usize> StaticVecIntoIter<T, N> {
   }
 
   /// Returns an immutable slice consisting of the elements in the range between the iterator's
-  /// `start` and `end` indices.
+  /// `start` and `end` indices, which are exactly the elements not yet yielded by either `next` or
+  /// `next_back` (including for zero-sized types).
   #[inline(always)]
   pub const fn as_slice(&self) -> &[T] {
     // Safety: `start_at` is never null. This function will "at worst" return an empty slice.
@@ -481,7 +541,8 @@ impl<T, const N: usize> StaticVecIntoIter<T, N> {
   }
 
   /// Returns a mutable slice consisting of the elements in the range between the iterator's
-  /// `start` and `end` indices.
+  /// `start` and `end` indices, which are exactly the elements not yet yielded by either `next` or
+  /// `next_back` (including for zero-sized types).
   #[inline(always)]
   pub const fn as_mut_slice(&mut self) -> &mut [T] {
     // Safety: `start_at` is never null. This function will "at worst" return an empty slice.
@@ -663,6 +724,20 @@ impl<T: Clone, const N: usize> Clone for StaticVecIntoIter<T, N> {
   }
 }
 
+impl<T, const N: usize> Default for StaticVecIntoIter<T, N> {
+  /// Returns an empty [`StaticVecIntoIter`](crate::iterators::StaticVecIntoIter), which makes it
+  /// possible to use things like [`mem::take`](core::mem::take) with one.
+  #[inline(always)]
+  fn default() -> Self {
+    // `data` is never read from (or dropped) at all when `start` and `end` are equal.
+    Self {
+      start: 0,
+      end: 0,
+      data: MaybeUninit::uninit(),
+    }
+  }
+}
+
 impl<T: Debug, const N: usize> Debug for StaticVecIntoIter<T, N> {
   #[inline(always)]
   fn fmt(&self, f: &mut Formatter) -> fmt::Result {
@@ -878,6 +953,9 @@ impl<T, I: Iterator<Item = T> + DoubleEndedIterator, const N: usize> DoubleEnded
 }
 
 impl<T, I: Iterator<Item = T>, const N: usize> ExactSizeIterator for StaticVecSplice<T, I, N> {
+  // Note that the items "yielded" by a StaticVecSplice are specifically the elements being removed
+  // from the range, so its length is exactly the number of those remaining regardless of how many
+  // items `replace_with` still has (which are instead inserted when the StaticVecSplice is dropped).
   #[inline(always)]
   fn len(&self) -> usize {
     self.end - self.start
@@ -933,3 +1011,215 @@ impl<T, I: Iterator<Item = T>, const N: usize> Drop for StaticVecSplice<T, I, N>
     }
   }
 }
+
+impl<'a, T: 'a> StaticVecWindowsStep<'a, T> {
+  #[inline(always)]
+  pub(crate) fn new(slice: &'a [T], size: usize, step: usize) -> Self {
+    let length = slice.len();
+    Self {
+      slice,
+      start: 0,
+      remaining: if length < size {
+        0
+      } else {
+        (length - size) / step + 1
+      },
+      size,
+      step,
+    }
+  }
+
+  /// Returns the window whose start is `index` windows after the current front window.
+  #[inline(always)]
+  fn window_at(&self, index: usize) -> &'a [T] {
+    let start = self.start + index * self.step;
+    // Safety: `index` is always less than `self.remaining` when this is called, which was
+    // calculated such that every such window lies fully within `self.slice`.
+    unsafe { self.slice.get_unchecked(start..start + self.size) }
+  }
+}
+
+impl<'a, T: 'a> Iterator for StaticVecWindowsStep<'a, T> {
+  type Item = &'a [T];
+
+  #[inline]
+  fn next(&mut self) -> Option<&'a [T]> {
+    if self.remaining == 0 {
+      return None;
+    }
+    let res = self.window_at(0);
+    self.start += self.step;
+    self.remaining -= 1;
+    Some(res)
+  }
+
+  #[inline(always)]
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    (self.remaining, Some(self.remaining))
+  }
+
+  #[inline(always)]
+  fn count(self) -> usize {
+    self.remaining
+  }
+
+  #[inline]
+  fn nth(&mut self, n: usize) -> Option<&'a [T]> {
+    if n >= self.remaining {
+      self.remaining = 0;
+      return None;
+    }
+    let res = self.window_at(n);
+    self.start += (n + 1) * self.step;
+    self.remaining -= n + 1;
+    Some(res)
+  }
+
+  #[inline(always)]
+  fn last(mut self) -> Option<&'a [T]> {
+    self.next_back()
+  }
+}
+
+impl<'a, T: 'a> DoubleEndedIterator for StaticVecWindowsStep<'a, T> {
+  #[inline]
+  fn next_back(&mut self) -> Option<&'a [T]> {
+    if self.remaining == 0 {
+      return None;
+    }
+    self.remaining -= 1;
+    Some(self.window_at(self.remaining))
+  }
+}
+
+impl<'a, T: 'a> ExactSizeIterator for StaticVecWindowsStep<'a, T> {
+  #[inline(always)]
+  fn len(&self) -> usize {
+    self.remaining
+  }
+
+  #[inline(always)]
+  fn is_empty(&self) -> bool {
+    self.remaining == 0
+  }
+}
+
+impl<'a, T: 'a> FusedIterator for StaticVecWindowsStep<'a, T> {}
+unsafe impl<'a, T: 'a> TrustedLen for StaticVecWindowsStep<'a, T> {}
+
+impl<'a, T: 'a> Clone for StaticVecWindowsStep<'a, T> {
+  #[inline(always)]
+  fn clone(&self) -> Self {
+    Self {
+      slice: self.slice,
+      start: self.start,
+      remaining: self.remaining,
+      size: self.size,
+      step: self.step,
+    }
+  }
+}
+
+impl<'a, T: 'a + Debug> Debug for StaticVecWindowsStep<'a, T> {
+  #[inline(always)]
+  fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+    f.debug_struct("StaticVecWindowsStep")
+      .field("slice", &self.slice)
+      .field("size", &self.size)
+      .field("step", &self.step)
+      .field("remaining", &self.remaining)
+      .finish()
+  }
+}
+
+impl<'a, T: 'a, const C: usize> StaticVecArrayRChunks<'a, T, C> {
+  /// Returns the elements at the front of the original [`StaticVec`] that were not included in
+  /// any chunk, which will always number exactly `len % C`.
+  #[inline(always)]
+  pub fn remainder(&self) -> &'a [T] {
+    self.iter.remainder()
+  }
+
+  /// Converts a slice returned by the inner [`RChunksExact`](core::slice::RChunksExact) into an
+  /// array reference.
+  #[inline(always)]
+  fn cast(chunk: &'a [T]) -> &'a [T; C] {
+    // Safety: every chunk yielded by `RChunksExact` has a length of exactly `C`.
+    unsafe { &*(chunk.as_ptr() as *const [T; C]) }
+  }
+}
+
+impl<'a, T: 'a, const C: usize> Iterator for StaticVecArrayRChunks<'a, T, C> {
+  type Item = &'a [T; C];
+
+  #[inline(always)]
+  fn next(&mut self) -> Option<&'a [T; C]> {
+    self.iter.next().map(Self::cast)
+  }
+
+  #[inline(always)]
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    self.iter.size_hint()
+  }
+
+  #[inline(always)]
+  fn count(self) -> usize {
+    self.iter.len()
+  }
+
+  #[inline(always)]
+  fn nth(&mut self, n: usize) -> Option<&'a [T; C]> {
+    self.iter.nth(n).map(Self::cast)
+  }
+
+  #[inline(always)]
+  fn last(self) -> Option<&'a [T; C]> {
+    self.iter.last().map(Self::cast)
+  }
+}
+
+impl<'a, T: 'a, const C: usize> DoubleEndedIterator for StaticVecArrayRChunks<'a, T, C> {
+  #[inline(always)]
+  fn next_back(&mut self) -> Option<&'a [T; C]> {
+    self.iter.next_back().map(Self::cast)
+  }
+
+  #[inline(always)]
+  fn nth_back(&mut self, n: usize) -> Option<&'a [T; C]> {
+    self.iter.nth_back(n).map(Self::cast)
+  }
+}
+
+impl<'a, T: 'a, const C: usize> ExactSizeIterator for StaticVecArrayRChunks<'a, T, C> {
+  #[inline(always)]
+  fn len(&self) -> usize {
+    self.iter.len()
+  }
+
+  #[inline(always)]
+  fn is_empty(&self) -> bool {
+    self.iter.is_empty()
+  }
+}
+
+impl<'a, T: 'a, const C: usize> FusedIterator for StaticVecArrayRChunks<'a, T, C> {}
+unsafe impl<'a, T: 'a, const C: usize> TrustedLen for StaticVecArrayRChunks<'a, T, C> {}
+
+impl<'a, T: 'a, const C: usize> Clone for StaticVecArrayRChunks<'a, T, C> {
+  #[inline(always)]
+  fn clone(&self) -> Self {
+    Self {
+      iter: self.iter.clone(),
+    }
+  }
+}
+
+impl<'a, T: 'a + Debug, const C: usize> Debug for StaticVecArrayRChunks<'a, T, C> {
+  #[inline(always)]
+  fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+    f.debug_struct("StaticVecArrayRChunks")
+      .field("iter", &self.iter)
+      .field("remainder", &self.iter.remainder())
+      .finish()
+  }
+}
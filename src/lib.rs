@@ -3,6 +3,8 @@
 //! - [`StaticVec::sorted`]
 //! - [`StaticVec::into_vec`] (and the corresponding [`Into`] impl)
 //! - [`StaticVec::from_vec`] (and the corresponding [`From`] impl)
+//! - [`StaticVec::try_from_vec`]
+//! - [`StaticVec::dedup_total`]
 //! - the implementation of the [`Read`](std::io::Read) trait for [`StaticVec`]
 //! - the implementation of the [`BufRead`](std::io::BufRead) trait for [`StaticVec`]
 //! - the implementation of the [`io::Write`](std::io::Write) trait for [`StaticVec`]
@@ -13,6 +15,13 @@
 //! - the implementation of [`Error`](std::error::Error) for [`StringError`]
 //! - the `bounds_to_string` function unique to this crate and implemented by several of the
 //!   iterators in it
+//!
+//! **Note:** fallible conversions such as [`StaticVec::try_from_slice`],
+//! [`StaticVec::try_from_vec`] and
+//! [`StaticString::try_from_staticvec`](crate::string::StaticString::try_from_staticvec) are
+//! provided as inherent methods rather than as implementations of [`TryFrom`], as the existing
+//! [`From`] implementations for the same source types already give StaticVec and StaticString
+//! (infallible) blanket [`TryFrom`] implementations for them.
 
 #![no_std]
 #![allow(
@@ -77,25 +86,31 @@
 )]
 #![cfg_attr(feature = "std", feature(read_buf))]
 
-use core::cmp::{Ord, PartialEq};
+use core::cmp::{Ord, Ordering, PartialEq, Reverse};
 use core::intrinsics::assume;
+use core::iter::{Enumerate, Product, Rev, StepBy, Sum};
 use core::marker::PhantomData;
 use core::mem::{self, size_of, MaybeUninit};
 use core::ops::{
   Add, Bound::Excluded, Bound::Included, Bound::Unbounded, Div, Mul, RangeBounds, Sub,
 };
 use core::ptr;
-use core::slice::{from_raw_parts, from_raw_parts_mut};
+use core::slice::{from_raw_parts, from_raw_parts_mut, SliceIndex};
 
 pub use crate::errors::{CapacityError, PushCapacityError};
 pub use crate::heap::{
   StaticHeap, StaticHeapDrainSorted, StaticHeapIntoIterSorted, StaticHeapPeekMut,
+  StaticVecIntoIterSorted,
 };
 pub use crate::iterators::{
-  StaticVecDrain, StaticVecIntoIter, StaticVecIterConst, StaticVecIterMut, StaticVecSplice,
+  StaticVecArrayRChunks, StaticVecDrain, StaticVecIntoIter, StaticVecIterConst, StaticVecIterMut,
+  StaticVecSplice, StaticVecWindowsStep,
 };
 pub use crate::string::{StaticString, StringError};
-use crate::utils::{const_min, quicksort_internal, reverse_copy, zst_ptr_add, zst_ptr_add_mut};
+use crate::utils::{
+  const_min, quicksort_internal, reverse_copy, zst_ptr_add, zst_ptr_add_mut, IntegerArithmetic,
+  MinCapacity, Pod, SameLayout,
+};
 
 #[cfg(any(feature = "std", rustdoc))]
 extern crate alloc;
@@ -106,6 +121,12 @@ use alloc::vec::Vec;
 #[cfg(feature = "std")]
 extern crate std;
 
+#[cfg(feature = "std")]
+use core::hash::Hash;
+
+#[cfg(feature = "std")]
+use std::collections::HashSet;
+
 mod errors;
 mod heap;
 mod iterators;
@@ -190,6 +211,28 @@ impl<T, const N: usize> StaticVec<T, N> {
     }
   }
 
+  /// A fallible version of [`new_from_slice`](crate::StaticVec::new_from_slice) that returns a
+  /// [`CapacityError`](crate::errors::CapacityError) instead of truncating. Locally requires that
+  /// `T` implements [`Copy`](core::marker::Copy) to avoid soundness issues.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let v = StaticVec::<i32, 4>::try_from_slice(&[1, 2, 3]);
+  /// assert_eq!(v, Ok(staticvec![1, 2, 3]));
+  /// let v2 = StaticVec::<i32, 2>::try_from_slice(&[1, 2, 3]);
+  /// assert_eq!(v2, Err(CapacityError::<2> {}));
+  /// ```
+  #[inline]
+  pub const fn try_from_slice(values: &[T]) -> Result<Self, CapacityError<N>>
+  where T: Copy {
+    if values.len() > N {
+      Err(CapacityError {})
+    } else {
+      Ok(Self::new_from_slice(values))
+    }
+  }
+
   /// Returns a new StaticVec instance filled with the contents, if any, of an array.
   /// If the array has a length greater than the StaticVec's declared capacity,
   /// any contents after that point are ignored.
@@ -341,6 +384,35 @@ impl<T, const N: usize> StaticVec<T, N> {
   /// ```
   pub const CAPACITY: usize = N;
 
+  /// Statically asserts that the constant `N` capacity of the StaticVec is at least `MIN`, causing
+  /// a *compile-time* error if it is not. This is useful for guarding capacity assumptions made in
+  /// generic code, given that a runtime `reserve`-style method makes no sense for a fixed-capacity
+  /// collection.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// fn needs_four<const N: usize>(v: &mut StaticVec<i32, N>) {
+  ///   StaticVec::<i32, N>::assert_capacity_at_least::<4>();
+  ///   v.extend_from_slice(&[1, 2, 3, 4]);
+  /// }
+  /// let mut v = StaticVec::<i32, 8>::new();
+  /// needs_four(&mut v);
+  /// assert_eq!(v, [1, 2, 3, 4]);
+  /// ```
+  /// Whereas this would give a compile-time error:
+  /// ```compile_fail
+  /// # use staticvec::*;
+  /// StaticVec::<i32, 3>::assert_capacity_at_least::<4>();
+  /// ```
+  #[inline(always)]
+  pub const fn assert_capacity_at_least<const MIN: usize>() {
+    // Referencing an associated const is what ensures this is checked during compilation
+    // regardless of whether or not the function is itself called in a const context, just as
+    // the assertion in `__new_from_const_str` is when used through `staticstring!`.
+    let () = MinCapacity::<N, MIN>::ASSERT;
+  }
+
   /// Returns the remaining capacity (which is to say, `self.capacity() - self.len()`) of the
   /// StaticVec.
   ///
@@ -420,6 +492,45 @@ impl<T, const N: usize> StaticVec<T, N> {
     self.length = new_len;
   }
 
+  /// A safe alternative to [`set_len`](crate::StaticVec::set_len) that changes the length of the
+  /// StaticVec to `new_len` while guaranteeing that every element within it is initialized. If
+  /// `new_len` is greater than the current length, the new slots are filled with clones of
+  /// `value` (with `value` itself being moved into the last one). If `new_len` is less than the
+  /// current length, the excess elements are dropped, as with
+  /// [`truncate`](crate::StaticVec::truncate).
+  ///
+  /// # Panics
+  ///
+  /// Panics if `new_len` is greater than the StaticVec's declared capacity.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let mut v = StaticVec::<i32, 6>::from([1, 2]);
+  /// v.set_len_filling(5, 0);
+  /// assert_eq!(v, [1, 2, 0, 0, 0]);
+  /// v.set_len_filling(1, 0);
+  /// assert_eq!(v, [1]);
+  /// ```
+  #[inline]
+  pub fn set_len_filling(&mut self, new_len: usize, value: T)
+  where T: Clone {
+    assert!(
+      new_len <= N,
+      "Insufficient capacity in `StaticVec::set_len_filling`!"
+    );
+    let old_length = self.length;
+    if new_len <= old_length {
+      self.truncate(new_len);
+      return;
+    }
+    // Pushing the clones one at a time keeps the length accurate even if `clone` panics.
+    for _ in old_length + 1..new_len {
+      unsafe { self.push_unchecked(value.clone()) };
+    }
+    unsafe { self.push_unchecked(value) };
+  }
+
   /// Returns true if the current length of the StaticVec is 0.
   ///
   /// # Example usage:
@@ -548,6 +659,150 @@ impl<T, const N: usize> StaticVec<T, N> {
     unsafe { from_raw_parts_mut(self.as_mut_ptr(), self.length) }
   }
 
+  /// Returns the inhabited area of the StaticVec reinterpreted as a slice of bytes, for use in
+  /// things like zero-copy serialization. Locally requires that `T` implements the unsafe marker
+  /// trait [`Pod`](crate::utils::Pod), which guarantees that it contains no padding or pointers.
+  ///
+  /// Note that the bytes are in the native endianness of the platform, so e.g. writing them to a
+  /// socket and reading them on a machine of differing endianness will not give the same values.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let v = staticvec![1u16, 0x0102];
+  /// let bytes = v.as_byte_slice();
+  /// assert_eq!(bytes.len(), 4);
+  /// if cfg!(target_endian = "little") {
+  ///   assert_eq!(bytes, [1, 0, 2, 1]);
+  /// } else {
+  ///   assert_eq!(bytes, [0, 1, 1, 2]);
+  /// }
+  /// ```
+  #[inline(always)]
+  pub const fn as_byte_slice(&self) -> &[u8]
+  where T: Pod {
+    // Safety: `T: Pod` guarantees that every byte of the inhabited area is initialized, and `u8`
+    // has an alignment of one, so there are no alignment concerns either.
+    unsafe { from_raw_parts(self.as_ptr() as *const u8, self.length * size_of::<T>()) }
+  }
+
+  /// Creates a new StaticVec by reinterpreting `bytes` as a sequence of values of type `T`,
+  /// returning it in `Some` if the length of `bytes` is an exact multiple of `size_of::<T>()` and
+  /// the resulting number of elements does not exceed `N`, or `None` otherwise. As the bytes are
+  /// copied into the StaticVec's own (correctly aligned) storage, `bytes` itself does not need to
+  /// have any particular alignment. Locally requires that `T` implements the unsafe marker trait
+  /// [`Pod`](crate::utils::Pod), which guarantees that any sequence of bytes is a valid `T`.
+  ///
+  /// The bytes are interpreted in the native endianness of the platform, exactly as they would
+  /// have been produced by [`as_byte_slice`](crate::StaticVec::as_byte_slice).
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let v = staticvec![1u32, 2, 3];
+  /// let v2 = StaticVec::<u32, 4>::try_from_byte_slice(v.as_byte_slice()).unwrap();
+  /// assert_eq!(v2, [1, 2, 3]);
+  /// assert!(StaticVec::<u32, 4>::try_from_byte_slice(&[1, 2, 3]).is_none());
+  /// assert!(StaticVec::<u32, 2>::try_from_byte_slice(v.as_byte_slice()).is_none());
+  /// ```
+  #[inline]
+  pub const fn try_from_byte_slice(bytes: &[u8]) -> Option<Self>
+  where T: Pod {
+    let size = size_of::<T>();
+    let byte_length = bytes.len();
+    if size == 0 {
+      return if byte_length == 0 { Some(Self::new()) } else { None };
+    }
+    let length = byte_length / size;
+    if byte_length % size != 0 || length > N {
+      return None;
+    }
+    let mut res = Self::new();
+    unsafe {
+      // Safety: we've checked above that `bytes` contains exactly `length` values' worth of bytes,
+      // and that `length` is within our capacity. Copying bytewise means the alignment of `bytes`
+      // doesn't matter, and `T: Pod` guarantees that the result is valid.
+      (res.as_mut_ptr() as *mut u8).copy_from_nonoverlapping(bytes.as_ptr(), byte_length);
+      res.set_len(length);
+    }
+    Some(res)
+  }
+
+  /// Reinterprets the inhabited area of the StaticVec as a prefix of `T`, a middle slice of
+  /// correctly aligned `U`, and a suffix of `T`, exactly as
+  /// [`align_to`](https://doc.rust-lang.org/nightly/std/primitive.slice.html#method.align_to) does
+  /// for slices (which this simply delegates to). The middle slice will be as long as possible,
+  /// with the prefix and suffix containing whatever is left over due to alignment and length
+  /// respectively.
+  ///
+  /// # Safety
+  ///
+  /// This is just as unsafe as its slice counterpart: it is up to the caller to ensure that every
+  /// possible bit pattern of the memory viewed through the middle slice is a valid `U`, which in
+  /// practice means that both `T` and `U` should be "plain old data" types (see
+  /// [`Pod`](crate::utils::Pod)).
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let v = StaticVec::<u8, 16>::from([1u8; 11]);
+  /// let (head, mid, tail) = unsafe { v.align_to::<u32>() };
+  /// assert_eq!(head.len() + mid.len() * 4 + tail.len(), 11);
+  /// assert!(mid.iter().all(|&x| x == u32::from_ne_bytes([1, 1, 1, 1])));
+  /// ```
+  #[inline(always)]
+  pub unsafe fn align_to<U>(&self) -> (&[T], &[U], &[T]) {
+    self.as_slice().align_to::<U>()
+  }
+
+  /// Returns a constant reference to the StaticVec's backing array in `Some` if the StaticVec is
+  /// at maximum capacity (and thus has every one of its `N` elements initialized), or `None`
+  /// otherwise. Unlike [`into_inner`](crate::StaticVec::into_inner), this does not consume the
+  /// StaticVec.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let v1 = staticvec![1, 2, 3];
+  /// assert_eq!(v1.as_array_ref(), Some(&[1, 2, 3]));
+  /// let v2 = StaticVec::<i32, 4>::from([1, 2, 3]);
+  /// assert_eq!(v2.as_array_ref(), None);
+  /// ```
+  #[inline(always)]
+  pub const fn as_array_ref(&self) -> Option<&[T; N]> {
+    if self.is_full() {
+      // Safety: all `N` elements of the backing array are initialized when the StaticVec is full.
+      Some(unsafe { &*(self.as_ptr() as *const [T; N]) })
+    } else {
+      None
+    }
+  }
+
+  /// Returns a mutable reference to the StaticVec's backing array in `Some` if the StaticVec is
+  /// at maximum capacity (and thus has every one of its `N` elements initialized), or `None`
+  /// otherwise.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let mut v1 = staticvec![1, 2, 3];
+  /// if let Some(a) = v1.as_array_mut() {
+  ///   a[1] = 9;
+  /// }
+  /// assert_eq!(v1, [1, 9, 3]);
+  /// let mut v2 = StaticVec::<i32, 4>::from([1, 2, 3]);
+  /// assert_eq!(v2.as_array_mut(), None);
+  /// ```
+  #[inline(always)]
+  pub const fn as_array_mut(&mut self) -> Option<&mut [T; N]> {
+    if self.is_full() {
+      // Safety: See as_array_ref.
+      Some(unsafe { &mut *(self.as_mut_ptr() as *mut [T; N]) })
+    } else {
+      None
+    }
+  }
+
   /// Returns a constant pointer to the element of the StaticVec at `index` without doing any
   /// checking to ensure that `index` is actually within any particular bounds. The return value of
   /// this function is equivalent to what would be returned from `as_ptr().add(index)`.
@@ -775,6 +1030,82 @@ impl<T, const N: usize> StaticVec<T, N> {
     &mut *self.mut_ptr_at_unchecked(index)
   }
 
+  /// Returns a constant reference to the element or subslice of the StaticVec described by
+  /// `index` in `Some` if `index` is within bounds, or `None` otherwise.
+  ///
+  /// This is functionally identical to [`slice::get`](https://doc.rust-lang.org/nightly/std/primitive.slice.html#method.get),
+  /// but is provided inherently so as to avoid any ambiguity with regards to method resolution
+  /// through `Deref` in generic code.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let v = staticvec![1, 2, 3, 4];
+  /// assert_eq!(v.get(1), Some(&2));
+  /// assert_eq!(v.get(4), None);
+  /// assert_eq!(v.get(1..3), Some(&[2, 3][..]));
+  /// assert_eq!(v.get(2..9), None);
+  /// ```
+  #[inline(always)]
+  pub fn get<I: SliceIndex<[T]>>(&self, index: I) -> Option<&I::Output> {
+    self.as_slice().get(index)
+  }
+
+  /// Returns a mutable reference to the element or subslice of the StaticVec described by
+  /// `index` in `Some` if `index` is within bounds, or `None` otherwise.
+  ///
+  /// The same reasoning as to why this method exists inherently applies as does for
+  /// [`get`](crate::StaticVec::get).
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let mut v = staticvec![1, 2, 3, 4];
+  /// if let Some(x) = v.get_mut(1) {
+  ///   *x = 9;
+  /// }
+  /// if let Some(s) = v.get_mut(2..) {
+  ///   s[1] = 7;
+  /// }
+  /// assert!(v.get_mut(4).is_none());
+  /// assert_eq!(v, [1, 9, 3, 7]);
+  /// ```
+  #[inline(always)]
+  pub fn get_mut<I: SliceIndex<[T]>>(&mut self, index: I) -> Option<&mut I::Output> {
+    self.as_mut_slice().get_mut(index)
+  }
+
+  /// Returns mutable references to several elements of the StaticVec at once, in `Some` if every
+  /// index in `indices` is within bounds and no two of them are equal, or `None` otherwise.
+  ///
+  /// This is functionally equivalent to the nightly [`slice::get_many_mut`](https://doc.rust-lang.org/nightly/std/primitive.slice.html#method.get_many_mut),
+  /// though the disjointness check is done with a simple quadratic scan, as `K` is expected to be
+  /// small.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let mut v = staticvec![1, 2, 3, 4];
+  /// if let Some([a, b]) = v.get_many_mut([0, 3]) {
+  ///   core::mem::swap(a, b);
+  /// }
+  /// assert_eq!(v, [4, 2, 3, 1]);
+  /// assert!(v.get_many_mut([1, 1]).is_none());
+  /// assert!(v.get_many_mut([2, 4]).is_none());
+  /// ```
+  #[inline]
+  pub fn get_many_mut<const K: usize>(&mut self, indices: [usize; K]) -> Option<[&mut T; K]> {
+    for (i, &index) in indices.iter().enumerate() {
+      if index >= self.length || indices[..i].contains(&index) {
+        return None;
+      }
+    }
+    let ptr = self.as_mut_ptr();
+    // Safety: every index was checked above to be within `0..self.length` and distinct from all of
+    // the others, so none of the references we create here can alias.
+    Some(indices.map(|index| unsafe { &mut *ptr.add(index) }))
+  }
+
   /// Appends a value to the end of the StaticVec without asserting that
   /// its current length is less than `N`.
   ///
@@ -870,111 +1201,320 @@ impl<T, const N: usize> StaticVec<T, N> {
     unsafe { self.push_unchecked(value) };
   }
 
-  /// Removes the value at the last position of the StaticVec and returns it in `Some` if
-  /// the StaticVec has a current length greater than 0, and returns `None` otherwise.
+  /// Appends all `K` elements of `values` to the end of the StaticVec at once, by value.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the remaining capacity of the StaticVec is less than `K`.
   ///
   /// # Example usage:
   /// ```
   /// # use staticvec::*;
-  /// let mut v = staticvec![1, 2, 3, 4];
-  /// assert_eq!(v.pop(), Some(4));
-  /// assert_eq!(v.pop(), Some(3));
-  /// assert_eq!(v, [1, 2]);
+  /// let mut v = StaticVec::<Box<i32>, 4>::from([Box::new(1)]);
+  /// v.push_array([Box::new(2), Box::new(3), Box::new(4)]);
+  /// assert_eq!(v, [Box::new(1), Box::new(2), Box::new(3), Box::new(4)]);
   /// ```
-  #[inline(always)]
-  pub const fn pop(&mut self) -> Option<T> {
-    if self.is_empty() {
-      None
-    } else {
-      Some(unsafe { self.pop_unchecked() })
-    }
+  #[inline]
+  pub const fn push_array<const K: usize>(&mut self, values: [T; K]) {
+    assert!(
+      K <= self.remaining_capacity(),
+      "Insufficient capacity in `StaticVec::push_array`!"
+    );
+    unsafe { self.push_array_unchecked(values) };
   }
 
-  /// Returns a constant reference to the first element of the StaticVec in `Some` if the StaticVec
-  /// is not empty, or `None` otherwise.
+  /// Appends all `K` elements of `values` to the end of the StaticVec at once, by value, if the
+  /// remaining capacity of the StaticVec is at least `K`, or returns a
+  /// [`PushCapacityError`](crate::errors::PushCapacityError) containing `values` otherwise (in
+  /// which case nothing is appended at all).
   ///
   /// # Example usage:
   /// ```
   /// # use staticvec::*;
-  /// let v1 = staticvec![10, 40, 30];
-  /// assert_eq!(Some(&10), v1.first());
-  /// let v2 = StaticVec::<i32, 0>::new();
-  /// assert_eq!(None, v2.first());
+  /// let mut v = StaticVec::<i32, 4>::from([1, 2]);
+  /// assert!(v.try_push_array([3, 4]).is_ok());
+  /// assert_eq!(v.try_push_array([5]).unwrap_err().into_value(), [5]);
+  /// assert_eq!(v, [1, 2, 3, 4]);
   /// ```
-  #[inline(always)]
-  pub const fn first(&self) -> Option<&T> {
-    if self.is_empty() {
-      None
+  #[inline]
+  pub const fn try_push_array<const K: usize>(
+    &mut self,
+    values: [T; K],
+  ) -> Result<(), PushCapacityError<[T; K], N>> {
+    if K <= self.remaining_capacity() {
+      unsafe { self.push_array_unchecked(values) };
+      Ok(())
     } else {
-      Some(unsafe { self.get_unchecked(0) })
+      Err(PushCapacityError::new(values))
     }
   }
 
-  /// Returns a mutable reference to the first element of the StaticVec in `Some` if the StaticVec
-  /// is not empty, or `None` otherwise.
+  /// Internal function used by both [`push_array`](crate::StaticVec::push_array) and
+  /// [`try_push_array`](crate::StaticVec::try_push_array), which requires that the remaining
+  /// capacity of the StaticVec has already been verified to be at least `K`.
+  #[inline(always)]
+  const unsafe fn push_array_unchecked<const K: usize>(&mut self, values: [T; K]) {
+    let old_length = self.length;
+    // The elements are moved bitwise into the StaticVec, and then `values` itself is forgotten so
+    // that they are never dropped twice.
+    values
+      .as_ptr()
+      .copy_to_nonoverlapping(self.mut_ptr_at_unchecked(old_length), K);
+    mem::forget(values);
+    self.set_len(old_length + K);
+  }
+
+  /// Inserts `value` at the start of the StaticVec, shifting all existing elements one position
+  /// to the right, without asserting that its current length is less than `N`.
+  ///
+  /// # Safety
+  ///
+  /// It is up to the caller to ensure that the length of the StaticVec
+  /// prior to using this function is less than `N`. Failure to do so will result
+  /// in writing to an out-of-bounds memory region.
+  #[inline(always)]
+  const unsafe fn push_front_unchecked(&mut self, value: T) {
+    let old_length = self.length;
+    let mp = self.as_mut_ptr();
+    mp.copy_to(mp.offset(1), old_length);
+    mp.write(value);
+    self.set_len(old_length + 1);
+  }
+
+  /// Pushes `value` to the start of the StaticVec, shifting all existing elements one position to
+  /// the right, if its current length is less than its capacity, or returns a
+  /// [`PushCapacityError`](crate::errors::PushCapacityError) otherwise.
   ///
   /// # Example usage:
   /// ```
   /// # use staticvec::*;
-  /// let mut x = staticvec![0, 1, 2];
-  /// if let Some(first) = x.first_mut() {
-  ///   *first = 5;
-  /// }
-  /// assert_eq!(x, &[5, 1, 2]);
+  /// let mut v = StaticVec::<i32, 3>::from([2, 3]);
+  /// assert!(v.try_push_front(1).is_ok());
+  /// assert_eq!(v, [1, 2, 3]);
+  /// assert_eq!(v.try_push_front(0).unwrap_err().into_value(), 0);
   /// ```
   #[inline(always)]
-  pub const fn first_mut(&mut self) -> Option<&mut T> {
-    if self.is_empty() {
-      None
+  pub const fn try_push_front(&mut self, value: T) -> Result<(), PushCapacityError<T, N>> {
+    if self.is_not_full() {
+      unsafe { self.push_front_unchecked(value) };
+      Ok(())
     } else {
-      Some(unsafe { self.get_unchecked_mut(0) })
+      Err(PushCapacityError::new(value))
     }
   }
 
-  /// Returns a constant reference to the last element of the StaticVec in `Some` if the StaticVec
-  /// is not empty, or `None` otherwise.
+  /// Pushes a value to the start of the StaticVec, shifting all existing elements one position to
+  /// the right. Panics if the collection is full; that is, if `self.len() == self.capacity()`.
   ///
   /// # Example usage:
   /// ```
   /// # use staticvec::*;
-  /// let v = staticvec![10, 40, 30];
-  /// assert_eq!(Some(&30), v.last());
-  /// let w = StaticVec::<i32, 0>::new();
-  /// assert_eq!(None, w.last());
+  /// let mut v = StaticVec::<i32, 8>::new();
+  /// v.push_front(1);
+  /// v.push_front(2);
+  /// assert_eq!(v, [2, 1]);
   /// ```
   #[inline(always)]
-  pub const fn last(&self) -> Option<&T> {
-    if self.is_empty() {
+  pub const fn push_front(&mut self, value: T) {
+    assert!(
+      self.is_not_full(),
+      "`StaticVec::push_front` was called through a StaticVec already at maximum capacity!"
+    );
+    unsafe { self.push_front_unchecked(value) };
+  }
+
+  /// Pushes a value to the end of the StaticVec and returns `None` if the StaticVec is not full.
+  /// Otherwise, removes the first element of the StaticVec, shifts all of the remaining elements
+  /// one position to the left, appends `value` at the end and returns the removed element in
+  /// `Some`. This effectively allows for the StaticVec to be used as a simple bounded FIFO buffer.
+  ///
+  /// If the StaticVec has a capacity of zero, `value` itself is returned in `Some`.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let mut v = StaticVec::<i32, 3>::new();
+  /// assert_eq!(v.rolling_push(1), None);
+  /// assert_eq!(v.rolling_push(2), None);
+  /// assert_eq!(v.rolling_push(3), None);
+  /// assert_eq!(v.rolling_push(4), Some(1));
+  /// assert_eq!(v, [2, 3, 4]);
+  /// ```
+  #[inline]
+  pub const fn rolling_push(&mut self, value: T) -> Option<T> {
+    if self.is_not_full() {
+      unsafe { self.push_unchecked(value) };
       None
+    } else if N == 0 {
+      Some(value)
     } else {
-      Some(unsafe { self.get_unchecked(self.length - 1) })
+      unsafe {
+        let mp = self.as_mut_ptr();
+        let evicted = mp.read();
+        mp.add(1).copy_to(mp, N - 1);
+        mp.add(N - 1).write(value);
+        Some(evicted)
+      }
     }
   }
 
-  /// Returns a mutable reference to the last element of the StaticVec in `Some` if the StaticVec is
-  /// not empty, or `None` otherwise.
+  /// Removes the value at the last position of the StaticVec and returns it in `Some` if
+  /// the StaticVec has a current length greater than 0, and returns `None` otherwise.
   ///
   /// # Example usage:
   /// ```
   /// # use staticvec::*;
-  /// let mut x = staticvec![0, 1, 2];
-  /// if let Some(last) = x.last_mut() {
-  ///   *last = 10;
-  /// }
-  /// assert_eq!(x, &[0, 1, 10]);
+  /// let mut v = staticvec![1, 2, 3, 4];
+  /// assert_eq!(v.pop(), Some(4));
+  /// assert_eq!(v.pop(), Some(3));
+  /// assert_eq!(v, [1, 2]);
   /// ```
   #[inline(always)]
-  pub const fn last_mut(&mut self) -> Option<&mut T> {
+  pub const fn pop(&mut self) -> Option<T> {
     if self.is_empty() {
       None
     } else {
-      Some(unsafe { self.get_unchecked_mut(self.length - 1) })
+      Some(unsafe { self.pop_unchecked() })
     }
   }
 
-  /// A crate-local unchecked version of `remove`, currently only used in the implementation of
-  /// `StaticVecSplice`.
-  #[inline]
+  /// Removes the value at the first position of the StaticVec and returns it directly, shifting
+  /// all remaining elements one position to the left, without asserting that the StaticVec's
+  /// current length is greater than 0.
+  ///
+  /// # Safety
+  ///
+  /// It is up to the caller to ensure that the StaticVec contains at least one
+  /// element prior to using this function. Failure to do so will result in reading
+  /// from uninitialized memory.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let mut v = StaticVec::<i32, 4>::from([1, 2, 3, 4]);
+  /// assert_eq!(unsafe { v.pop_front_unchecked() }, 1);
+  /// assert_eq!(v, [2, 3, 4]);
+  /// ```
+  #[inline(always)]
+  pub const unsafe fn pop_front_unchecked(&mut self) -> T {
+    debug_assert!(
+      self.is_not_empty(),
+      "`StaticVec::pop_front_unchecked` was called through an empty StaticVec!"
+    );
+    let new_length = self.length - 1;
+    let mp = self.as_mut_ptr();
+    let res = mp.read();
+    mp.offset(1).copy_to(mp, new_length);
+    self.set_len(new_length);
+    res
+  }
+
+  /// Removes the value at the first position of the StaticVec, shifting all remaining elements one
+  /// position to the left, and returns it in `Some` if the StaticVec has a current length greater
+  /// than 0, and returns `None` otherwise.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let mut v = staticvec![1, 2, 3, 4];
+  /// assert_eq!(v.pop_front(), Some(1));
+  /// assert_eq!(v.pop_front(), Some(2));
+  /// assert_eq!(v, [3, 4]);
+  /// ```
+  #[inline(always)]
+  pub const fn pop_front(&mut self) -> Option<T> {
+    if self.is_empty() {
+      None
+    } else {
+      Some(unsafe { self.pop_front_unchecked() })
+    }
+  }
+
+  /// Returns a constant reference to the first element of the StaticVec in `Some` if the StaticVec
+  /// is not empty, or `None` otherwise.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let v1 = staticvec![10, 40, 30];
+  /// assert_eq!(Some(&10), v1.first());
+  /// let v2 = StaticVec::<i32, 0>::new();
+  /// assert_eq!(None, v2.first());
+  /// ```
+  #[inline(always)]
+  pub const fn first(&self) -> Option<&T> {
+    if self.is_empty() {
+      None
+    } else {
+      Some(unsafe { self.get_unchecked(0) })
+    }
+  }
+
+  /// Returns a mutable reference to the first element of the StaticVec in `Some` if the StaticVec
+  /// is not empty, or `None` otherwise.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let mut x = staticvec![0, 1, 2];
+  /// if let Some(first) = x.first_mut() {
+  ///   *first = 5;
+  /// }
+  /// assert_eq!(x, &[5, 1, 2]);
+  /// ```
+  #[inline(always)]
+  pub const fn first_mut(&mut self) -> Option<&mut T> {
+    if self.is_empty() {
+      None
+    } else {
+      Some(unsafe { self.get_unchecked_mut(0) })
+    }
+  }
+
+  /// Returns a constant reference to the last element of the StaticVec in `Some` if the StaticVec
+  /// is not empty, or `None` otherwise.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let v = staticvec![10, 40, 30];
+  /// assert_eq!(Some(&30), v.last());
+  /// let w = StaticVec::<i32, 0>::new();
+  /// assert_eq!(None, w.last());
+  /// ```
+  #[inline(always)]
+  pub const fn last(&self) -> Option<&T> {
+    if self.is_empty() {
+      None
+    } else {
+      Some(unsafe { self.get_unchecked(self.length - 1) })
+    }
+  }
+
+  /// Returns a mutable reference to the last element of the StaticVec in `Some` if the StaticVec is
+  /// not empty, or `None` otherwise.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let mut x = staticvec![0, 1, 2];
+  /// if let Some(last) = x.last_mut() {
+  ///   *last = 10;
+  /// }
+  /// assert_eq!(x, &[0, 1, 10]);
+  /// ```
+  #[inline(always)]
+  pub const fn last_mut(&mut self) -> Option<&mut T> {
+    if self.is_empty() {
+      None
+    } else {
+      Some(unsafe { self.get_unchecked_mut(self.length - 1) })
+    }
+  }
+
+  /// A crate-local unchecked version of `remove`, currently only used in the implementation of
+  /// `StaticVecSplice`.
+  #[inline]
   pub(crate) const fn remove_unchecked(&mut self, index: usize) -> T {
     let old_length = self.length;
     unsafe {
@@ -1081,6 +1621,129 @@ impl<T, const N: usize> StaticVec<T, N> {
     }
   }
 
+  /// Moves the element at `index` to the front of the StaticVec, shifting all of the elements
+  /// that were previously before it one position to the right. This is functionally equivalent to
+  /// `let x = v.remove(index); v.insert(0, x);`, but is done with a single shift and never changes
+  /// the length of the StaticVec, even temporarily.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `index` is out of bounds.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let mut v = staticvec!["A", "B", "C", "D"];
+  /// v.move_to_front(2);
+  /// assert_eq!(v, ["C", "A", "B", "D"]);
+  /// ```
+  #[inline]
+  pub const fn move_to_front(&mut self, index: usize) {
+    assert!(
+      index < self.length,
+      "Bounds check failure in `StaticVec::move_to_front`!"
+    );
+    unsafe {
+      let mp = self.as_mut_ptr();
+      let value = mp.add(index).read();
+      mp.copy_to(mp.add(1), index);
+      mp.write(value);
+    }
+  }
+
+  /// Moves the element at `index` to the back of the StaticVec, shifting all of the elements
+  /// that were previously after it one position to the left. This is functionally equivalent to
+  /// `let x = v.remove(index); v.push(x);`, but is done with a single shift and never changes the
+  /// length of the StaticVec, even temporarily.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `index` is out of bounds.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let mut v = staticvec!["A", "B", "C", "D"];
+  /// v.move_to_back(1);
+  /// assert_eq!(v, ["A", "C", "D", "B"]);
+  /// ```
+  #[inline]
+  pub const fn move_to_back(&mut self, index: usize) {
+    let length = self.length;
+    assert!(
+      index < length,
+      "Bounds check failure in `StaticVec::move_to_back`!"
+    );
+    unsafe {
+      let mp = self.as_mut_ptr();
+      let value = mp.add(index).read();
+      mp.add(index + 1).copy_to(mp.add(index), length - index - 1);
+      mp.add(length - 1).write(value);
+    }
+  }
+
+  /// Swaps all elements of the StaticVec with those of `other`, which must have a length equal to
+  /// that of the StaticVec.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `other.len()` is not equal to `self.len()`.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let mut v = staticvec![1, 2, 3];
+  /// let mut a = [4, 5, 6];
+  /// v.swap_with_slice(&mut a);
+  /// assert_eq!(v, [4, 5, 6]);
+  /// assert_eq!(a, [1, 2, 3]);
+  /// ```
+  #[inline]
+  pub const fn swap_with_slice(&mut self, other: &mut [T]) {
+    let length = self.length;
+    assert!(
+      other.len() == length,
+      "Length mismatch in `StaticVec::swap_with_slice`!"
+    );
+    // Safety: `other` is a distinct mutable borrow, so the two regions can't overlap.
+    unsafe { ptr::swap_nonoverlapping(self.as_mut_ptr(), other.as_mut_ptr(), length) };
+  }
+
+  /// Copies the elements within the specified range of the StaticVec to the position starting at
+  /// `dest`, within the StaticVec itself. The source range and the destination range are allowed
+  /// to overlap. Locally requires that `T` implements [`Copy`](core::marker::Copy) to avoid
+  /// soundness issues.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the range's starting point is greater than the end point, if the end point is
+  /// greater than the length of the StaticVec, or if `dest + (end - start)` is greater than the
+  /// length of the StaticVec.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let mut v = staticvec![1, 2, 3, 4, 5];
+  /// v.copy_within(1..3, 2);
+  /// assert_eq!(v, [1, 2, 2, 3, 5]);
+  /// v.copy_within(2.., 0);
+  /// assert_eq!(v, [2, 3, 5, 3, 5]);
+  /// ```
+  #[inline]
+  pub fn copy_within<R: RangeBounds<usize>>(&mut self, src: R, dest: usize)
+  where T: Copy {
+    let length = self.length;
+    let (start, end) = self.decode_range(src, "Bounds check failure in `StaticVec::copy_within`!");
+    assert!(
+      start <= end && end <= length && dest <= length - (end - start),
+      "Bounds check failure in `StaticVec::copy_within`!"
+    );
+    unsafe {
+      let mp = self.as_mut_ptr();
+      mp.add(start).copy_to(mp.add(dest), end - start);
+    }
+  }
+
   /// Asserts that the current length of the StaticVec is less than `N` and that
   /// `index` is less than the length, and if so inserts `value` at that position.
   /// Any values that exist in positions after `index` are shifted to the right.
@@ -1167,6 +1830,49 @@ impl<T, const N: usize> StaticVec<T, N> {
     }
   }
 
+  /// A fully checked version of [`insert_many`](crate::StaticVec::insert_many) that never panics,
+  /// and instead returns a [`CapacityError`](crate::errors::CapacityError) if `index` is greater
+  /// than the current length of the StaticVec or if the StaticVec does not have enough remaining
+  /// capacity for all of the items `iter` reports that it will yield. Both conditions are checked
+  /// up front, such that the StaticVec is left entirely unchanged in the event of an error.
+  ///
+  /// The items are first appended to the end of the StaticVec and then rotated into place, so an
+  /// incorrect [`ExactSizeIterator`](core::iter::ExactSizeIterator) implementation can never cause
+  /// anything worse than a shorter insertion: no more than `iter.len()` items are taken from it,
+  /// and if it yields fewer than that, only those that were actually yielded are inserted.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let mut v = StaticVec::<usize, 6>::from([1, 2, 5, 6]);
+  /// assert!(v.checked_insert_many(2, staticvec![3, 4]).is_ok());
+  /// assert_eq!(v, [1, 2, 3, 4, 5, 6]);
+  /// assert_eq!(v.checked_insert_many(0, staticvec![0]), Err(CapacityError::<6> {}));
+  /// assert_eq!(v, [1, 2, 3, 4, 5, 6]);
+  /// ```
+  #[inline]
+  pub fn checked_insert_many<I: IntoIterator<Item = T>>(
+    &mut self,
+    index: usize,
+    iter: I,
+  ) -> Result<(), CapacityError<N>>
+  where
+    I::IntoIter: ExactSizeIterator<Item = T>,
+  {
+    let old_length = self.length;
+    let it = iter.into_iter();
+    let iter_size = it.len();
+    if index > old_length || iter_size > N - old_length {
+      return Err(CapacityError {});
+    }
+    for item in it.take(iter_size) {
+      unsafe { self.push_unchecked(item) };
+    }
+    let added = self.length - old_length;
+    self.as_mut_slice()[index..].rotate_right(added);
+    Ok(())
+  }
+
   /// Functionally equivalent to [`insert_many`](crate::StaticVec::insert_many), except with
   /// multiple items provided by a slice reference as opposed to an arbitrary iterator. Locally
   /// requires that `T` implements [`Copy`](core::marker::Copy) to avoid soundness issues.
@@ -1195,6 +1901,48 @@ impl<T, const N: usize> StaticVec<T, N> {
     }
   }
 
+  /// Inserts as many of the items in `values` at `index` as there is remaining capacity for,
+  /// shifting any values that exist in positions after `index` to the right by exactly that many
+  /// positions, and returns the number of items that were actually inserted. Any items that do not
+  /// fit are silently ignored, much like with
+  /// [`extend_from_slice`](crate::StaticVec::extend_from_slice). Locally requires that `T`
+  /// implements [`Copy`](core::marker::Copy) to avoid soundness issues.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `index` is greater than the length of the StaticVec.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let mut v = StaticVec::<usize, 6>::from([1, 2, 7, 8]);
+  /// assert_eq!(v.insert_from_slice_bounded(2, &[3, 4, 5, 6]), 2);
+  /// assert_eq!(v, [1, 2, 3, 4, 7, 8]);
+  /// assert_eq!(v.insert_from_slice_bounded(0, &[0]), 0);
+  /// ```
+  #[inline]
+  pub const fn insert_from_slice_bounded(&mut self, index: usize, values: &[T]) -> usize
+  where T: Copy {
+    let old_length = self.length;
+    assert!(
+      index <= old_length,
+      "Bounds check failure in `StaticVec::insert_from_slice_bounded`!"
+    );
+    let remaining_capacity = self.remaining_capacity();
+    let values_length = if values.len() < remaining_capacity {
+      values.len()
+    } else {
+      remaining_capacity
+    };
+    unsafe {
+      let self_ptr = self.mut_ptr_at_unchecked(index);
+      self_ptr.copy_to(self_ptr.add(values_length), old_length - index);
+      self_ptr.copy_from_nonoverlapping(values.as_ptr(), values_length);
+      self.set_len(old_length + values_length);
+    }
+    values_length
+  }
+
   /// Inserts `value` at `index` if the current length of the StaticVec is less than `N` and `index`
   /// is less than the length, or returns a [`CapacityError`](crate::errors::CapacityError)
   /// otherwise. Any values that exist in positions after `index` are shifted to the right.
@@ -1274,1365 +2022,4040 @@ impl<T, const N: usize> StaticVec<T, N> {
     self.iter().any(|current| current == value)
   }
 
-  /// Removes all contents from the StaticVec and sets its length back to 0.
+  /// Binary searches the portion of the StaticVec described by `range` for `x`, returning the
+  /// result in the same form as [`slice::binary_search`](https://doc.rust-lang.org/nightly/std/primitive.slice.html#method.binary_search)
+  /// does, except that the index in either the `Ok` or `Err` case is relative to the start of the
+  /// whole StaticVec as opposed to the start of the range. This is useful for StaticVecs that
+  /// contain multiple sorted segments. Only the elements within `range` need to be sorted.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the range's starting point is greater than the end point or if the end point is
+  /// greater than the length of the StaticVec.
   ///
   /// # Example usage:
   /// ```
   /// # use staticvec::*;
-  /// let mut v = staticvec![1, 2, 3];
-  /// assert_eq!(v.len(), 3);
-  /// assert_eq!(v, [1, 2, 3]);
-  /// v.clear();
-  /// assert_eq!(v.len(), 0);
-  /// assert_eq!(v, []);
+  /// let v = staticvec![9, 8, 1, 3, 5, 7, 0];
+  /// assert_eq!(v.binary_search_in(2..6, &5), Ok(4));
+  /// assert_eq!(v.binary_search_in(2..6, &4), Err(4));
+  /// assert_eq!(v.binary_search_in(2..6, &9), Err(6));
   /// ```
-  #[inline(always)]
-  pub fn clear(&mut self) {
-    // Safety: `self.as_mut_slice()` will always return either a slice of values that are known to
-    // be initialized, or simply a benign empty slice if the StaticVec it is called through is
-    // empty.
-    unsafe { ptr::drop_in_place(self.as_mut_slice()) };
-    self.length = 0;
+  #[inline]
+  pub fn binary_search_in<R: RangeBounds<usize>>(&self, range: R, x: &T) -> Result<usize, usize>
+  where T: Ord {
+    let (start, end) =
+      self.decode_range(range, "Bounds check failure in `StaticVec::binary_search_in`!");
+    assert!(
+      start <= end && end <= self.length,
+      "Bounds check failure in `StaticVec::binary_search_in`!"
+    );
+    match unsafe { self.as_slice().get_unchecked(start..end) }.binary_search(x) {
+      Ok(index) => Ok(start + index),
+      Err(index) => Err(start + index),
+    }
   }
 
-  /// Returns a [`StaticVecIterConst`](crate::iterators::StaticVecIterConst) over the StaticVec's
-  /// inhabited area.
+  /// Returns the index of the first element of the StaticVec for which `predicate` returns true
+  /// in `Some`, or `None` if there is no such element.
   ///
   /// # Example usage:
   /// ```
   /// # use staticvec::*;
-  /// let v = staticvec![4, 3, 2, 1];
-  /// for i in v.iter() {
-  ///   println!("{}", i);
-  /// }
+  /// let v = staticvec![1, 2, 3, 2];
+  /// assert_eq!(v.position(|x| *x == 2), Some(1));
+  /// assert_eq!(v.position(|x| *x == 4), None);
   /// ```
   #[inline(always)]
-  pub const fn iter(&self) -> StaticVecIterConst<T, N> {
-    let start_ptr = self.as_ptr();
-    unsafe {
-      // `start_ptr` will never be null, so this is a safe assumption to give the optimizer.
-      assume(!start_ptr.is_null());
-      StaticVecIterConst {
-        start: start_ptr,
-        end: match size_of::<T>() {
-          0 => zst_ptr_add(start_ptr, self.length),
-          _ => start_ptr.add(self.length),
-        },
-        marker: PhantomData,
-      }
-    }
+  pub fn position<P: FnMut(&T) -> bool>(&self, predicate: P) -> Option<usize> {
+    self.iter().position(predicate)
   }
 
-  /// Returns a [`StaticVecIterMut`](crate::iterators::StaticVecIterMut) over the StaticVec's
-  /// inhabited area.
+  /// Returns the index of the last element of the StaticVec for which `predicate` returns true
+  /// in `Some`, or `None` if there is no such element.
   ///
   /// # Example usage:
   /// ```
   /// # use staticvec::*;
-  /// let mut v = staticvec![4, 3, 2, 1];
-  /// for i in v.iter_mut() {
-  ///   *i -= 1;
-  /// }
-  /// assert_eq!(v, [3, 2, 1, 0]);
+  /// let v = staticvec![1, 2, 3, 2];
+  /// assert_eq!(v.rposition(|x| *x == 2), Some(3));
+  /// assert_eq!(v.rposition(|x| *x == 4), None);
   /// ```
   #[inline(always)]
-  pub const fn iter_mut(&mut self) -> StaticVecIterMut<T, N> {
-    let start_ptr = self.as_mut_ptr();
-    unsafe {
-      // `start_ptr` will never be null, so this is a safe assumption to give the optimizer.
-      assume(!start_ptr.is_null());
-      StaticVecIterMut {
-        start: start_ptr,
-        end: match size_of::<T>() {
-          0 => zst_ptr_add_mut(start_ptr, self.length),
-          _ => start_ptr.add(self.length),
-        },
-        marker: PhantomData,
-      }
-    }
+  pub fn rposition<P: FnMut(&T) -> bool>(&self, predicate: P) -> Option<usize> {
+    self.iter().rposition(predicate)
   }
 
-  /// Returns a separate, stable-sorted StaticVec of the contents of the StaticVec's inhabited area
-  /// without modifying the original data. Locally requires that `T` implements
-  /// [`Copy`](core::marker::Copy) to avoid soundness issues, and [`Ord`](core::cmp::Ord) to make
-  /// the sorting possible.
+  /// Returns the index of the element of the StaticVec that gives the minimum value from `f` in
+  /// `Some`, or `None` if the StaticVec is empty. If several elements are equally minimum, the
+  /// index of the first one is returned.
   ///
   /// # Example usage:
   /// ```
-  /// # use staticvec::{staticvec, StaticVec};
-  /// const V: StaticVec<StaticVec<i32, 2>, 2> = staticvec![staticvec![1, 3], staticvec![4, 2]];
-  /// assert_eq!(
-  ///   V.iter().flatten().collect::<StaticVec<i32, 4>>().sorted(),
-  ///   [1, 2, 3, 4]
-  /// );
+  /// # use staticvec::*;
+  /// let v = staticvec![-3, 1, 3, -1];
+  /// assert_eq!(v.index_of_min_by_key(|x| x.abs()), Some(1));
+  /// assert_eq!(StaticVec::<i32, 4>::new().index_of_min_by_key(|x| *x), None);
   /// ```
-  #[cfg(feature = "std")]
-  #[doc(cfg(feature = "std"))]
   #[inline]
-  pub fn sorted(&self) -> Self
-  where T: Copy + Ord {
-    // StaticVec uses specialization to have an optimized version of `Clone` for copy types.
-    let mut res = self.clone();
-    res.sort();
-    res
+  pub fn index_of_min_by_key<K: Ord, F: FnMut(&T) -> K>(&self, mut f: F) -> Option<usize> {
+    let mut it = self.iter().enumerate();
+    let (mut best_index, first) = it.next()?;
+    let mut best_key = f(first);
+    for (index, item) in it {
+      let key = f(item);
+      // A strict comparison keeps the first of any equal keys.
+      if key < best_key {
+        best_index = index;
+        best_key = key;
+      }
+    }
+    Some(best_index)
   }
 
-  /// Returns a separate, unstable-sorted StaticVec of the contents of the StaticVec's inhabited
-  /// area without modifying the original data. Locally requires that `T` implements
-  /// [`Copy`](core::marker::Copy) to avoid soundness issues, and [`Ord`](core::cmp::Ord) to make
-  /// the sorting possible.
+  /// Returns the index of the element of the StaticVec that gives the maximum value from `f` in
+  /// `Some`, or `None` if the StaticVec is empty. If several elements are equally maximum, the
+  /// index of the first one is returned (note that this differs from
+  /// [`Iterator::max_by_key`](core::iter::Iterator::max_by_key), which returns the last one).
   ///
   /// # Example usage:
   /// ```
-  /// # use staticvec::{staticvec, StaticVec};
-  /// const V: StaticVec<StaticVec<i32, 2>, 2> = staticvec![staticvec![1, 3], staticvec![4, 2]];
-  /// assert_eq!(
-  ///   V.iter().flatten().collect::<StaticVec<i32, 4>>().sorted_unstable(),
-  ///   [1, 2, 3, 4]
-  /// );
+  /// # use staticvec::*;
+  /// let v = staticvec![-3, 1, 3, -1];
+  /// assert_eq!(v.index_of_max_by_key(|x| x.abs()), Some(0));
+  /// assert_eq!(StaticVec::<i32, 4>::new().index_of_max_by_key(|x| *x), None);
   /// ```
   #[inline]
-  pub fn sorted_unstable(&self) -> Self
-  where T: Copy + Ord {
-    // StaticVec uses specialization to have an optimized version of `Clone` for copy types.
-    let mut res = self.clone();
-    res.sort_unstable();
-    res
+  pub fn index_of_max_by_key<K: Ord, F: FnMut(&T) -> K>(&self, mut f: F) -> Option<usize> {
+    let mut it = self.iter().enumerate();
+    let (mut best_index, first) = it.next()?;
+    let mut best_key = f(first);
+    for (index, item) in it {
+      let key = f(item);
+      // A strict comparison keeps the first of any equal keys.
+      if key > best_key {
+        best_index = index;
+        best_key = key;
+      }
+    }
+    Some(best_index)
   }
 
-  /// Returns a separate, unstable-quicksorted StaticVec of the contents of the StaticVec's
-  /// inhabited area without modifying the original data. Locally requires that `T` implements
-  /// [`Copy`](core::marker::Copy) to avoid soundness issues, and
-  /// [`PartialOrd`](core::cmp::PartialOrd) to make the sorting possible.
-  ///
-  /// Unlike [`sorted`](crate::StaticVec::sorted) and
-  /// [`sorted_unstable`](crate::StaticVec::sorted_unstable), this function does not make use of
-  /// Rust's built-in sorting methods, but instead makes direct use of a comparatively
-  /// unsophisticated recursive quicksort algorithm implemented in this crate.
+  /// Returns the index of the maximum element of the StaticVec with respect to the comparison
+  /// function `cmp` in `Some`, or `None` if the StaticVec is empty. If several elements are
+  /// equally maximum, the index of the last one is returned, matching the behavior of
+  /// [`Iterator::max_by`](core::iter::Iterator::max_by).
   ///
-  /// This has the advantage of only needing to have [`PartialOrd`](core::cmp::PartialOrd) as a
-  /// constraint as opposed to [`Ord`](core::cmp::Ord), but is very likely less performant for
-  /// most inputs, so if the type you're sorting does derive or implement
-  /// [`Ord`](core::cmp::Ord) it's recommended that you use [`sorted`](crate::StaticVec::sorted) or
-  /// [`sorted_unstable`](crate::StaticVec::sorted_unstable) instead of this function.
+  /// As the comparison is user-provided, this works for types that do not implement
+  /// [`Ord`](core::cmp::Ord), such as `f32` and `f64`.
   ///
   /// # Example usage:
   /// ```
-  /// # use staticvec::{staticvec, StaticVec};
-  /// const V: StaticVec<StaticVec<i32, 2>, 2> = staticvec![staticvec![1, 3], staticvec![4, 2]];
-  /// assert_eq!(
-  ///   V.iter().flatten().collect::<StaticVec<i32, 4>>().quicksorted_unstable(),
-  ///   [1, 2, 3, 4]
-  /// );
+  /// # use staticvec::*;
+  /// let v = staticvec![2.5, -1.0, 7.25, 7.25, 0.0];
+  /// assert_eq!(v.position_max_by(|a, b| a.partial_cmp(b).unwrap()), Some(3));
+  /// let empty = StaticVec::<f64, 4>::new();
+  /// assert_eq!(empty.position_max_by(|a, b| a.partial_cmp(b).unwrap()), None);
   /// ```
   #[inline]
-  pub const fn quicksorted_unstable(&self) -> Self
-  where T: Copy + ~const PartialOrd {
-    let length = self.length;
-    if length < 2 {
-      // StaticVec uses specialization to have an optimized verson of `Clone` for copy types.
-      return self.clone();
-    }
-    let mut res = Self::new_data_uninit();
-    let res_ptr = Self::first_ptr_mut(&mut res);
-    // Copy the inhabited part of `self` into the array we'll use for the returned StaticVec.
-    unsafe { self.as_ptr().copy_to_nonoverlapping(res_ptr, length) };
-    // Sort the array, and then build and return a new StaticVec from it.
-    quicksort_internal(res_ptr, 0, (length - 1) as isize);
-    Self { data: res, length }
+  pub fn position_max_by<F: FnMut(&T, &T) -> Ordering>(&self, mut cmp: F) -> Option<usize> {
+    self
+      .iter()
+      .enumerate()
+      .max_by(|(_, a), (_, b)| cmp(a, b))
+      .map(|(index, _)| index)
   }
 
-  /// Provides the same sorting functionality as
-  /// [`quicksorted_unstable`](crate::StaticVec::quicksorted_unstable) (and has the same trait
-  /// bound requirements) but operates in-place on the calling StaticVec instance rather than
-  /// returning the sorted data in a new one.
+  /// Returns the index of the minimum element of the StaticVec with respect to the comparison
+  /// function `cmp` in `Some`, or `None` if the StaticVec is empty. If several elements are
+  /// equally minimum, the index of the first one is returned, matching the behavior of
+  /// [`Iterator::min_by`](core::iter::Iterator::min_by).
+  ///
+  /// As the comparison is user-provided, this works for types that do not implement
+  /// [`Ord`](core::cmp::Ord), such as `f32` and `f64`.
   ///
   /// # Example usage:
   /// ```
   /// # use staticvec::*;
-  /// let mut v = staticvec![5.0, 4.0, 3.0, 2.0, 1.0];
-  /// v.quicksort_unstable();
-  /// assert_eq!(v, [1.0, 2.0, 3.0, 4.0, 5.0]);
-  /// // Note that if you are actually sorting floating-point numbers as shown above, and the
-  /// // StaticVec contains one or more instances of NAN, the "accuracy" of the sorting will
-  /// // essentially be determined by a combination of how many *consecutive* NANs there are,
-  /// // as well as how "mixed up" the surrounding valid numbers were to begin with. In any case,
-  /// // the outcome of this particular hypothetical scenario will never be any worse than the
-  /// // values simply not being sorted quite as you'd hoped.
+  /// let v = staticvec![2.5, -1.0, 7.25, -1.0, 0.0];
+  /// assert_eq!(v.position_min_by(|a, b| a.partial_cmp(b).unwrap()), Some(1));
+  /// let empty = StaticVec::<f64, 4>::new();
+  /// assert_eq!(empty.position_min_by(|a, b| a.partial_cmp(b).unwrap()), None);
   /// ```
   #[inline]
-  pub const fn quicksort_unstable(&mut self)
-  where T: Copy + /*~const */PartialOrd {
-    let length = self.length;
-    if length < 2 {
-      return;
-    }
-    let self_ptr = self.as_mut_ptr();
-    // We know self_ptr will never be null, so this is a safe hint to give the optimizer.
-    unsafe { assume(!self_ptr.is_null()) };
-    quicksort_internal(self_ptr, 0, (length - 1) as isize);
+  pub fn position_min_by<F: FnMut(&T, &T) -> Ordering>(&self, mut cmp: F) -> Option<usize> {
+    self
+      .iter()
+      .enumerate()
+      .min_by(|(_, a), (_, b)| cmp(a, b))
+      .map(|(index, _)| index)
   }
 
-  /// Returns a separate, reversed StaticVec of the contents of the StaticVec's inhabited area
-  /// without modifying the original data. Locally requires that `T` implements
-  /// [`Copy`](core::marker::Copy) to avoid soundness issues.
+  /// Returns a constant reference to the first element of the StaticVec for which `predicate`
+  /// returns true in `Some`, or `None` if there is no such element.
   ///
   /// # Example usage:
   /// ```
   /// # use staticvec::*;
-  /// assert_eq!(staticvec![1, 2, 3].reversed(), [3, 2, 1]);
+  /// let v = staticvec![1, 2, 3, 4];
+  /// assert_eq!(v.find(|x| *x > 2), Some(&3));
+  /// assert_eq!(v.find(|x| *x > 4), None);
   /// ```
   #[inline(always)]
-  pub const fn reversed(&self) -> Self
-  where T: Copy {
-    Self {
-      data: reverse_copy(self.length, &self.data),
-      length: self.length,
-    }
+  pub fn find<P: FnMut(&T) -> bool>(&self, mut predicate: P) -> Option<&T> {
+    self.iter().find(|item| predicate(item))
   }
 
-  /// Returns a new StaticVec instance filled with the return value of an initializer function.
-  /// The length field of the newly created StaticVec will be equal to its capacity.
+  /// Returns the number of elements in the StaticVec that satisfy `predicate`. Equivalent to
+  /// `self.iter().filter(|item| predicate(item)).count()`.
   ///
   /// # Example usage:
   /// ```
-  /// # use staticvec::StaticVec;
-  /// let mut i = 0;
-  /// let v = StaticVec::<i32, 64>::filled_with(|| { i += 1; i });
-  /// assert_eq!(v.len(), 64);
-  /// assert_eq!(v[0], 1);
-  /// assert_eq!(v[1], 2);
-  /// assert_eq!(v[2], 3);
-  /// assert_eq!(v[3], 4);
+  /// # use staticvec::*;
+  /// let v = staticvec![1, 2, 3, 4, 5, 6];
+  /// assert_eq!(v.count(|x| x % 2 == 0), 3);
   /// ```
-  #[inline]
-  pub fn filled_with<F: FnMut() -> T>(mut initializer: F) -> Self {
-    let mut res = Self::new();
-    for i in 0..N {
-      unsafe {
-        res.mut_ptr_at_unchecked(i).write(initializer());
-      }
-      // Adjusting the length of `res` as we go ensures any items already written to it will be
-      // dropped properly in the event that `initializer` panics at some point.
-      res.length += 1;
-    }
-    res
+  #[inline(always)]
+  pub fn count<P: FnMut(&T) -> bool>(&self, mut predicate: P) -> usize {
+    self.iter().filter(|item| predicate(item)).count()
   }
 
-  /// Returns a new StaticVec instance filled with the return value of an initializer function.
-  /// Unlike for [`filled_with`](crate::StaticVec::filled_with), the initializer function in
-  /// this case must take a single usize variable as an input parameter, which will be called
-  /// with the current index of the `0..N` loop that
-  /// [`filled_with_by_index`](crate::StaticVec::filled_with_by_index) is implemented with
-  /// internally. The length field of the newly created StaticVec will be equal to its capacity.
+  /// Returns the number of elements in the StaticVec that are equal to `value`, according to the
+  /// locally required [`PartialEq`](core::cmp::PartialEq) trait implementation for `T`.
   ///
   /// # Example usage:
   /// ```
-  /// # use staticvec::StaticVec;
-  /// let v = StaticVec::<usize, 64>::filled_with_by_index(|i| { i + 1 });
-  /// assert_eq!(v.len(), 64);
-  /// assert_eq!(v[0], 1);
-  /// assert_eq!(v[1], 2);
-  /// assert_eq!(v[2], 3);
-  /// assert_eq!(v[3], 4);
+  /// # use staticvec::*;
+  /// let v = staticvec![1, 2, 1, 3, 1];
+  /// assert_eq!(v.count_eq(&1), 3);
+  /// assert_eq!(v.count_eq(&4), 0);
   /// ```
-  #[inline]
-  pub fn filled_with_by_index<F: FnMut(usize) -> T>(mut initializer: F) -> Self {
-    let mut res = Self::new();
-    for i in 0..N {
-      unsafe {
-        res.mut_ptr_at_unchecked(i).write(initializer(i));
-      }
-      // Adjusting the length of `res` as we go ensures any items already written to it will be
-      // dropped properly in the event that `initializer` panics at some point.
-      res.length += 1;
-    }
-    res
+  #[inline(always)]
+  pub fn count_eq(&self, value: &T) -> usize
+  where T: PartialEq {
+    self.iter().filter(|item| *item == value).count()
   }
 
-  /// Copies and appends all elements, if any, of a slice (which can also be `&mut` as it will
-  /// coerce implicitly to `&`) to the StaticVec. If the slice has a length greater than the
-  /// StaticVec's remaining capacity, any contents after that point are ignored.
-  /// Locally requires that `T` implements [`Copy`](core::marker::Copy) to avoid soundness issues.
+  /// Removes all contents from the StaticVec and sets its length back to 0.
   ///
   /// # Example usage:
   /// ```
   /// # use staticvec::*;
-  /// let mut v = StaticVec::<i32, 8>::new();
-  /// v.extend_from_slice(&[1, 2, 3, 4]);
-  /// v.extend_from_slice(&[5, 6, 7, 8, 9, 10, 11]);
-  /// assert_eq!(v, [1, 2, 3, 4, 5, 6, 7, 8]);
+  /// let mut v = staticvec![1, 2, 3];
+  /// assert_eq!(v.len(), 3);
+  /// assert_eq!(v, [1, 2, 3]);
+  /// v.clear();
+  /// assert_eq!(v.len(), 0);
+  /// assert_eq!(v, []);
   /// ```
   #[inline(always)]
-  pub const fn extend_from_slice(&mut self, values: &[T])
-  where T: Copy {
-    let old_length = self.length;
-    let added_length = const_min(values.len(), N - old_length);
-    // Safety: added_length is <= our remaining capacity and values.len.
-    unsafe {
-      values
-        .as_ptr()
-        .copy_to_nonoverlapping(self.mut_ptr_at_unchecked(old_length), added_length);
-      self.set_len(old_length + added_length);
-    }
+  pub fn clear(&mut self) {
+    // Safety: `self.as_mut_slice()` will always return either a slice of values that are known to
+    // be initialized, or simply a benign empty slice if the StaticVec it is called through is
+    // empty.
+    unsafe { ptr::drop_in_place(self.as_mut_slice()) };
+    self.length = 0;
   }
 
-  /// Copies and appends all elements, if any, of a slice to the StaticVec if the
-  /// StaticVec's remaining capacity is greater than the length of the slice, or returns
-  /// a [`CapacityError`](crate::errors::CapacityError) otherwise.
+  /// Moves all of the elements of the StaticVec into a new StaticVec and returns it, leaving the
+  /// original empty. No elements are dropped or cloned, as ownership of them is simply
+  /// transferred. This is similar to `mem::take(&mut v)`, but only copies the inhabited area as
+  /// opposed to the whole backing array.
+  ///
+  /// This is not simply named `take`, as on a `StaticVec<u8, N>` method call syntax would resolve
+  /// to [`Read::take`](https://doc.rust-lang.org/std/io/trait.Read.html#method.take) instead
+  /// whenever that trait is in scope.
   ///
   /// # Example usage:
   /// ```
   /// # use staticvec::*;
-  /// let mut v = StaticVec::<i32, 8>::new();
-  /// assert!(v.try_extend_from_slice(&[1, 2, 3, 4]).is_ok());
-  /// assert!(v.try_extend_from_slice(&[5, 6, 7, 8, 9, 10, 11]).is_err());
-  /// assert_eq!(v, [1, 2, 3, 4]);
+  /// let mut v = staticvec![Box::new(1), Box::new(2), Box::new(3)];
+  /// let v2 = v.take_all();
+  /// assert!(v.is_empty());
+  /// assert_eq!(v2, [Box::new(1), Box::new(2), Box::new(3)]);
   /// ```
-  #[inline(always)]
-  pub fn try_extend_from_slice(&mut self, values: &[T]) -> Result<(), CapacityError<N>>
-  where T: Copy {
-    let old_length = self.length;
-    let added_length = values.len();
-    if N - old_length < added_length {
-      return Err(CapacityError {});
-    }
+  #[inline]
+  pub const fn take_all(&mut self) -> Self {
+    let length = self.length;
+    let mut res = Self::new();
     unsafe {
-      values
+      // Resetting our own length first means that the elements are only ever owned by one of the
+      // two StaticVecs at any given time.
+      self.set_len(0);
+      self
         .as_ptr()
-        .copy_to_nonoverlapping(self.mut_ptr_at_unchecked(old_length), added_length);
-      self.set_len(old_length + added_length);
+        .copy_to_nonoverlapping(res.as_mut_ptr(), length);
+      res.set_len(length);
     }
-    Ok(())
+    res
   }
 
-  /// Appends `self.remaining_capacity()` (or as many as available) items from
-  /// `other` to `self`. The appended items (if any) will no longer exist in `other` afterwards,
-  /// as `other`'s `length` field will be adjusted to indicate.
+  /// Drops all of the elements of the StaticVec and returns an empty `StaticVec<U, N>` that reuses
+  /// its backing storage as-is. This is intended for arena-style code that wants to recycle a
+  /// buffer for a different (but identically laid out) element type.
   ///
-  /// The `N2` parameter does not need to be provided explicitly, and can be inferred directly from
-  /// the constant `N2` constraint of `other` (which may or may not be the same as the `N`
-  /// constraint of `self`.)
+  /// `T` and `U` are required to have exactly the same size and alignment, which is checked at
+  /// compile time: attempting to call this function with two types that do not will result in a
+  /// compilation error.
   ///
   /// # Example usage:
   /// ```
   /// # use staticvec::*;
-  /// let mut a = StaticVec::<i32, 8>::from([1, 2, 3, 4]);
-  /// let mut b = staticvec![1, 2, 3, 4, 5, 6, 7, 8];
-  /// a.append(&mut b);
-  /// assert_eq!(a.len(), 8);
-  /// assert_eq!(a, [1, 2, 3, 4, 1, 2, 3, 4]);
-  /// assert_eq!(b, [5, 6, 7, 8]);
+  /// let v = staticvec![1u32, 2, 3, 4];
+  /// let mut v2 = v.into_empty_of::<i32>();
+  /// assert!(v2.is_empty());
+  /// assert_eq!(v2.capacity(), 4);
+  /// v2.push(-1);
+  /// assert_eq!(v2, [-1]);
   /// ```
   #[inline]
-  pub const fn append<const N2: usize>(&mut self, other: &mut StaticVec<T, N2>) {
-    let old_length = self.length;
-    // Get the maximum number of items both within our capacity and within
-    // what `other` actually has to offer.
-    let item_count = const_min(N - old_length, other.length);
-    // Calculate what the length of `other` should be changed to once we've
-    // moved the items from it into self.
-    let other_new_length = other.length - item_count;
-    unsafe {
-      // Copy over the items.
-      self
-        .mut_ptr_at_unchecked(old_length)
-        .copy_from_nonoverlapping(other.as_ptr(), item_count);
-      // Shift the items leftwards in `other` if / as necessary. This only
-      // really does anything if it's the case that the remaining capacity
-      // of `self` was less than the number of items `other` had available.
-      other
-        .as_mut_ptr()
-        .copy_from(other.ptr_at_unchecked(item_count), other_new_length);
-      // Adjust the lengths of `other` and `self`.
-      other.set_len(other_new_length);
-      self.set_len(old_length + item_count);
-    }
+  pub fn into_empty_of<U>(mut self) -> StaticVec<U, N> {
+    let () = SameLayout::<T, U>::ASSERT;
+    self.clear();
+    // Safety: `T` and `U` have been verified to have the same layout, meaning so do
+    // `MaybeUninit<[T; N]>` and `MaybeUninit<[U; N]>`. The storage is entirely uninitialized as
+    // far as the returned StaticVec is concerned, as its length is zero, and since `self` no
+    // longer owns any elements it can simply be forgotten.
+    let data = unsafe { (self.data.as_ptr() as *const MaybeUninit<[U; N]>).read() };
+    mem::forget(self);
+    StaticVec { data, length: 0 }
   }
 
-  /// Returns a new StaticVec consisting of the elements of `self` and `other` concatenated in
-  /// linear fashion such that the first element of `other` comes immediately after the last
-  /// element of `self`.
-  ///
-  /// The `N2` parameter does not need to be provided explicitly, and can be inferred directly from
-  /// the constant `N2` constraint of `other` (which may or may not be the same as the `N`
-  /// constraint of `self`.)
+  /// Drops the current contents of the StaticVec and refills it to its full capacity of `N` with
+  /// values produced by [`T::default()`](core::default::Default::default), such that its length
+  /// becomes `N`.
   ///
-  /// Locally requires that `T` implements [`Copy`](core::marker::Copy) to
-  /// avoid soundness issues and also allow for a more efficient implementation than would otherwise
-  /// be possible.
+  /// If a call to `T::default()` panics partway through, the StaticVec is left containing only the
+  /// default values that were successfully produced before that point.
   ///
   /// # Example usage:
   /// ```
-  /// # use staticvec::staticvec;
-  /// assert!(staticvec!['a', 'b'].concat(&staticvec!['c', 'd']) == ['a', 'b', 'c', 'd']);
+  /// # use staticvec::*;
+  /// let mut v = StaticVec::<i32, 4>::from([1, 2]);
+  /// v.fill_default();
+  /// assert_eq!(v, [0, 0, 0, 0]);
   /// ```
-  #[inline]
-  pub const fn concat<const N2: usize>(
-    &self,
-    other: &StaticVec<T, N2>,
-  ) -> StaticVec<T, { N + N2 }>
-  where
-    T: Copy,
-  {
-    let length = self.length;
-    let other_length = other.length;
-    let mut res = StaticVec::new_data_uninit();
-    let res_ptr = res.as_mut_ptr() as *mut T;
-    unsafe {
-      // Copy over all of `self`.
-      self.as_ptr().copy_to_nonoverlapping(res_ptr, length);
-      // Copy over all of `other` starting at the position immediately following
-      // the last occupied position of the copy we just did from `self`.
-      other
-        .as_ptr()
-        .copy_to_nonoverlapping(res_ptr.add(length), other_length);
-    }
-    StaticVec {
-      data: res,
-      length: length + other_length,
-    }
+  #[inline(always)]
+  pub fn fill_default(&mut self)
+  where T: Default {
+    self.reset_to_default_len(N);
   }
 
-  /// A version of [`concat`](crate::StaticVec::concat) for scenarios where `T` does not
-  /// derive [`Copy`](core::marker::Copy) but does implement [`Clone`](core::clone::Clone).
+  /// Drops the current contents of the StaticVec and refills it with `length` values produced by
+  /// [`T::default()`](core::default::Default::default), such that its length becomes `length`.
   ///
-  /// Due to needing to call `clone()` through each individual element of `self` and `other`, this
-  /// function is less efficient than [`concat`](crate::StaticVec::concat), so
-  /// [`concat`](crate::StaticVec::concat) should be preferred whenever possible.
+  /// If a call to `T::default()` panics partway through, the StaticVec is left containing only the
+  /// default values that were successfully produced before that point.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `length` is greater than the capacity of the StaticVec.
   ///
   /// # Example usage:
   /// ```
-  /// # use staticvec::staticvec;
-  /// assert!(staticvec!["a", "b"].concat_clone(&staticvec!["c", "d"]) == ["a", "b", "c", "d"]);
+  /// # use staticvec::*;
+  /// let mut v = staticvec![String::from("a"), String::from("b"), String::from("c")];
+  /// v.reset_to_default_len(2);
+  /// assert_eq!(v, ["", ""]);
   /// ```
   #[inline]
-  pub fn concat_clone<const N2: usize>(
-    &self,
-    other: &StaticVec<T, N2>,
-  ) -> StaticVec<T, { N + N2 }>
-  where
-    T: Clone,
-  {
-    let mut res = StaticVec::new();
-    for item in self {
-      unsafe { res.push_unchecked(item.clone()) };
-    }
-    for item in other {
-      unsafe { res.push_unchecked(item.clone()) };
+  pub fn reset_to_default_len(&mut self, length: usize)
+  where T: Default {
+    assert!(
+      length <= N,
+      "Insufficient capacity in `StaticVec::reset_to_default_len`!"
+    );
+    self.clear();
+    for _ in 0..length {
+      // Safety: we've checked above that `length` is within our capacity, and pushing one value at
+      // a time means anything already produced is dropped properly if `T::default()` panics.
+      unsafe { self.push_unchecked(T::default()) };
     }
-    res
   }
 
-  /// Returns a new StaticVec consisting of the elements of `self` in linear order, interspersed
-  /// with a copy of `separator` between each one.
-  ///
-  /// Locally requires that `T` implements [`Copy`](core::marker::Copy) to
-  /// avoid soundness issues and also allow for a more efficient implementation than would otherwise
-  /// be possible.
+  /// Returns a [`StaticVecIterConst`](crate::iterators::StaticVecIterConst) over the StaticVec's
+  /// inhabited area.
   ///
   /// # Example usage:
   /// ```
-  /// # use staticvec::staticvec;
-  /// assert_eq!(
-  ///  staticvec!["A", "B", "C", "D"].intersperse("Z"),
-  ///  ["A", "Z", "B", "Z", "C", "Z", "D"]
-  /// );
+  /// # use staticvec::*;
+  /// let v = staticvec![4, 3, 2, 1];
+  /// for i in v.iter() {
+  ///   println!("{}", i);
+  /// }
   /// ```
-  #[inline]
-  pub const fn intersperse(&self, separator: T) -> StaticVec<T, { N * 2 }>
-  where T: Copy {
-    if self.is_empty() {
-      return StaticVec::new();
-    }
-    let mut res = StaticVec::new_data_uninit();
-    // The `as *mut T` cast below is necessary to make the type inference work properly (at the
-    // moment at least). `rustc` still gets a bit confused by math operations done on const generic
-    // values in return types it seems.
-    // Note that the `StaticVec::new()` calls above *have* to be written without any constraints,
-    // as otherwise we'll hit a particular bug where `rustc` says:
-    // "expected struct `StaticVec<_, { N * 2 }>`, found struct `StaticVec<_, { N * 2 }>`".
-    let mut res_ptr = StaticVec::first_ptr_mut(&mut res) as *mut T;
-    let mut i = 0;
-    let length = self.length;
-    while i < length - 1 {
-      unsafe {
-        res_ptr.write(self.ptr_at_unchecked(i).read());
-        res_ptr.offset(1).write(separator);
-        res_ptr = res_ptr.offset(2);
-      }
-      i += 1;
-    }
+  #[inline(always)]
+  pub const fn iter(&self) -> StaticVecIterConst<T, N> {
+    let start_ptr = self.as_ptr();
     unsafe {
-      res_ptr.write(self.ptr_at_unchecked(i).read());
-    }
-    StaticVec {
-      data: res,
-      length: (length * 2) - 1,
+      // `start_ptr` will never be null, so this is a safe assumption to give the optimizer.
+      assume(!start_ptr.is_null());
+      StaticVecIterConst {
+        start: start_ptr,
+        end: match size_of::<T>() {
+          0 => zst_ptr_add(start_ptr, self.length),
+          _ => start_ptr.add(self.length),
+        },
+        marker: PhantomData,
+      }
     }
   }
 
-  /// A version of [`intersperse`](crate::StaticVec::intersperse) for scenarios where `T` does not
-  /// derive [`Copy`](core::marker::Copy) but does implement [`Clone`](core::clone::Clone).
-  ///
-  /// Due to needing to call `clone()` through each individual element of `self` and also on
-  /// `separator`, this function is less efficient than
-  /// [`intersperse`](crate::StaticVec::intersperse), so
-  /// [`intersperse`](crate::StaticVec::intersperse) should be preferred whenever possible.
+  /// Returns a [`StaticVecIterMut`](crate::iterators::StaticVecIterMut) over the StaticVec's
+  /// inhabited area.
   ///
   /// # Example usage:
   /// ```
-  /// # use staticvec::staticvec;
-  /// assert_eq!(
-  ///  staticvec!["A", "B", "C", "D"].intersperse_clone("Z"),
-  ///  ["A", "Z", "B", "Z", "C", "Z", "D"]
-  /// );
+  /// # use staticvec::*;
+  /// let mut v = staticvec![4, 3, 2, 1];
+  /// for i in v.iter_mut() {
+  ///   *i -= 1;
+  /// }
+  /// assert_eq!(v, [3, 2, 1, 0]);
   /// ```
-  #[inline]
-  pub fn intersperse_clone(&self, separator: T) -> StaticVec<T, { N * 2 }>
-  where T: Clone {
-    if self.is_empty() {
-      return StaticVec::new();
-    }
-    let mut res = StaticVec::new();
-    let length = self.length;
+  #[inline(always)]
+  pub const fn iter_mut(&mut self) -> StaticVecIterMut<T, N> {
+    let start_ptr = self.as_mut_ptr();
     unsafe {
-      for i in 0..length - 1 {
-        res.push_unchecked(self.get_unchecked(i).clone());
-        res.push_unchecked(separator.clone());
+      // `start_ptr` will never be null, so this is a safe assumption to give the optimizer.
+      assume(!start_ptr.is_null());
+      StaticVecIterMut {
+        start: start_ptr,
+        end: match size_of::<T>() {
+          0 => zst_ptr_add_mut(start_ptr, self.length),
+          _ => start_ptr.add(self.length),
+        },
+        marker: PhantomData,
       }
-      res.push_unchecked(self.get_unchecked(length - 1).clone());
     }
-    res
   }
 
-  /// Returns a StaticVec containing the contents of a [`Vec`](alloc::vec::Vec) instance.
-  /// If the [`Vec`](alloc::vec::Vec) has a length greater than the declared capacity of the
-  /// resulting StaticVec, any contents after that point are ignored. Note that using this function
-  /// consumes the source [`Vec`](alloc::vec::Vec).
+  /// Returns a reversed [`StaticVecIterConst`](crate::iterators::StaticVecIterConst) over the
+  /// StaticVec's inhabited area. Equivalent to `self.iter().rev()`.
   ///
   /// # Example usage:
   /// ```
   /// # use staticvec::*;
-  /// let mut v = vec![1, 2, 3];
-  /// let sv: StaticVec<i32, 3> = StaticVec::from_vec(v);
-  /// assert_eq!(sv, [1, 2, 3]);
+  /// let v = staticvec![1, 2, 3];
+  /// let mut it = v.iter_rev();
+  /// assert_eq!(it.next(), Some(&3));
+  /// assert_eq!(it.next(), Some(&2));
+  /// assert_eq!(it.next(), Some(&1));
+  /// assert_eq!(it.next(), None);
   /// ```
-  #[cfg(feature = "std")]
-  #[doc(cfg(feature = "std"))]
-  #[inline]
-  pub fn from_vec(mut vec: Vec<T>) -> Self {
-    let vec_len = vec.len();
-    let item_count = vec_len.min(N);
-    Self {
-      data: {
-        // Set the length of `vec` to 0 to prevent double-drops.
-        unsafe { vec.set_len(0) };
-        let mut data = Self::new_data_uninit();
-        unsafe {
-          vec
-            .as_ptr()
-            .copy_to_nonoverlapping(Self::first_ptr_mut(&mut data), item_count);
-          // Manually drop any excess values in the source vec to avoid undesirable memory leaks.
-          if vec_len > item_count {
-            ptr::drop_in_place(from_raw_parts_mut(
-              vec.as_mut_ptr().add(item_count),
-              vec_len - item_count,
-            ));
-          }
-          data
-        }
-      },
-      length: item_count,
-    }
+  #[inline(always)]
+  pub fn iter_rev(&self) -> Rev<StaticVecIterConst<T, N>> {
+    self.iter().rev()
   }
 
-  /// Returns a [`Vec`](alloc::vec::Vec) containing the contents of the StaticVec instance.
-  /// The returned [`Vec`](alloc::vec::Vec) will initially have the same value for
-  /// [`len`](alloc::vec::Vec::len) and [`capacity`](alloc::vec::Vec::capacity) as the source
-  /// StaticVec. Note that using this function consumes the source StaticVec.
+  /// Returns an iterator over the StaticVec's inhabited area that yields each element along with
+  /// its index, as `(usize, &T)` pairs. Equivalent to `self.iter().enumerate()`, but with a
+  /// shorter form and a concrete return type.
   ///
   /// # Example usage:
   /// ```
   /// # use staticvec::*;
-  /// let mut sv = staticvec![1, 2, 3];
-  /// let v = sv.into_vec();
-  /// assert_eq!(v, [1, 2, 3]);
+  /// let v = staticvec!['a', 'b', 'c'];
+  /// let mut it = v.indexed();
+  /// assert_eq!(it.next(), Some((0, &'a')));
+  /// assert_eq!(it.next_back(), Some((2, &'c')));
+  /// assert_eq!(it.next(), Some((1, &'b')));
+  /// assert_eq!(it.next(), None);
   /// ```
-  #[cfg(feature = "std")]
-  #[doc(cfg(feature = "std"))]
   #[inline(always)]
-  pub fn into_vec(mut self) -> Vec<T> {
-    let mut res = Vec::with_capacity(N);
-    let length = self.length;
-    unsafe {
-      self
-        .as_ptr()
-        .copy_to_nonoverlapping(res.as_mut_ptr(), length);
-      res.set_len(length);
-      // Set the length of `self` to 0 to prevent double-drops.
-      self.set_len(0);
-      res
-    }
+  pub fn indexed(&self) -> Enumerate<StaticVecIterConst<T, N>> {
+    self.iter().enumerate()
   }
 
-  /// Inspired by the function of the same name from `ArrayVec`, this function directly returns
-  /// the StaticVec's backing array (as a "normal" array not wrapped in an instance of
-  /// `MaybeUninit`) in `Ok` if and only if the StaticVec is at maximum capacity. Otherwise, the
-  /// StaticVec itself is returned in `Err`.
+  /// Returns an iterator over the StaticVec's inhabited area that yields each element along with
+  /// its index, as `(usize, &mut T)` pairs. Equivalent to `self.iter_mut().enumerate()`, but with
+  /// a shorter form and a concrete return type.
   ///
   /// # Example usage:
   /// ```
   /// # use staticvec::*;
-  /// let mut v1 = StaticVec::<i32, 4>::new();
-  /// v1.push(1);
-  /// v1.push(2);
-  /// let a = v1.into_inner();
-  /// assert!(a.is_err());
-  /// let v2 = staticvec![1, 2, 3, 4];
-  /// let a = v2.into_inner();
-  /// assert!(a.is_ok());
-  /// assert_eq!(a.unwrap(), [1, 2, 3, 4]);
+  /// let mut v = staticvec![10, 20, 30];
+  /// for (i, x) in v.indexed_mut() {
+  ///   *x += i;
+  /// }
+  /// assert_eq!(v, [10, 21, 32]);
   /// ```
   #[inline(always)]
-  pub const fn into_inner(self) -> Result<[T; N], Self> {
-    if self.is_not_full() {
-      Err(self)
-    } else {
-      // Read out the contents of `data`. The way we do it looks silly, but is the only way for this
-      // function to be const-compatible. Note that setting `self.length` to zero here is not
-      // necessary, as `drop` will not be called on this StaticVec, so there is no risk of
-      // double-drops.
-      unsafe {
-        Ok(
-          MaybeUninit::new(self)
-            .assume_init_mut()
-            .data
-            .assume_init_read(),
-        )
-      }
-    }
+  pub fn indexed_mut(&mut self) -> Enumerate<StaticVecIterMut<T, N>> {
+    self.iter_mut().enumerate()
   }
 
-  /// Removes the specified range of elements from the StaticVec and returns them in a new one.
+  /// Returns a reversed [`StaticVecIntoIter`](crate::iterators::StaticVecIntoIter) over the
+  /// StaticVec's inhabited area, consuming the StaticVec. Equivalent to `self.into_iter().rev()`.
   ///
-  /// # Panics
-  ///
-  /// Panics if the range's starting point is greater than the end point or if the end point is
-  /// greater than the length of the StaticVec.
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let v = staticvec![Box::new(1), Box::new(2), Box::new(3)];
+  /// let r: StaticVec<Box<i32>, 3> = v.into_iter_rev().collect();
+  /// assert_eq!(r, [Box::new(3), Box::new(2), Box::new(1)]);
+  /// ```
+  #[inline(always)]
+  pub fn into_iter_rev(self) -> Rev<StaticVecIntoIter<T, N>> {
+    self.into_iter().rev()
+  }
+
+  /// Returns a "consuming" iterator that lazily yields the elements of the StaticVec in ascending
+  /// order. The elements are first moved into a min-heap in `O(n)` time, and each call to `next`
+  /// then pops the smallest remaining one in `O(log n)` time, so this is cheaper than fully sorting
+  /// the StaticVec when only part of the sorted sequence is actually needed. Locally requires that
+  /// `T` implements [`Ord`](core::cmp::Ord).
   ///
   /// # Example usage:
   /// ```
   /// # use staticvec::*;
-  /// let mut v = staticvec![1, 2, 3];
-  /// let u = v.drain(1..);
-  /// assert_eq!(v, &[1]);
+  /// let v = staticvec![5, 1, 4, 2, 6, 3];
+  /// let smallest = v.into_sorted_iter().take(3).collect::<StaticVec<_, 3>>();
+  /// assert_eq!(smallest, [1, 2, 3]);
   /// ```
-  // No Copy bounds here because the original StaticVec gives up all access to the values in
-  // question.
   #[inline]
-  pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Self {
-    // Borrowed this part from normal Vec's implementation.
-    let old_length = self.length;
-    let start = match range.start_bound() {
-      Included(&idx) => idx,
-      Excluded(&idx) => idx + 1,
-      Unbounded => 0,
-    };
-    let end = match range.end_bound() {
-      Included(&idx) => idx + 1,
-      Excluded(&idx) => idx,
-      Unbounded => old_length,
-    };
-    assert!(
-      start <= end && end <= old_length,
-      "Bounds check failure in `StaticVec::drain`!"
-    );
-    let res_length = end - start;
-    Self {
-      data: {
-        let mut res = Self::new_data_uninit();
-        unsafe {
-          self
-            .ptr_at_unchecked(start)
-            .copy_to_nonoverlapping(Self::first_ptr_mut(&mut res), res_length);
-          let mp = self.as_mut_ptr();
-          mp.add(end).copy_to(mp.add(start), old_length - end);
-          self.set_len(old_length - res_length);
-          res
-        }
-      },
-      length: res_length,
+  pub fn into_sorted_iter(self) -> StaticVecIntoIterSorted<T, N>
+  where T: Ord {
+    StaticVecIntoIterSorted {
+      inner: StaticHeap::from(self.into_iter().map(Reverse).collect::<StaticVec<_, N>>()),
     }
   }
 
-  /// Removes the specified range of elements from the StaticVec and returns them in a
-  /// [`StaticVecDrain`](crate::iterators::StaticVecDrain).
+  /// Consumes the StaticVec, moving its elements in linear order into batches of up to `C`
+  /// elements each, which are themselves collected into an outer StaticVec with a capacity of `M`.
+  /// Every batch but the last one will be of length `C`, while the last one will contain the
+  /// leftover `self.len() % C` elements if the length of the StaticVec is not evenly divisible by
+  /// `C`. No batches (and thus no empty ones) are created for an empty StaticVec.
   ///
   /// # Panics
   ///
-  /// Panics if the range's starting point is greater than the end point or if the end point is
-  /// greater than the length of the StaticVec.
+  /// Panics if `C` is zero, or if the number of batches needed is greater than `M`.
   ///
   /// # Example usage:
   /// ```
   /// # use staticvec::*;
-  /// let mut v1 = staticvec![0, 4, 5, 6, 7];
-  /// let v2: StaticVec<i32, 3> = v1.drain_iter(1..4).rev().collect();
-  /// assert_eq!(v2, [6, 5, 4]);
+  /// let v = staticvec![1, 2, 3, 4, 5];
+  /// let batches: StaticVec<StaticVec<i32, 2>, 3> = v.into_batches();
+  /// assert_eq!(batches, [staticvec![1, 2], staticvec![3, 4], staticvec![5]]);
   /// ```
   #[inline]
-  pub fn drain_iter<R: RangeBounds<usize>>(&mut self, range: R) -> StaticVecDrain<T, N> {
-    // Borrowed this part from normal Vec's implementation.
-    let length = self.length;
-    let start = match range.start_bound() {
-      Included(&idx) => idx,
-      Excluded(&idx) => idx + 1,
-      Unbounded => 0,
-    };
-    let end = match range.end_bound() {
-      Included(&idx) => idx + 1,
-      Excluded(&idx) => idx,
-      Unbounded => length,
-    };
+  pub fn into_batches<const C: usize, const M: usize>(self) -> StaticVec<StaticVec<T, C>, M> {
+    assert!(C != 0, "`StaticVec::into_batches` was called with a batch size of zero!");
     assert!(
-      start <= end && end <= length,
-      "Bounds check failure in `StaticVec::drain_iter`!"
+      self.length / C + (self.length % C != 0) as usize <= M,
+      "Insufficient capacity in `StaticVec::into_batches`!"
     );
-    unsafe {
-      // Set the length to `start` to avoid memory issues if anything goes wrong with the Drain.
-      self.set_len(start);
-      let start_ptr = match size_of::<T>() {
-        0 => zst_ptr_add(self.as_ptr(), start),
-        _ => self.ptr_at_unchecked(start),
-      };
-      // `start_ptr` will never be null, so this is a safe assumption to give to
-      // the optimizer.
-      assume(!start_ptr.is_null());
-      // Create the StaticVecDrain from the specified range.
-      StaticVecDrain {
-        start: end,
-        length: length - end,
-        iter: StaticVecIterConst {
-          start: start_ptr,
-          end: match size_of::<T>() {
-            0 => zst_ptr_add(self.as_ptr(), end),
-            _ => self.ptr_at_unchecked(end),
-          },
-          marker: PhantomData,
-        },
-        vec: self,
+    let mut res = StaticVec::<StaticVec<T, C>, M>::new();
+    // Going through `into_iter` means that the elements not yet moved into a batch are still
+    // dropped properly even if something goes wrong partway through.
+    let mut iter = self.into_iter();
+    while iter.len() > 0 {
+      let mut batch = StaticVec::new();
+      // `iter.by_ref().take(C)` yields at most `C` elements, so this is always in bounds.
+      for item in iter.by_ref().take(C) {
+        unsafe { batch.push_unchecked(item) };
       }
+      unsafe { res.push_unchecked(batch) };
     }
+    res
   }
 
-  /// Removes all elements in the StaticVec for which `filter` returns true and returns them in a
-  /// new one.
+  /// Returns an iterator over the inhabited area of the StaticVec in non-overlapping chunks of
+  /// exactly `C` elements each, yielded as `&[T; C]` and starting from the back. If the length of
+  /// the StaticVec is not evenly divisible by `C`, the leftover `self.len() % C` elements at the
+  /// front are not yielded, but can be accessed through the
+  /// [`remainder`](crate::iterators::StaticVecArrayRChunks::remainder) method of the returned
+  /// iterator.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `C` is zero.
   ///
   /// # Example usage:
   /// ```
   /// # use staticvec::*;
-  /// let mut numbers = staticvec![1, 2, 3, 4, 5, 6, 8, 9, 11, 13, 14, 15];
-  /// let evens = numbers.drain_filter(|x| *x % 2 == 0);
-  /// let odds = numbers;
-  /// assert_eq!(evens, [2, 4, 6, 8, 14]);
-  /// assert_eq!(odds, [1, 3, 5, 9, 11, 13, 15]);
+  /// let v = staticvec![1, 2, 3, 4, 5, 6, 7];
+  /// let mut it = v.rarray_chunks::<3>();
+  /// assert_eq!(it.len(), 2);
+  /// assert_eq!(it.remainder(), &[1]);
+  /// assert_eq!(it.next(), Some(&[5, 6, 7]));
+  /// assert_eq!(it.next(), Some(&[2, 3, 4]));
+  /// assert_eq!(it.next(), None);
   /// ```
   #[inline]
-  pub fn drain_filter<F: FnMut(&mut T) -> bool>(&mut self, mut filter: F) -> Self {
-    let old_length = self.length;
-    // Temporarily set our length to 0 to avoid double drops and such if anything
-    // goes wrong in the filter loop.
-    self.length = 0;
-    let mut res = Self::new();
-    let mut res_length = 0;
-    unsafe {
-      // If `self.length` was already 0, this loop is skipped completely.
-      for i in 0..old_length {
-        // This is fine because we intentionally set `self.length` to `0` ourselves just now.
-        if filter(self.get_unchecked_mut(i)) {
-          res
-            .mut_ptr_at_unchecked(res_length)
-            .write(self.ptr_at_unchecked(i).read());
-          res_length += 1;
-        } else if res_length > 0 {
-          let mp = self.as_mut_ptr();
-          mp.add(i).copy_to_nonoverlapping(mp.add(i - res_length), 1);
-        }
-      }
+  pub fn rarray_chunks<const C: usize>(&self) -> StaticVecArrayRChunks<T, C> {
+    assert!(C != 0, "`StaticVec::rarray_chunks` was called with a chunk size of zero!");
+    StaticVecArrayRChunks {
+      iter: self.as_slice().rchunks_exact(C),
     }
-    self.length = old_length - res_length;
-    res.length = res_length;
-    res
   }
 
-  /// Replaces the specified range in the StaticVec with the contents of `replace_with` and returns
-  /// the removed items in an instance of [`StaticVecSplice`](crate::iterators::StaticVecSplice).
-  /// `replace_with` does not need to be the same length as `range`. Returns immediately if and when
-  /// the StaticVec reaches maximum capacity, regardless of whether or not `replace_with` still has
-  /// more items to yield.
+  /// Returns an iterator over windows of `size` elements into the inhabited area of the
+  /// StaticVec, with the start of each window being `step` elements after the start of the
+  /// previous one. This generalizes [`windows`](https://doc.rust-lang.org/nightly/std/primitive.slice.html#method.windows)
+  /// (which is equivalent to a `step` of `1`), and is useful for things like audio framing. Any
+  /// trailing elements that don't make up a full window are not yielded.
   ///
   /// # Panics
   ///
-  /// Panics if the range's starting point is greater than the end point or if the end point is
-  /// greater than the length of the StaticVec.
+  /// Panics if either `size` or `step` is zero.
   ///
   /// # Example usage:
   /// ```
   /// # use staticvec::*;
-  /// let mut v = staticvec![1, 2, 3];
-  /// let new = [7, 8];
-  /// let u: StaticVec<u8, 2> = v.splice(..2, new.iter().copied()).collect();
-  /// assert_eq!(v, [7, 8, 3]);
-  /// assert_eq!(u, [1, 2]);
+  /// let v = staticvec![1, 2, 3, 4, 5, 6];
+  /// let mut it = v.windows_step(3, 2);
+  /// assert_eq!(it.len(), 2);
+  /// assert_eq!(it.next(), Some(&[1, 2, 3][..]));
+  /// assert_eq!(it.next(), Some(&[3, 4, 5][..]));
+  /// assert_eq!(it.next(), None);
   /// ```
   #[inline]
-  pub fn splice<R: RangeBounds<usize>, I: IntoIterator<Item = T>>(
-    &mut self,
-    range: R,
-    replace_with: I,
-  ) -> StaticVecSplice<T, I::IntoIter, N> {
-    let length = self.length;
-    let start = match range.start_bound() {
-      Included(&idx) => idx,
-      Excluded(&idx) => idx + 1,
-      Unbounded => 0,
-    };
-    let end = match range.end_bound() {
-      Included(&idx) => idx + 1,
-      Excluded(&idx) => idx,
-      Unbounded => length,
-    };
+  pub fn windows_step(&self, size: usize, step: usize) -> StaticVecWindowsStep<T> {
     assert!(
-      start <= end && end <= length,
-      "Bounds check failure in `StaticVec::splice`!"
+      size != 0 && step != 0,
+      "`StaticVec::windows_step` was called with a `size` or `step` of zero!"
     );
-    StaticVecSplice {
-      start,
-      end,
-      replace_with: replace_with.into_iter(),
-      vec: self,
-    }
+    StaticVecWindowsStep::new(self.as_slice(), size, step)
   }
 
-  /// Removes all elements in the StaticVec for which `filter` returns false.
+  /// Returns an iterator over every `step`-th element of the StaticVec's inhabited area, starting
+  /// with the first one. This is equivalent to `self.iter().step_by(step)`, and so returns a
+  /// concrete [`StepBy`](core::iter::StepBy) wrapping a
+  /// [`StaticVecIterConst`](crate::iterators::StaticVecIterConst), which implements
+  /// [`ExactSizeIterator`](core::iter::ExactSizeIterator) and
+  /// [`DoubleEndedIterator`](core::iter::DoubleEndedIterator).
+  ///
+  /// # Panics
+  ///
+  /// Panics if `step` is zero.
   ///
   /// # Example usage:
   /// ```
   /// # use staticvec::*;
-  /// let mut v = staticvec![1, 2, 3, 4, 5];
-  /// let keep = staticvec![false, true, true, false, true];
-  /// let mut i = 0;
-  /// v.retain(|_| (keep[i], i += 1).0);
-  /// assert_eq!(v, [2, 3, 5]);
+  /// let v = staticvec![1, 2, 3, 4, 5, 6, 7];
+  /// let mut it = v.stride(3);
+  /// assert_eq!(it.len(), 3);
+  /// assert_eq!(it.next(), Some(&1));
+  /// assert_eq!(it.next(), Some(&4));
+  /// assert_eq!(it.next(), Some(&7));
+  /// assert_eq!(it.next(), None);
   /// ```
   #[inline(always)]
-  pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut filter: F) {
-    self.drain_filter(|val| !filter(val));
+  pub fn stride(&self, step: usize) -> StepBy<StaticVecIterConst<T, N>> {
+    assert!(step != 0, "`StaticVec::stride` was called with a `step` of zero!");
+    self.iter().step_by(step)
   }
 
-  /// Shortens the StaticVec, keeping the first `length` elements and dropping the rest.
-  /// Does nothing if `length` is greater than or equal to the current length of the StaticVec.
+  /// Calls `f` on a mutable reference to each element of the StaticVec, in order. Equivalent to
+  /// `self.iter_mut().for_each(f)`.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let mut v = staticvec![1, 2, 3];
+  /// v.apply(|x| *x *= 2);
+  /// assert_eq!(v, [2, 4, 6]);
+  /// ```
+  #[inline(always)]
+  pub fn apply<F: FnMut(&mut T)>(&mut self, f: F) {
+    self.iter_mut().for_each(f);
+  }
+
+  /// Calls `f` on the index of and a mutable reference to each element of the StaticVec, in
+  /// order.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let mut v = staticvec![1, 1, 1];
+  /// v.apply_indexed(|i, x| *x += i);
+  /// assert_eq!(v, [1, 2, 3]);
+  /// ```
+  #[inline(always)]
+  pub fn apply_indexed<F: FnMut(usize, &mut T)>(&mut self, mut f: F) {
+    for (i, item) in self.iter_mut().enumerate() {
+      f(i, item);
+    }
+  }
+
+  /// Returns a separate, stable-sorted StaticVec of the contents of the StaticVec's inhabited area
+  /// without modifying the original data. Locally requires that `T` implements
+  /// [`Copy`](core::marker::Copy) to avoid soundness issues, and [`Ord`](core::cmp::Ord) to make
+  /// the sorting possible.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::{staticvec, StaticVec};
+  /// const V: StaticVec<StaticVec<i32, 2>, 2> = staticvec![staticvec![1, 3], staticvec![4, 2]];
+  /// assert_eq!(
+  ///   V.iter().flatten().collect::<StaticVec<i32, 4>>().sorted(),
+  ///   [1, 2, 3, 4]
+  /// );
+  /// ```
+  #[cfg(feature = "std")]
+  #[doc(cfg(feature = "std"))]
+  #[inline]
+  pub fn sorted(&self) -> Self
+  where T: Copy + Ord {
+    // StaticVec uses specialization to have an optimized version of `Clone` for copy types.
+    let mut res = self.clone();
+    res.sort();
+    res
+  }
+
+  /// Returns a separate, unstable-sorted StaticVec of the contents of the StaticVec's inhabited
+  /// area without modifying the original data. Locally requires that `T` implements
+  /// [`Copy`](core::marker::Copy) to avoid soundness issues, and [`Ord`](core::cmp::Ord) to make
+  /// the sorting possible.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::{staticvec, StaticVec};
+  /// const V: StaticVec<StaticVec<i32, 2>, 2> = staticvec![staticvec![1, 3], staticvec![4, 2]];
+  /// assert_eq!(
+  ///   V.iter().flatten().collect::<StaticVec<i32, 4>>().sorted_unstable(),
+  ///   [1, 2, 3, 4]
+  /// );
+  /// ```
+  #[inline]
+  pub fn sorted_unstable(&self) -> Self
+  where T: Copy + Ord {
+    // StaticVec uses specialization to have an optimized version of `Clone` for copy types.
+    let mut res = self.clone();
+    res.sort_unstable();
+    res
+  }
+
+  /// Returns a separate, unstable-quicksorted StaticVec of the contents of the StaticVec's
+  /// inhabited area without modifying the original data. Locally requires that `T` implements
+  /// [`Copy`](core::marker::Copy) to avoid soundness issues, and
+  /// [`PartialOrd`](core::cmp::PartialOrd) to make the sorting possible.
+  ///
+  /// Unlike [`sorted`](crate::StaticVec::sorted) and
+  /// [`sorted_unstable`](crate::StaticVec::sorted_unstable), this function does not make use of
+  /// Rust's built-in sorting methods, but instead makes direct use of a comparatively
+  /// unsophisticated recursive quicksort algorithm implemented in this crate.
+  ///
+  /// This has the advantage of only needing to have [`PartialOrd`](core::cmp::PartialOrd) as a
+  /// constraint as opposed to [`Ord`](core::cmp::Ord), but is very likely less performant for
+  /// most inputs, so if the type you're sorting does derive or implement
+  /// [`Ord`](core::cmp::Ord) it's recommended that you use [`sorted`](crate::StaticVec::sorted) or
+  /// [`sorted_unstable`](crate::StaticVec::sorted_unstable) instead of this function.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::{staticvec, StaticVec};
+  /// const V: StaticVec<StaticVec<i32, 2>, 2> = staticvec![staticvec![1, 3], staticvec![4, 2]];
+  /// assert_eq!(
+  ///   V.iter().flatten().collect::<StaticVec<i32, 4>>().quicksorted_unstable(),
+  ///   [1, 2, 3, 4]
+  /// );
+  /// ```
+  #[inline]
+  pub const fn quicksorted_unstable(&self) -> Self
+  where T: Copy + ~const PartialOrd {
+    let length = self.length;
+    if length < 2 {
+      // StaticVec uses specialization to have an optimized verson of `Clone` for copy types.
+      return self.clone();
+    }
+    let mut res = Self::new_data_uninit();
+    let res_ptr = Self::first_ptr_mut(&mut res);
+    // Copy the inhabited part of `self` into the array we'll use for the returned StaticVec.
+    unsafe { self.as_ptr().copy_to_nonoverlapping(res_ptr, length) };
+    // Sort the array, and then build and return a new StaticVec from it.
+    quicksort_internal(res_ptr, 0, (length - 1) as isize);
+    Self { data: res, length }
+  }
+
+  /// Provides the same sorting functionality as
+  /// [`quicksorted_unstable`](crate::StaticVec::quicksorted_unstable) (and has the same trait
+  /// bound requirements) but operates in-place on the calling StaticVec instance rather than
+  /// returning the sorted data in a new one.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let mut v = staticvec![5.0, 4.0, 3.0, 2.0, 1.0];
+  /// v.quicksort_unstable();
+  /// assert_eq!(v, [1.0, 2.0, 3.0, 4.0, 5.0]);
+  /// // Note that if you are actually sorting floating-point numbers as shown above, and the
+  /// // StaticVec contains one or more instances of NAN, the "accuracy" of the sorting will
+  /// // essentially be determined by a combination of how many *consecutive* NANs there are,
+  /// // as well as how "mixed up" the surrounding valid numbers were to begin with. In any case,
+  /// // the outcome of this particular hypothetical scenario will never be any worse than the
+  /// // values simply not being sorted quite as you'd hoped.
+  /// ```
+  #[inline]
+  pub const fn quicksort_unstable(&mut self)
+  where T: Copy + /*~const */PartialOrd {
+    let length = self.length;
+    if length < 2 {
+      return;
+    }
+    let self_ptr = self.as_mut_ptr();
+    // We know self_ptr will never be null, so this is a safe hint to give the optimizer.
+    unsafe { assume(!self_ptr.is_null()) };
+    quicksort_internal(self_ptr, 0, (length - 1) as isize);
+  }
+
+  /// Reorders the StaticVec in-place such that the element at `index` is the one that would be
+  /// there if the StaticVec were fully sorted, with every element before it being less than or
+  /// equal to it and every element after it being greater than or equal to it, and returns a
+  /// mutable reference to it. This is done by way of quickselect in `O(n)` time on average, making
+  /// it useful for things like finding medians or percentiles without a full sort. Locally requires
+  /// that `T` implements [`Ord`](core::cmp::Ord).
+  ///
+  /// This is directly equivalent to the second return value of
+  /// [`slice::select_nth_unstable`](https://doc.rust-lang.org/nightly/std/primitive.slice.html#method.select_nth_unstable),
+  /// which it uses internally.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `index` is greater than or equal to the length of the StaticVec.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let mut v = staticvec![9, 1, 8, 2, 7, 3, 6];
+  /// assert_eq!(*v.nth_smallest(3), 6);
+  /// assert!(v[..3].iter().all(|x| *x <= 6));
+  /// assert!(v[4..].iter().all(|x| *x >= 6));
+  /// ```
+  #[inline]
+  pub fn nth_smallest(&mut self, index: usize) -> &mut T
+  where T: Ord {
+    assert!(
+      index < self.length,
+      "Bounds check failure in `StaticVec::nth_smallest`!"
+    );
+    self.as_mut_slice().select_nth_unstable(index).1
+  }
+
+  /// Returns a separate, reversed StaticVec of the contents of the StaticVec's inhabited area
+  /// without modifying the original data. Locally requires that `T` implements
+  /// [`Copy`](core::marker::Copy) to avoid soundness issues.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// assert_eq!(staticvec![1, 2, 3].reversed(), [3, 2, 1]);
+  /// ```
+  #[inline(always)]
+  pub const fn reversed(&self) -> Self
+  where T: Copy {
+    Self {
+      data: reverse_copy(self.length, &self.data),
+      length: self.length,
+    }
+  }
+
+  /// Reverses the order of the elements within `range` in place, leaving everything outside of it
+  /// untouched. The range is interpreted relative to the StaticVec's inhabited area.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the starting point is greater than the end point or if the end point is greater
+  /// than the length of the StaticVec.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let mut v = staticvec![1, 2, 3, 4, 5, 6];
+  /// v.reverse_range(1..5);
+  /// assert_eq!(v, [1, 5, 4, 3, 2, 6]);
+  /// v.reverse_range(..);
+  /// assert_eq!(v, [6, 2, 3, 4, 5, 1]);
+  /// ```
+  #[inline]
+  pub fn reverse_range<R: RangeBounds<usize>>(&mut self, range: R) {
+    let (start, end) =
+      self.decode_range(range, "Bounds check failure in `StaticVec::reverse_range`!");
+    assert!(
+      start <= end && end <= self.length,
+      "Bounds check failure in `StaticVec::reverse_range`!"
+    );
+    if end - start < 2 {
+      return;
+    }
+    let (mut i, mut j) = (start, end - 1);
+    while i < j {
+      // Safety: `i` and `j` are always distinct indices within the bounds checked above.
+      unsafe { ptr::swap(self.mut_ptr_at_unchecked(i), self.mut_ptr_at_unchecked(j)) };
+      i += 1;
+      j -= 1;
+    }
+  }
+
+  /// Shuffles the contents of the StaticVec in place using the Fisher-Yates algorithm, with `rng`
+  /// as the source of randomness.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// use rand::{rngs::StdRng, SeedableRng};
+  /// let mut v = staticvec![1, 2, 3, 4, 5];
+  /// v.shuffle(&mut StdRng::seed_from_u64(42));
+  /// assert_eq!(v.len(), 5);
+  /// assert_eq!(v.sorted_unstable(), [1, 2, 3, 4, 5]);
+  /// ```
+  #[cfg(feature = "rand")]
+  #[doc(cfg(feature = "rand"))]
+  #[inline]
+  pub fn shuffle<R: rand::Rng + ?Sized>(&mut self, rng: &mut R) {
+    let mp = self.as_mut_ptr();
+    let mut i = self.length;
+    while i > 1 {
+      let j = rng.gen_range(0..i);
+      i -= 1;
+      // Safety: both `i` and `j` are always strictly less than `self.length`.
+      unsafe { ptr::swap(mp.add(i), mp.add(j)) };
+    }
+  }
+
+  /// Returns a constant reference to a randomly chosen element of the StaticVec in `Some`, with
+  /// `rng` as the source of randomness, or `None` if the StaticVec is empty.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// use rand::{rngs::StdRng, SeedableRng};
+  /// let mut rng = StdRng::seed_from_u64(42);
+  /// let v = staticvec![1, 2, 3, 4, 5];
+  /// assert!(v.contains(v.choose(&mut rng).unwrap()));
+  /// assert_eq!(StaticVec::<i32, 4>::new().choose(&mut rng), None);
+  /// ```
+  #[cfg(feature = "rand")]
+  #[doc(cfg(feature = "rand"))]
+  #[inline]
+  pub fn choose<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Option<&T> {
+    match self.length {
+      0 => None,
+      length => Some(unsafe { self.get_unchecked(rng.gen_range(0..length)) }),
+    }
+  }
+
+  /// Returns a new StaticVec instance filled with the return value of an initializer function.
+  /// The length field of the newly created StaticVec will be equal to its capacity.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::StaticVec;
+  /// let mut i = 0;
+  /// let v = StaticVec::<i32, 64>::filled_with(|| { i += 1; i });
+  /// assert_eq!(v.len(), 64);
+  /// assert_eq!(v[0], 1);
+  /// assert_eq!(v[1], 2);
+  /// assert_eq!(v[2], 3);
+  /// assert_eq!(v[3], 4);
+  /// ```
+  #[inline]
+  pub fn filled_with<F: FnMut() -> T>(mut initializer: F) -> Self {
+    let mut res = Self::new();
+    for i in 0..N {
+      unsafe {
+        res.mut_ptr_at_unchecked(i).write(initializer());
+      }
+      // Adjusting the length of `res` as we go ensures any items already written to it will be
+      // dropped properly in the event that `initializer` panics at some point.
+      res.length += 1;
+    }
+    res
+  }
+
+  /// Returns a new StaticVec instance filled with the return value of an initializer function.
+  /// Unlike for [`filled_with`](crate::StaticVec::filled_with), the initializer function in
+  /// this case must take a single usize variable as an input parameter, which will be called
+  /// with the current index of the `0..N` loop that
+  /// [`filled_with_by_index`](crate::StaticVec::filled_with_by_index) is implemented with
+  /// internally. The length field of the newly created StaticVec will be equal to its capacity.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::StaticVec;
+  /// let v = StaticVec::<usize, 64>::filled_with_by_index(|i| { i + 1 });
+  /// assert_eq!(v.len(), 64);
+  /// assert_eq!(v[0], 1);
+  /// assert_eq!(v[1], 2);
+  /// assert_eq!(v[2], 3);
+  /// assert_eq!(v[3], 4);
+  /// ```
+  #[inline]
+  pub fn filled_with_by_index<F: FnMut(usize) -> T>(mut initializer: F) -> Self {
+    let mut res = Self::new();
+    for i in 0..N {
+      unsafe {
+        res.mut_ptr_at_unchecked(i).write(initializer(i));
+      }
+      // Adjusting the length of `res` as we go ensures any items already written to it will be
+      // dropped properly in the event that `initializer` panics at some point.
+      res.length += 1;
+    }
+    res
+  }
+
+  /// Returns a new StaticVec instance with each position `i` of its `0..N` capacity filled with
+  /// the return value of `f(i)`, in order. This is exactly the same as
+  /// [`filled_with_by_index`](crate::StaticVec::filled_with_by_index), but named to match
+  /// [`array::from_fn`](https://doc.rust-lang.org/nightly/core/array/fn.from_fn.html). The length
+  /// field of the newly created StaticVec will be equal to its capacity.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let v = StaticVec::<usize, 4>::from_fn(|i| i * i);
+  /// assert_eq!(v, [0, 1, 4, 9]);
+  /// ```
+  #[inline(always)]
+  pub fn from_fn<F: FnMut(usize) -> T>(f: F) -> Self {
+    Self::filled_with_by_index(f)
+  }
+
+  /// Returns a new StaticVec instance with only the first `count` positions of its capacity
+  /// filled with the return value of `f(i)` for each index `i`, in order. The length field of the
+  /// newly created StaticVec will be equal to `count`.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `count` is greater than the StaticVec's declared capacity.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let v = StaticVec::<usize, 8>::from_fn_n(3, |i| i + 10);
+  /// assert_eq!(v, [10, 11, 12]);
+  /// assert_eq!(v.remaining_capacity(), 5);
+  /// ```
+  #[inline]
+  pub fn from_fn_n<F: FnMut(usize) -> T>(count: usize, mut f: F) -> Self {
+    assert!(count <= N, "Insufficient capacity in `StaticVec::from_fn_n`!");
+    let mut res = Self::new();
+    for i in 0..count {
+      // Pushing as we go ensures any items already written to `res` will be dropped properly in
+      // the event that `f` panics at some point.
+      unsafe { res.push_unchecked(f(i)) };
+    }
+    res
+  }
+
+  /// Writes the return value of `f(i)` into each position `i` of the StaticVec described by
+  /// `range`, extending the length of the StaticVec to the end of the range if necessary. Any
+  /// existing elements within the range are dropped as they are replaced. This is essentially a
+  /// generalization of [`filled_with_by_index`](crate::StaticVec::filled_with_by_index) that
+  /// works on a sub-range of an existing StaticVec.
+  ///
+  /// If `f` panics at some point, the StaticVec is left containing all of the elements written
+  /// up until that point (along with any of its original elements that had not yet been replaced).
+  ///
+  /// # Panics
+  ///
+  /// Panics if the range's starting point is greater than the end point, if the starting point is
+  /// greater than the current length of the StaticVec (as that would leave a gap of uninitialized
+  /// elements), or if the end point is greater than `N`.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let mut v = StaticVec::<usize, 6>::from([9, 9, 9]);
+  /// v.init_range(1..5, |i| i * 10);
+  /// assert_eq!(v, [9, 10, 20, 30, 40]);
+  /// v.init_range(.., |i| i);
+  /// assert_eq!(v, [0, 1, 2, 3, 4, 5]);
+  /// ```
+  #[inline]
+  pub fn init_range<R: RangeBounds<usize>, F: FnMut(usize) -> T>(&mut self, range: R, mut f: F) {
+    // Unlike everywhere else, an unbounded end here means "up to the capacity" rather than "up to
+    // the current length".
+    let unbounded_end = matches!(range.end_bound(), Unbounded);
+    let (start, mut end) =
+      self.decode_range(range, "Bounds check failure in `StaticVec::init_range`!");
+    if unbounded_end {
+      end = N;
+    }
+    assert!(
+      start <= end && start <= self.length && end <= N,
+      "Bounds check failure in `StaticVec::init_range`!"
+    );
+    for i in start..end {
+      // We call `f` before touching anything, so that the StaticVec is always in a valid state
+      // if it panics.
+      let value = f(i);
+      if i < self.length {
+        // This position is already initialized, so assigning to it drops the old value.
+        unsafe { *self.get_unchecked_mut(i) = value };
+      } else {
+        // This position is uninitialized, so we just write to it and extend the length by one.
+        unsafe { self.push_unchecked(value) };
+      }
+    }
+  }
+
+  /// Copies and appends all elements, if any, of a slice (which can also be `&mut` as it will
+  /// coerce implicitly to `&`) to the StaticVec. If the slice has a length greater than the
+  /// StaticVec's remaining capacity, any contents after that point are ignored.
+  /// Locally requires that `T` implements [`Copy`](core::marker::Copy) to avoid soundness issues.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let mut v = StaticVec::<i32, 8>::new();
+  /// v.extend_from_slice(&[1, 2, 3, 4]);
+  /// v.extend_from_slice(&[5, 6, 7, 8, 9, 10, 11]);
+  /// assert_eq!(v, [1, 2, 3, 4, 5, 6, 7, 8]);
+  /// ```
+  #[inline(always)]
+  pub const fn extend_from_slice(&mut self, values: &[T])
+  where T: Copy {
+    let old_length = self.length;
+    let added_length = const_min(values.len(), N - old_length);
+    // Safety: added_length is <= our remaining capacity and values.len.
+    unsafe {
+      values
+        .as_ptr()
+        .copy_to_nonoverlapping(self.mut_ptr_at_unchecked(old_length), added_length);
+      self.set_len(old_length + added_length);
+    }
+  }
+
+  /// Copies and appends all elements, if any, of a slice to the StaticVec if the
+  /// StaticVec's remaining capacity is greater than the length of the slice, or returns
+  /// a [`CapacityError`](crate::errors::CapacityError) otherwise.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let mut v = StaticVec::<i32, 8>::new();
+  /// assert!(v.try_extend_from_slice(&[1, 2, 3, 4]).is_ok());
+  /// assert!(v.try_extend_from_slice(&[5, 6, 7, 8, 9, 10, 11]).is_err());
+  /// assert_eq!(v, [1, 2, 3, 4]);
+  /// ```
+  #[inline(always)]
+  pub fn try_extend_from_slice(&mut self, values: &[T]) -> Result<(), CapacityError<N>>
+  where T: Copy {
+    let old_length = self.length;
+    let added_length = values.len();
+    if N - old_length < added_length {
+      return Err(CapacityError {});
+    }
+    unsafe {
+      values
+        .as_ptr()
+        .copy_to_nonoverlapping(self.mut_ptr_at_unchecked(old_length), added_length);
+      self.set_len(old_length + added_length);
+    }
+    Ok(())
+  }
+
+  /// Replaces the contents of the StaticVec with clones of the elements of `src`, first dropping
+  /// the existing contents. Unlike [`extend_from_slice`](crate::StaticVec::extend_from_slice), this
+  /// replaces rather than appends, and unlike the slice method of the same name (which this takes
+  /// precedence over in method call syntax) `src` does not need to be the same length as the
+  /// StaticVec.
+  ///
+  /// If a call to `clone` panics partway through, the StaticVec is left containing only the
+  /// elements that were successfully cloned before that point.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the length of `src` is greater than the capacity of the StaticVec.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let mut v = staticvec![Box::new(1), Box::new(2), Box::new(3)];
+  /// v.clone_from_slice(&[Box::new(4), Box::new(5)]);
+  /// assert_eq!(v, [Box::new(4), Box::new(5)]);
+  /// ```
+  #[inline]
+  pub fn clone_from_slice(&mut self, src: &[T])
+  where T: Clone {
+    assert!(
+      src.len() <= N,
+      "Insufficient capacity in `StaticVec::clone_from_slice`!"
+    );
+    self.clear();
+    for value in src {
+      // Safety: we've checked above that `src` fits within our capacity, and pushing one element
+      // at a time means anything already cloned is dropped properly if a later `clone` panics.
+      unsafe { self.push_unchecked(value.clone()) };
+    }
+  }
+
+  /// Replaces the contents of the StaticVec with a copy of the elements of `src`. This is the
+  /// [`Copy`](core::marker::Copy)-based counterpart to
+  /// [`clone_from_slice`](crate::StaticVec::clone_from_slice), and similarly takes precedence over
+  /// the slice method of the same name in method call syntax while not requiring `src` to be the
+  /// same length as the StaticVec.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the length of `src` is greater than the capacity of the StaticVec.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let mut v = staticvec![1, 2, 3, 4];
+  /// v.copy_from_slice(&[5, 6]);
+  /// assert_eq!(v, [5, 6]);
+  /// ```
+  #[inline]
+  pub const fn copy_from_slice(&mut self, src: &[T])
+  where T: Copy {
+    assert!(
+      src.len() <= N,
+      "Insufficient capacity in `StaticVec::copy_from_slice`!"
+    );
+    // Safety: `T` is `Copy`, so nothing needs to be dropped, and we've checked above that `src`
+    // fits within our capacity.
+    unsafe {
+      src
+        .as_ptr()
+        .copy_to_nonoverlapping(Self::first_ptr_mut(&mut self.data), src.len());
+      self.set_len(src.len());
+    }
+  }
+
+  /// Appends elements from `iter` to the StaticVec until either the StaticVec is full or `iter` is
+  /// exhausted, and returns the number of elements that were added. Passing `iter` by mutable
+  /// reference (for example with [`by_ref`](core::iter::Iterator::by_ref)) allows for it to
+  /// continue to be used afterwards, with only the elements that were actually added having been
+  /// consumed from it.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let mut v = StaticVec::<i32, 4>::from([1]);
+  /// let mut it = 2..10;
+  /// assert_eq!(v.fill_from_iter(it.by_ref()), 3);
+  /// assert_eq!(v, [1, 2, 3, 4]);
+  /// assert_eq!(it.next(), Some(5));
+  /// ```
+  #[inline]
+  pub fn fill_from_iter<I: IntoIterator<Item = T>>(&mut self, iter: I) -> usize {
+    let old_length = self.length;
+    let mut it = iter.into_iter();
+    while self.length < N {
+      match it.next() {
+        Some(value) => unsafe { self.push_unchecked(value) },
+        None => break,
+      }
+    }
+    self.length - old_length
+  }
+
+  /// Appends elements produced by repeatedly calling `f` to the StaticVec until either the
+  /// StaticVec is full or `f` returns `None`, and returns the number of elements that were added.
+  /// `f` is never called again after it has returned `None`, and is also not called at all once
+  /// the StaticVec is full, so no values are ever produced and then discarded.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let mut v = StaticVec::<i32, 8>::from([1]);
+  /// let mut n = 1;
+  /// let added = v.try_fill_remaining(|| {
+  ///   n += 1;
+  ///   if n <= 4 { Some(n) } else { None }
+  /// });
+  /// assert_eq!(added, 3);
+  /// assert_eq!(v, [1, 2, 3, 4]);
+  /// ```
+  #[inline]
+  pub fn try_fill_remaining<F: FnMut() -> Option<T>>(&mut self, mut f: F) -> usize {
+    let old_length = self.length;
+    while self.length < N {
+      match f() {
+        Some(value) => unsafe { self.push_unchecked(value) },
+        None => break,
+      }
+    }
+    self.length - old_length
+  }
+
+  /// Fills the remaining capacity of the StaticVec with clones of the elements of `pattern`, in
+  /// order, wrapping back around to the start of `pattern` as many times as necessary until the
+  /// StaticVec is full. Locally requires that `T` implements [`Clone`](core::clone::Clone).
+  ///
+  /// If any of the clones panic, the StaticVec is left containing all of the elements that were
+  /// successfully appended up until that point.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `pattern` is empty.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let mut v = StaticVec::<i32, 7>::from([0]);
+  /// v.cycle_fill(&[1, 2, 3]);
+  /// assert_eq!(v, [0, 1, 2, 3, 1, 2, 3]);
+  /// ```
+  #[inline]
+  pub fn cycle_fill(&mut self, pattern: &[T])
+  where T: Clone {
+    let pattern_length = pattern.len();
+    assert!(
+      pattern_length > 0,
+      "`StaticVec::cycle_fill` was called with an empty `pattern`!"
+    );
+    let mut i = 0;
+    while self.length < N {
+      // Pushing each clone individually means `length` is always accurate if `clone` panics.
+      unsafe { self.push_unchecked(pattern.get_unchecked(i).clone()) };
+      i += 1;
+      if i == pattern_length {
+        i = 0;
+      }
+    }
+  }
+
+  /// Appends `self.remaining_capacity()` (or as many as available) items from
+  /// `other` to `self`. The appended items (if any) will no longer exist in `other` afterwards,
+  /// as `other`'s `length` field will be adjusted to indicate.
+  ///
+  /// The `N2` parameter does not need to be provided explicitly, and can be inferred directly from
+  /// the constant `N2` constraint of `other` (which may or may not be the same as the `N`
+  /// constraint of `self`.)
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let mut a = StaticVec::<i32, 8>::from([1, 2, 3, 4]);
+  /// let mut b = staticvec![1, 2, 3, 4, 5, 6, 7, 8];
+  /// a.append(&mut b);
+  /// assert_eq!(a.len(), 8);
+  /// assert_eq!(a, [1, 2, 3, 4, 1, 2, 3, 4]);
+  /// assert_eq!(b, [5, 6, 7, 8]);
+  /// ```
+  #[inline]
+  pub const fn append<const N2: usize>(&mut self, other: &mut StaticVec<T, N2>) {
+    let old_length = self.length;
+    // Get the maximum number of items both within our capacity and within
+    // what `other` actually has to offer.
+    let item_count = const_min(N - old_length, other.length);
+    // Calculate what the length of `other` should be changed to once we've
+    // moved the items from it into self.
+    let other_new_length = other.length - item_count;
+    unsafe {
+      // Copy over the items.
+      self
+        .mut_ptr_at_unchecked(old_length)
+        .copy_from_nonoverlapping(other.as_ptr(), item_count);
+      // Shift the items leftwards in `other` if / as necessary. This only
+      // really does anything if it's the case that the remaining capacity
+      // of `self` was less than the number of items `other` had available.
+      other
+        .as_mut_ptr()
+        .copy_from(other.ptr_at_unchecked(item_count), other_new_length);
+      // Adjust the lengths of `other` and `self`.
+      other.set_len(other_new_length);
+      self.set_len(old_length + item_count);
+    }
+  }
+
+  /// Returns a new StaticVec consisting of the elements of `self` and `other` concatenated in
+  /// linear fashion such that the first element of `other` comes immediately after the last
+  /// element of `self`.
+  ///
+  /// The `N2` parameter does not need to be provided explicitly, and can be inferred directly from
+  /// the constant `N2` constraint of `other` (which may or may not be the same as the `N`
+  /// constraint of `self`.)
+  ///
+  /// Locally requires that `T` implements [`Copy`](core::marker::Copy) to
+  /// avoid soundness issues and also allow for a more efficient implementation than would otherwise
+  /// be possible.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::staticvec;
+  /// assert!(staticvec!['a', 'b'].concat(&staticvec!['c', 'd']) == ['a', 'b', 'c', 'd']);
+  /// ```
+  #[inline]
+  pub const fn concat<const N2: usize>(
+    &self,
+    other: &StaticVec<T, N2>,
+  ) -> StaticVec<T, { N + N2 }>
+  where
+    T: Copy,
+  {
+    let length = self.length;
+    let other_length = other.length;
+    let mut res = StaticVec::new_data_uninit();
+    let res_ptr = res.as_mut_ptr() as *mut T;
+    unsafe {
+      // Copy over all of `self`.
+      self.as_ptr().copy_to_nonoverlapping(res_ptr, length);
+      // Copy over all of `other` starting at the position immediately following
+      // the last occupied position of the copy we just did from `self`.
+      other
+        .as_ptr()
+        .copy_to_nonoverlapping(res_ptr.add(length), other_length);
+    }
+    StaticVec {
+      data: res,
+      length: length + other_length,
+    }
+  }
+
+  /// A version of [`concat`](crate::StaticVec::concat) for scenarios where `T` does not
+  /// derive [`Copy`](core::marker::Copy) but does implement [`Clone`](core::clone::Clone).
+  ///
+  /// Due to needing to call `clone()` through each individual element of `self` and `other`, this
+  /// function is less efficient than [`concat`](crate::StaticVec::concat), so
+  /// [`concat`](crate::StaticVec::concat) should be preferred whenever possible.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::staticvec;
+  /// assert!(staticvec!["a", "b"].concat_clone(&staticvec!["c", "d"]) == ["a", "b", "c", "d"]);
+  /// ```
+  #[inline]
+  pub fn concat_clone<const N2: usize>(
+    &self,
+    other: &StaticVec<T, N2>,
+  ) -> StaticVec<T, { N + N2 }>
+  where
+    T: Clone,
+  {
+    let mut res = StaticVec::new();
+    for item in self {
+      unsafe { res.push_unchecked(item.clone()) };
+    }
+    for item in other {
+      unsafe { res.push_unchecked(item.clone()) };
+    }
+    res
+  }
+
+  /// A version of [`concat`](crate::StaticVec::concat) that writes its output into a StaticVec
+  /// with an explicitly specified capacity of `N3`, as opposed to one with a capacity computed as
+  /// `N + N2`. This is useful in generic contexts where the `{ N + N2 }` return type of
+  /// [`concat`](crate::StaticVec::concat) cannot be named or unified with anything else.
+  ///
+  /// The `N2` parameter does not need to be provided explicitly, and can be inferred from `other`
+  /// itself.
+  ///
+  /// Locally requires that `T` implements [`Copy`](core::marker::Copy) to
+  /// avoid soundness issues and also allow for a more efficient implementation than would otherwise
+  /// be possible.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `self.len() + other.len()` is greater than `N3`.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let v: StaticVec<char, 5> = staticvec!['a', 'b'].concat_into(&staticvec!['c', 'd']);
+  /// assert_eq!(v, ['a', 'b', 'c', 'd']);
+  /// assert_eq!(v.capacity(), 5);
+  /// ```
+  #[inline]
+  pub const fn concat_into<const N2: usize, const N3: usize>(
+    &self,
+    other: &StaticVec<T, N2>,
+  ) -> StaticVec<T, N3>
+  where
+    T: Copy,
+  {
+    let length = self.length;
+    let other_length = other.length;
+    assert!(
+      length + other_length <= N3,
+      "Insufficient capacity in `StaticVec::concat_into`!"
+    );
+    let mut res = StaticVec::<T, N3>::new_data_uninit();
+    let res_ptr = StaticVec::<T, N3>::first_ptr_mut(&mut res);
+    unsafe {
+      // Copy over all of `self`.
+      self.as_ptr().copy_to_nonoverlapping(res_ptr, length);
+      // Copy over all of `other` starting at the position immediately following
+      // the last occupied position of the copy we just did from `self`.
+      other
+        .as_ptr()
+        .copy_to_nonoverlapping(res_ptr.add(length), other_length);
+    }
+    StaticVec {
+      data: res,
+      length: length + other_length,
+    }
+  }
+
+  /// A version of [`concat_into`](crate::StaticVec::concat_into) for scenarios where `T` does not
+  /// derive [`Copy`](core::marker::Copy) but does implement [`Clone`](core::clone::Clone).
+  ///
+  /// # Panics
+  ///
+  /// Panics if `self.len() + other.len()` is greater than `N3`.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let v: StaticVec<&str, 4> = staticvec!["a", "b"].concat_clone_into(&staticvec!["c", "d"]);
+  /// assert_eq!(v, ["a", "b", "c", "d"]);
+  /// ```
+  #[inline]
+  pub fn concat_clone_into<const N2: usize, const N3: usize>(
+    &self,
+    other: &StaticVec<T, N2>,
+  ) -> StaticVec<T, N3>
+  where
+    T: Clone,
+  {
+    assert!(
+      self.length + other.length <= N3,
+      "Insufficient capacity in `StaticVec::concat_clone_into`!"
+    );
+    let mut res = StaticVec::new();
+    for item in self {
+      unsafe { res.push_unchecked(item.clone()) };
+    }
+    for item in other {
+      unsafe { res.push_unchecked(item.clone()) };
+    }
+    res
+  }
+
+  /// Returns a new StaticVec consisting of the elements of `self` in linear order, interspersed
+  /// with a copy of `separator` between each one.
+  ///
+  /// Locally requires that `T` implements [`Copy`](core::marker::Copy) to
+  /// avoid soundness issues and also allow for a more efficient implementation than would otherwise
+  /// be possible.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::staticvec;
+  /// assert_eq!(
+  ///  staticvec!["A", "B", "C", "D"].intersperse("Z"),
+  ///  ["A", "Z", "B", "Z", "C", "Z", "D"]
+  /// );
+  /// ```
+  #[inline]
+  pub const fn intersperse(&self, separator: T) -> StaticVec<T, { N * 2 }>
+  where T: Copy {
+    if self.is_empty() {
+      return StaticVec::new();
+    }
+    let mut res = StaticVec::new_data_uninit();
+    // The `as *mut T` cast below is necessary to make the type inference work properly (at the
+    // moment at least). `rustc` still gets a bit confused by math operations done on const generic
+    // values in return types it seems.
+    // Note that the `StaticVec::new()` calls above *have* to be written without any constraints,
+    // as otherwise we'll hit a particular bug where `rustc` says:
+    // "expected struct `StaticVec<_, { N * 2 }>`, found struct `StaticVec<_, { N * 2 }>`".
+    let mut res_ptr = StaticVec::first_ptr_mut(&mut res) as *mut T;
+    let mut i = 0;
+    let length = self.length;
+    while i < length - 1 {
+      unsafe {
+        res_ptr.write(self.ptr_at_unchecked(i).read());
+        res_ptr.offset(1).write(separator);
+        res_ptr = res_ptr.offset(2);
+      }
+      i += 1;
+    }
+    unsafe {
+      res_ptr.write(self.ptr_at_unchecked(i).read());
+    }
+    StaticVec {
+      data: res,
+      length: (length * 2) - 1,
+    }
+  }
+
+  /// A version of [`intersperse`](crate::StaticVec::intersperse) for scenarios where `T` does not
+  /// derive [`Copy`](core::marker::Copy) but does implement [`Clone`](core::clone::Clone).
+  ///
+  /// Due to needing to call `clone()` through each individual element of `self` and also on
+  /// `separator`, this function is less efficient than
+  /// [`intersperse`](crate::StaticVec::intersperse), so
+  /// [`intersperse`](crate::StaticVec::intersperse) should be preferred whenever possible.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::staticvec;
+  /// assert_eq!(
+  ///  staticvec!["A", "B", "C", "D"].intersperse_clone("Z"),
+  ///  ["A", "Z", "B", "Z", "C", "Z", "D"]
+  /// );
+  /// ```
+  #[inline]
+  pub fn intersperse_clone(&self, separator: T) -> StaticVec<T, { N * 2 }>
+  where T: Clone {
+    if self.is_empty() {
+      return StaticVec::new();
+    }
+    let mut res = StaticVec::new();
+    let length = self.length;
+    unsafe {
+      for i in 0..length - 1 {
+        res.push_unchecked(self.get_unchecked(i).clone());
+        res.push_unchecked(separator.clone());
+      }
+      res.push_unchecked(self.get_unchecked(length - 1).clone());
+    }
+    res
+  }
+
+  /// A version of [`intersperse`](crate::StaticVec::intersperse) that writes its output into a
+  /// StaticVec with an explicitly specified capacity of `M`, as opposed to one with a capacity
+  /// computed as `N * 2`.
+  ///
+  /// Locally requires that `T` implements [`Copy`](core::marker::Copy) to
+  /// avoid soundness issues and also allow for a more efficient implementation than would otherwise
+  /// be possible.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the StaticVec is not empty and `(self.len() * 2) - 1` is greater than `M`.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let v: StaticVec<&str, 7> = staticvec!["A", "B", "C", "D"].intersperse_into("Z");
+  /// assert_eq!(v, ["A", "Z", "B", "Z", "C", "Z", "D"]);
+  /// ```
+  #[inline]
+  pub const fn intersperse_into<const M: usize>(&self, separator: T) -> StaticVec<T, M>
+  where T: Copy {
+    let length = self.length;
+    if length == 0 {
+      return StaticVec::new();
+    }
+    assert!(
+      (length * 2) - 1 <= M,
+      "Insufficient capacity in `StaticVec::intersperse_into`!"
+    );
+    let mut res = StaticVec::<T, M>::new_data_uninit();
+    let mut res_ptr = StaticVec::<T, M>::first_ptr_mut(&mut res);
+    let mut i = 0;
+    while i < length - 1 {
+      unsafe {
+        res_ptr.write(self.ptr_at_unchecked(i).read());
+        res_ptr.offset(1).write(separator);
+        res_ptr = res_ptr.offset(2);
+      }
+      i += 1;
+    }
+    unsafe {
+      res_ptr.write(self.ptr_at_unchecked(i).read());
+    }
+    StaticVec {
+      data: res,
+      length: (length * 2) - 1,
+    }
+  }
+
+  /// A version of [`intersperse_into`](crate::StaticVec::intersperse_into) for scenarios where `T`
+  /// does not derive [`Copy`](core::marker::Copy) but does implement
+  /// [`Clone`](core::clone::Clone).
+  ///
+  /// # Panics
+  ///
+  /// Panics if the StaticVec is not empty and `(self.len() * 2) - 1` is greater than `M`.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let v: StaticVec<&str, 8> = staticvec!["A", "B", "C", "D"].intersperse_clone_into("Z");
+  /// assert_eq!(v, ["A", "Z", "B", "Z", "C", "Z", "D"]);
+  /// ```
+  #[inline]
+  pub fn intersperse_clone_into<const M: usize>(&self, separator: T) -> StaticVec<T, M>
+  where T: Clone {
+    let length = self.length;
+    if length == 0 {
+      return StaticVec::new();
+    }
+    assert!(
+      (length * 2) - 1 <= M,
+      "Insufficient capacity in `StaticVec::intersperse_clone_into`!"
+    );
+    let mut res = StaticVec::new();
+    unsafe {
+      for i in 0..length - 1 {
+        res.push_unchecked(self.get_unchecked(i).clone());
+        res.push_unchecked(separator.clone());
+      }
+      res.push_unchecked(self.get_unchecked(length - 1).clone());
+    }
+    res
+  }
+
+  /// Returns a new StaticVec with a capacity of `M` consisting of the elements of `self` and
+  /// `other` in alternating order (that is, `self[0], other[0], self[1], other[1]`, and so on.)
+  /// Once the shorter of the two has been exhausted, the remaining elements of the longer one are
+  /// appended at the end in linear order.
+  ///
+  /// The `N2` parameter does not need to be provided explicitly, and can be inferred from `other`
+  /// itself.
+  ///
+  /// Locally requires that `T` implements [`Copy`](core::marker::Copy) to
+  /// avoid soundness issues and also allow for a more efficient implementation than would otherwise
+  /// be possible.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `self.len() + other.len()` is greater than `M`.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let left = staticvec![1, 3, 5, 7, 8];
+  /// let right = staticvec![2, 4, 6];
+  /// let v: StaticVec<i32, 8> = left.interleave_into(&right);
+  /// assert_eq!(v, [1, 2, 3, 4, 5, 6, 7, 8]);
+  /// ```
+  #[inline]
+  pub const fn interleave_into<const N2: usize, const M: usize>(
+    &self,
+    other: &StaticVec<T, N2>,
+  ) -> StaticVec<T, M>
+  where
+    T: Copy,
+  {
+    let length = self.length;
+    let other_length = other.length;
+    assert!(
+      length + other_length <= M,
+      "Insufficient capacity in `StaticVec::interleave_into`!"
+    );
+    let mut res = StaticVec::<T, M>::new_data_uninit();
+    let res_ptr = StaticVec::<T, M>::first_ptr_mut(&mut res);
+    let shared_length = if length < other_length {
+      length
+    } else {
+      other_length
+    };
+    let mut i = 0;
+    while i < shared_length {
+      unsafe {
+        res_ptr.add(i * 2).write(self.ptr_at_unchecked(i).read());
+        res_ptr.add(i * 2 + 1).write(other.ptr_at_unchecked(i).read());
+      }
+      i += 1;
+    }
+    unsafe {
+      // At most one of these copies is ever non-empty.
+      self.ptr_at_unchecked(shared_length).copy_to_nonoverlapping(
+        res_ptr.add(shared_length * 2),
+        length - shared_length,
+      );
+      other.ptr_at_unchecked(shared_length).copy_to_nonoverlapping(
+        res_ptr.add(shared_length * 2),
+        other_length - shared_length,
+      );
+    }
+    StaticVec {
+      data: res,
+      length: length + other_length,
+    }
+  }
+
+  /// A version of [`interleave_into`](crate::StaticVec::interleave_into) for scenarios where `T`
+  /// does not derive [`Copy`](core::marker::Copy) but does implement
+  /// [`Clone`](core::clone::Clone).
+  ///
+  /// # Panics
+  ///
+  /// Panics if `self.len() + other.len()` is greater than `M`.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let left = staticvec![String::from("L0"), String::from("L1")];
+  /// let right = staticvec![String::from("R0")];
+  /// let v: StaticVec<String, 3> = left.interleave_clone_into(&right);
+  /// assert_eq!(v, ["L0", "R0", "L1"]);
+  /// ```
+  #[inline]
+  pub fn interleave_clone_into<const N2: usize, const M: usize>(
+    &self,
+    other: &StaticVec<T, N2>,
+  ) -> StaticVec<T, M>
+  where
+    T: Clone,
+  {
+    assert!(
+      self.length + other.length <= M,
+      "Insufficient capacity in `StaticVec::interleave_clone_into`!"
+    );
+    let mut res = StaticVec::new();
+    let mut left = self.iter();
+    let mut right = other.iter();
+    loop {
+      match (left.next(), right.next()) {
+        (Some(a), Some(b)) => unsafe {
+          res.push_unchecked(a.clone());
+          res.push_unchecked(b.clone());
+        },
+        (Some(a), None) => {
+          unsafe { res.push_unchecked(a.clone()) };
+          for item in left {
+            unsafe { res.push_unchecked(item.clone()) };
+          }
+          break;
+        }
+        (None, Some(b)) => {
+          unsafe { res.push_unchecked(b.clone()) };
+          for item in right {
+            unsafe { res.push_unchecked(item.clone()) };
+          }
+          break;
+        }
+        (None, None) => break,
+      }
+    }
+    res
+  }
+
+  /// Merges `self` and `other`, both of which are assumed to already be sorted in ascending
+  /// order, into a new StaticVec with a capacity of `M` that is also sorted in ascending order.
+  /// Duplicate values are preserved, with elements from `self` being placed before any equal
+  /// elements from `other`. If either input is not actually sorted, the output will still
+  /// contain all of the elements of both, but in an unspecified order.
+  ///
+  /// The `N2` parameter does not need to be provided explicitly, and can be inferred from `other`
+  /// itself.
+  ///
+  /// Locally requires that `T` implements [`Copy`](core::marker::Copy) to
+  /// avoid soundness issues and also allow for a more efficient implementation than would otherwise
+  /// be possible.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `self.len() + other.len()` is greater than `M`.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let v: StaticVec<i32, 7> = staticvec![1, 3, 3, 8].merge_sorted_into(&staticvec![2, 3, 9]);
+  /// assert_eq!(v, [1, 2, 3, 3, 3, 8, 9]);
+  /// ```
+  #[inline]
+  pub fn merge_sorted_into<const N2: usize, const M: usize>(
+    &self,
+    other: &StaticVec<T, N2>,
+  ) -> StaticVec<T, M>
+  where
+    T: Copy + Ord,
+  {
+    let length = self.length;
+    let other_length = other.length;
+    assert!(
+      length + other_length <= M,
+      "Insufficient capacity in `StaticVec::merge_sorted_into`!"
+    );
+    let mut res = StaticVec::<T, M>::new_data_uninit();
+    let res_ptr = StaticVec::<T, M>::first_ptr_mut(&mut res);
+    let mut i = 0;
+    let mut j = 0;
+    unsafe {
+      while i < length && j < other_length {
+        let a = self.ptr_at_unchecked(i).read();
+        let b = other.ptr_at_unchecked(j).read();
+        // Taking from `self` on equality is what keeps the merge stable.
+        if b < a {
+          res_ptr.add(i + j).write(b);
+          j += 1;
+        } else {
+          res_ptr.add(i + j).write(a);
+          i += 1;
+        }
+      }
+      // At most one of these copies is ever non-empty.
+      self
+        .ptr_at_unchecked(i)
+        .copy_to_nonoverlapping(res_ptr.add(i + j), length - i);
+      other
+        .ptr_at_unchecked(j)
+        .copy_to_nonoverlapping(res_ptr.add(i + j), other_length - j);
+    }
+    StaticVec {
+      data: res,
+      length: length + other_length,
+    }
+  }
+
+  /// A version of [`merge_sorted_into`](crate::StaticVec::merge_sorted_into) for scenarios where
+  /// `T` does not derive [`Copy`](core::marker::Copy) but does implement
+  /// [`Clone`](core::clone::Clone).
+  ///
+  /// # Panics
+  ///
+  /// Panics if `self.len() + other.len()` is greater than `M`.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let a = staticvec![String::from("apple"), String::from("pear")];
+  /// let b = staticvec![String::from("fig")];
+  /// let v: StaticVec<String, 3> = a.merge_sorted_clone_into(&b);
+  /// assert_eq!(v, ["apple", "fig", "pear"]);
+  /// ```
+  #[inline]
+  pub fn merge_sorted_clone_into<const N2: usize, const M: usize>(
+    &self,
+    other: &StaticVec<T, N2>,
+  ) -> StaticVec<T, M>
+  where
+    T: Clone + Ord,
+  {
+    assert!(
+      self.length + other.length <= M,
+      "Insufficient capacity in `StaticVec::merge_sorted_clone_into`!"
+    );
+    let mut res = StaticVec::new();
+    let mut left = self.iter().peekable();
+    let mut right = other.iter().peekable();
+    loop {
+      let item = match (left.peek(), right.peek()) {
+        // Taking from `self` on equality is what keeps the merge stable.
+        (Some(a), Some(b)) if b < a => right.next(),
+        (Some(_), _) => left.next(),
+        (None, Some(_)) => right.next(),
+        (None, None) => break,
+      };
+      unsafe { res.push_unchecked(item.unwrap().clone()) };
+    }
+    res
+  }
+
+  /// Returns a StaticVec containing the contents of a [`Vec`](alloc::vec::Vec) instance.
+  /// If the [`Vec`](alloc::vec::Vec) has a length greater than the declared capacity of the
+  /// resulting StaticVec, any contents after that point are ignored. Note that using this function
+  /// consumes the source [`Vec`](alloc::vec::Vec).
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let mut v = vec![1, 2, 3];
+  /// let sv: StaticVec<i32, 3> = StaticVec::from_vec(v);
+  /// assert_eq!(sv, [1, 2, 3]);
+  /// ```
+  #[cfg(feature = "std")]
+  #[doc(cfg(feature = "std"))]
+  #[inline]
+  pub fn from_vec(mut vec: Vec<T>) -> Self {
+    let vec_len = vec.len();
+    let item_count = vec_len.min(N);
+    Self {
+      data: {
+        // Set the length of `vec` to 0 to prevent double-drops.
+        unsafe { vec.set_len(0) };
+        let mut data = Self::new_data_uninit();
+        unsafe {
+          vec
+            .as_ptr()
+            .copy_to_nonoverlapping(Self::first_ptr_mut(&mut data), item_count);
+          // Manually drop any excess values in the source vec to avoid undesirable memory leaks.
+          if vec_len > item_count {
+            ptr::drop_in_place(from_raw_parts_mut(
+              vec.as_mut_ptr().add(item_count),
+              vec_len - item_count,
+            ));
+          }
+          data
+        }
+      },
+      length: item_count,
+    }
+  }
+
+  /// A fallible version of [`from_vec`](crate::StaticVec::from_vec) that gives back the original,
+  /// unmodified [`Vec`](alloc::vec::Vec) in `Err` instead of truncating it.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let sv = StaticVec::<i32, 4>::try_from_vec(vec![1, 2, 3]).unwrap();
+  /// assert_eq!(sv, [1, 2, 3]);
+  /// let v = StaticVec::<i32, 2>::try_from_vec(vec![1, 2, 3]).unwrap_err();
+  /// assert_eq!(v, [1, 2, 3]);
+  /// ```
+  #[cfg(feature = "std")]
+  #[doc(cfg(feature = "std"))]
+  #[inline]
+  pub fn try_from_vec(vec: Vec<T>) -> Result<Self, Vec<T>> {
+    if vec.len() > N {
+      Err(vec)
+    } else {
+      Ok(Self::from_vec(vec))
+    }
+  }
+
+  /// Returns a [`Vec`](alloc::vec::Vec) containing the contents of the StaticVec instance.
+  /// The returned [`Vec`](alloc::vec::Vec) will initially have the same value for
+  /// [`len`](alloc::vec::Vec::len) and [`capacity`](alloc::vec::Vec::capacity) as the source
+  /// StaticVec. Note that using this function consumes the source StaticVec.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let mut sv = staticvec![1, 2, 3];
+  /// let v = sv.into_vec();
+  /// assert_eq!(v, [1, 2, 3]);
+  /// ```
+  #[cfg(feature = "std")]
+  #[doc(cfg(feature = "std"))]
+  #[inline(always)]
+  pub fn into_vec(mut self) -> Vec<T> {
+    let mut res = Vec::with_capacity(N);
+    let length = self.length;
+    unsafe {
+      self
+        .as_ptr()
+        .copy_to_nonoverlapping(res.as_mut_ptr(), length);
+      res.set_len(length);
+      // Set the length of `self` to 0 to prevent double-drops.
+      self.set_len(0);
+      res
+    }
+  }
+
+  /// Inspired by the function of the same name from `ArrayVec`, this function directly returns
+  /// the StaticVec's backing array (as a "normal" array not wrapped in an instance of
+  /// `MaybeUninit`) in `Ok` if and only if the StaticVec is at maximum capacity. Otherwise, the
+  /// StaticVec itself is returned in `Err`.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let mut v1 = StaticVec::<i32, 4>::new();
+  /// v1.push(1);
+  /// v1.push(2);
+  /// let a = v1.into_inner();
+  /// assert!(a.is_err());
+  /// let v2 = staticvec![1, 2, 3, 4];
+  /// let a = v2.into_inner();
+  /// assert!(a.is_ok());
+  /// assert_eq!(a.unwrap(), [1, 2, 3, 4]);
+  /// ```
+  #[inline(always)]
+  pub const fn into_inner(self) -> Result<[T; N], Self> {
+    if self.is_not_full() {
+      Err(self)
+    } else {
+      // Read out the contents of `data`. The way we do it looks silly, but is the only way for this
+      // function to be const-compatible. Note that setting `self.length` to zero here is not
+      // necessary, as `drop` will not be called on this StaticVec, so there is no risk of
+      // double-drops.
+      unsafe {
+        Ok(
+          MaybeUninit::new(self)
+            .assume_init_mut()
+            .data
+            .assume_init_read(),
+        )
+      }
+    }
+  }
+
+  /// A generalization of [`into_inner`](crate::StaticVec::into_inner) that moves the contents of
+  /// the StaticVec out into an array of length `M` in `Ok` if and only if the StaticVec has a
+  /// length of exactly `M` (which does not need to be equal to its capacity of `N`). Otherwise,
+  /// the StaticVec itself is returned in `Err`.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let v = StaticVec::<i32, 8>::from([1, 2, 3]);
+  /// let v = v.try_into_array::<4>().unwrap_err();
+  /// assert_eq!(v.try_into_array::<3>(), Ok([1, 2, 3]));
+  /// ```
+  #[inline]
+  pub const fn try_into_array<const M: usize>(self) -> Result<[T; M], Self> {
+    if self.length != M {
+      Err(self)
+    } else {
+      let mut res = MaybeUninit::<[T; M]>::uninit();
+      // Safety: we know we have exactly `M` initialized elements, and forgetting `self` afterwards
+      // ensures that none of them will be dropped twice.
+      unsafe {
+        self
+          .as_ptr()
+          .copy_to_nonoverlapping(StaticVec::<T, M>::first_ptr_mut(&mut res), M);
+        mem::forget(self);
+        Ok(res.assume_init())
+      }
+    }
+  }
+
+  /// Removes the specified range of elements from the StaticVec and returns them in a new one.
+  /// Passing an empty range (at any position, including `self.len()..self.len()`) returns an
+  /// empty StaticVec and leaves `self` entirely untouched, while passing `..` removes and returns
+  /// everything.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the range's starting point is greater than the end point or if the end point is
+  /// greater than the length of the StaticVec. If this happens, `self` is left untouched.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let mut v = staticvec![1, 2, 3];
+  /// let u = v.drain(1..);
+  /// assert_eq!(v, &[1]);
+  /// assert_eq!(u, &[2, 3]);
+  /// assert_eq!(v.drain(1..1), []);
+  /// assert_eq!(v.drain(..), [1]);
+  /// ```
+  // No Copy bounds here because the original StaticVec gives up all access to the values in
+  // question.
+  #[inline]
+  pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Self {
+    // Borrowed this part from normal Vec's implementation.
+    let old_length = self.length;
+    let (start, end) = self.decode_range(range, "Bounds check failure in `StaticVec::drain`!");
+    assert!(
+      start <= end && end <= old_length,
+      "Bounds check failure in `StaticVec::drain`!"
+    );
+    // An empty range (including `self.len()..self.len()`) is a no-op, so we don't touch
+    // `self` at all in that case.
+    if start == end {
+      return Self::new();
+    }
+    let res_length = end - start;
+    Self {
+      data: {
+        let mut res = Self::new_data_uninit();
+        unsafe {
+          self
+            .ptr_at_unchecked(start)
+            .copy_to_nonoverlapping(Self::first_ptr_mut(&mut res), res_length);
+          let mp = self.as_mut_ptr();
+          mp.add(end).copy_to(mp.add(start), old_length - end);
+          self.set_len(old_length - res_length);
+          res
+        }
+      },
+      length: res_length,
+    }
+  }
+
+  /// Removes the specified range of elements from the StaticVec and returns them in a
+  /// [`StaticVecDrain`](crate::iterators::StaticVecDrain).
+  ///
+  /// # Panics
+  ///
+  /// Panics if the range's starting point is greater than the end point or if the end point is
+  /// greater than the length of the StaticVec.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let mut v1 = staticvec![0, 4, 5, 6, 7];
+  /// let v2: StaticVec<i32, 3> = v1.drain_iter(1..4).rev().collect();
+  /// assert_eq!(v2, [6, 5, 4]);
+  /// ```
+  #[inline]
+  pub fn drain_iter<R: RangeBounds<usize>>(&mut self, range: R) -> StaticVecDrain<T, N> {
+    // Borrowed this part from normal Vec's implementation.
+    let length = self.length;
+    let (start, end) = self.decode_range(range, "Bounds check failure in `StaticVec::drain_iter`!");
+    assert!(
+      start <= end && end <= length,
+      "Bounds check failure in `StaticVec::drain_iter`!"
+    );
+    unsafe {
+      // Set the length to `start` to avoid memory issues if anything goes wrong with the Drain.
+      self.set_len(start);
+      let start_ptr = match size_of::<T>() {
+        0 => zst_ptr_add(self.as_ptr(), start),
+        _ => self.ptr_at_unchecked(start),
+      };
+      // `start_ptr` will never be null, so this is a safe assumption to give to
+      // the optimizer.
+      assume(!start_ptr.is_null());
+      // Create the StaticVecDrain from the specified range.
+      StaticVecDrain {
+        start: end,
+        length: length - end,
+        iter: StaticVecIterConst {
+          start: start_ptr,
+          end: match size_of::<T>() {
+            0 => zst_ptr_add(self.as_ptr(), end),
+            _ => self.ptr_at_unchecked(end),
+          },
+          marker: PhantomData,
+        },
+        vec: self,
+      }
+    }
+  }
+
+  /// Removes all of the elements from the StaticVec and returns them by value in a
+  /// [`StaticVecIntoIter`](crate::iterators::StaticVecIntoIter), leaving the StaticVec itself
+  /// empty but still usable. Unlike [`drain_iter`](crate::StaticVec::drain_iter), the returned
+  /// iterator does not borrow the StaticVec, and so can be held onto while new elements are
+  /// pushed to it. Any elements not consumed from the iterator are dropped along with it.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let mut v = staticvec![1, 2, 3];
+  /// let it = v.drain_all();
+  /// assert!(v.is_empty());
+  /// v.push(4);
+  /// assert_eq!(it.collect::<StaticVec<i32, 3>>(), [1, 2, 3]);
+  /// assert_eq!(v, [4]);
+  /// ```
+  #[inline(always)]
+  pub fn drain_all(&mut self) -> StaticVecIntoIter<T, N> {
+    self.take_all().into_iter()
+  }
+
+  /// Removes all elements in the StaticVec for which `filter` returns true and returns them in a
+  /// new one.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let mut numbers = staticvec![1, 2, 3, 4, 5, 6, 8, 9, 11, 13, 14, 15];
+  /// let evens = numbers.drain_filter(|x| *x % 2 == 0);
+  /// let odds = numbers;
+  /// assert_eq!(evens, [2, 4, 6, 8, 14]);
+  /// assert_eq!(odds, [1, 3, 5, 9, 11, 13, 15]);
+  /// ```
+  #[inline]
+  pub fn drain_filter<F: FnMut(&mut T) -> bool>(&mut self, mut filter: F) -> Self {
+    let old_length = self.length;
+    // Temporarily set our length to 0 to avoid double drops and such if anything
+    // goes wrong in the filter loop.
+    self.length = 0;
+    let mut res = Self::new();
+    let mut res_length = 0;
+    unsafe {
+      // If `self.length` was already 0, this loop is skipped completely.
+      for i in 0..old_length {
+        // This is fine because we intentionally set `self.length` to `0` ourselves just now.
+        if filter(self.get_unchecked_mut(i)) {
+          res
+            .mut_ptr_at_unchecked(res_length)
+            .write(self.ptr_at_unchecked(i).read());
+          res_length += 1;
+        } else if res_length > 0 {
+          let mp = self.as_mut_ptr();
+          mp.add(i).copy_to_nonoverlapping(mp.add(i - res_length), 1);
+        }
+      }
+    }
+    self.length = old_length - res_length;
+    res.length = res_length;
+    res
+  }
+
+  /// Replaces the specified range in the StaticVec with the contents of `replace_with` and returns
+  /// the removed items in an instance of [`StaticVecSplice`](crate::iterators::StaticVecSplice).
+  /// `replace_with` does not need to be the same length as `range`. Returns immediately if and when
+  /// the StaticVec reaches maximum capacity, regardless of whether or not `replace_with` still has
+  /// more items to yield.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the range's starting point is greater than the end point or if the end point is
+  /// greater than the length of the StaticVec.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let mut v = staticvec![1, 2, 3];
+  /// let new = [7, 8];
+  /// let u: StaticVec<u8, 2> = v.splice(..2, new.iter().copied()).collect();
+  /// assert_eq!(v, [7, 8, 3]);
+  /// assert_eq!(u, [1, 2]);
+  /// ```
+  #[inline]
+  pub fn splice<R: RangeBounds<usize>, I: IntoIterator<Item = T>>(
+    &mut self,
+    range: R,
+    replace_with: I,
+  ) -> StaticVecSplice<T, I::IntoIter, N> {
+    let length = self.length;
+    let (start, end) = self.decode_range(range, "Bounds check failure in `StaticVec::splice`!");
+    assert!(
+      start <= end && end <= length,
+      "Bounds check failure in `StaticVec::splice`!"
+    );
+    StaticVecSplice {
+      start,
+      end,
+      replace_with: replace_with.into_iter(),
+      vec: self,
+    }
+  }
+
+  /// Replaces every element of the StaticVec that is equal to `from` with a clone of `to`,
+  /// dropping the old values, and returns the number of elements that were replaced.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let mut v = staticvec![1, 2, 1, 3, 1];
+  /// assert_eq!(v.replace_all(&1, 9), 3);
+  /// assert_eq!(v, [9, 2, 9, 3, 9]);
+  /// assert_eq!(v.replace_all(&1, 0), 0);
+  /// ```
+  #[inline]
+  pub fn replace_all(&mut self, from: &T, to: T) -> usize
+  where T: PartialEq + Clone {
+    let mut count = 0;
+    for item in self.iter_mut() {
+      if *item == *from {
+        *item = to.clone();
+        count += 1;
+      }
+    }
+    count
+  }
+
+  /// Replaces the first element of the StaticVec that is equal to `from` with `to`, dropping the
+  /// old value, and returns true if a replacement took place. If no element is equal to `from`,
+  /// `to` is dropped and false is returned.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let mut v = staticvec!["a", "b", "a"];
+  /// assert!(v.replace_first(&"a", "c"));
+  /// assert_eq!(v, ["c", "b", "a"]);
+  /// assert!(!v.replace_first(&"d", "e"));
+  /// ```
+  #[inline]
+  pub fn replace_first(&mut self, from: &T, to: T) -> bool
+  where T: PartialEq {
+    match self.iter_mut().find(|item| **item == *from) {
+      Some(item) => {
+        *item = to;
+        true
+      }
+      None => false,
+    }
+  }
+
+  /// Replaces the specified range in the StaticVec with the contents of `replace_with`, dropping
+  /// the removed items. This is equivalent to calling [`splice`](crate::StaticVec::splice) and
+  /// immediately discarding the returned [`StaticVecSplice`](crate::iterators::StaticVecSplice),
+  /// and so has exactly the same semantics with regards to `replace_with` being a different length
+  /// than `range` and to the StaticVec reaching maximum capacity.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the range's starting point is greater than the end point or if the end point is
+  /// greater than the length of the StaticVec.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let mut v = StaticVec::<i32, 5>::from([1, 2, 3]);
+  /// v.replace_range(..2, [7, 8, 9]);
+  /// assert_eq!(v, [7, 8, 9, 3]);
+  /// v.replace_range(1.., [0]);
+  /// assert_eq!(v, [7, 0]);
+  /// ```
+  #[inline(always)]
+  pub fn replace_range<R: RangeBounds<usize>, I: IntoIterator<Item = T>>(
+    &mut self,
+    range: R,
+    replace_with: I,
+  ) {
+    // The `Drop` impl of `StaticVecSplice` is what actually does all of the work here, both in
+    // terms of removing the items in `range` and shifting the tail of the StaticVec as needed.
+    drop(self.splice(range, replace_with));
+  }
+
+  /// Removes all elements in the StaticVec for which `filter` returns false.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let mut v = staticvec![1, 2, 3, 4, 5];
+  /// let keep = staticvec![false, true, true, false, true];
+  /// let mut i = 0;
+  /// v.retain(|_| (keep[i], i += 1).0);
+  /// assert_eq!(v, [2, 3, 5]);
+  /// ```
+  #[inline(always)]
+  pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut filter: F) {
+    self.drain_filter(|val| !filter(val));
+  }
+
+  /// Removes all elements in the StaticVec for which `filter` returns false, exactly as
+  /// [`retain`](crate::StaticVec::retain) does, but also returns the number of elements that were
+  /// removed.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let mut v = staticvec![1, 2, 3, 4, 5];
+  /// assert_eq!(v.retain_counting(|x| *x % 2 == 1), 2);
+  /// assert_eq!(v, [1, 3, 5]);
+  /// ```
+  #[inline(always)]
+  pub fn retain_counting<F: FnMut(&T) -> bool>(&mut self, mut filter: F) -> usize {
+    // The removed elements are dropped as soon as we've gotten the length of the returned
+    // StaticVec.
+    self.drain_filter(|val| !filter(val)).len()
+  }
+
+  /// Consumes the StaticVec and splits its elements into two new StaticVecs of the same capacity,
+  /// with the first containing all elements for which `predicate` returns true and the second
+  /// containing all elements for which it returns false. The relative order of the elements is
+  /// preserved in both.
+  ///
+  /// If `predicate` panics, all elements (whether or not they've been routed into one of the two
+  /// new StaticVecs yet) are dropped as usual during unwinding.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let v = staticvec![1, 2, 3, 4, 5, 6, 7];
+  /// let (evens, odds) = v.partition(|x| *x % 2 == 0);
+  /// assert_eq!(evens, [2, 4, 6]);
+  /// assert_eq!(odds, [1, 3, 5, 7]);
+  /// ```
+  #[inline]
+  pub fn partition<F: FnMut(&T) -> bool>(self, mut predicate: F) -> (Self, Self) {
+    let mut matching = Self::new();
+    let mut rest = Self::new();
+    // Going through `StaticVecIntoIter` here means that if `predicate` panics, everything not yet
+    // yielded by it is dropped by it, while everything already yielded is dropped by either
+    // `matching`, `rest`, or the loop itself.
+    for item in self {
+      // Neither of the new StaticVecs can receive more than `N` elements in total.
+      if predicate(&item) {
+        unsafe { matching.push_unchecked(item) };
+      } else {
+        unsafe { rest.push_unchecked(item) };
+      }
+    }
+    (matching, rest)
+  }
+
+  /// Removes all elements within the specified range of the StaticVec for which `filter` returns
+  /// false, leaving all elements outside of the range untouched. Any elements after the end of the
+  /// range are shifted to the left as needed to close the resulting gap.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the range's starting point is greater than the end point or if the end point is
+  /// greater than the length of the StaticVec.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let mut v = staticvec![1, 2, 3, 4, 5, 6, 7, 8];
+  /// v.retain_range(2..6, |x| *x % 2 == 0);
+  /// assert_eq!(v, [1, 2, 4, 6, 7, 8]);
+  /// ```
+  #[inline]
+  pub fn retain_range<R: RangeBounds<usize>, F: FnMut(&T) -> bool>(
+    &mut self,
+    range: R,
+    mut filter: F,
+  ) {
+    let old_length = self.length;
+    let (start, end) =
+      self.decode_range(range, "Bounds check failure in `StaticVec::retain_range`!");
+    assert!(
+      start <= end && end <= old_length,
+      "Bounds check failure in `StaticVec::retain_range`!"
+    );
+    // Temporarily set our length to `start` to avoid double drops and such if anything goes wrong
+    // in the filter loop. The worst case scenario is that everything from `start` onwards leaks.
+    self.length = start;
+    let mut deleted = 0;
+    unsafe {
+      let mp = self.as_mut_ptr();
+      for i in start..end {
+        if !filter(&*mp.add(i)) {
+          ptr::drop_in_place(mp.add(i));
+          deleted += 1;
+        } else if deleted > 0 {
+          mp.add(i).copy_to_nonoverlapping(mp.add(i - deleted), 1);
+        }
+      }
+      if deleted > 0 {
+        mp.add(end).copy_to(mp.add(end - deleted), old_length - end);
+      }
+    }
+    self.length = old_length - deleted;
+  }
+
+  /// Shortens the StaticVec, keeping the first `length` elements and dropping the rest.
+  /// Does nothing if `length` is greater than or equal to the current length of the StaticVec.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let mut v = staticvec![1, 2, 3, 4, 5];
+  /// v.truncate(2);
+  /// assert_eq!(v, [1, 2]);
+  /// ```
+  #[inline(always)]
+  pub fn truncate(&mut self, length: usize) {
+    if length < self.length {
+      let old_length = self.length;
+      unsafe {
+        self.set_len(length);
+        ptr::drop_in_place(from_raw_parts_mut(
+          self.mut_ptr_at_unchecked(length),
+          old_length - length,
+        ));
+      }
+    }
+  }
+
+  /// Drops and removes all elements of the StaticVec from `index` to the end, keeping the first
+  /// `index` elements. This is equivalent to [`truncate`](crate::StaticVec::truncate), except
+  /// that it panics if `index` is greater than the current length of the StaticVec, instead
+  /// of simply doing nothing.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `index` is greater than the current length of the StaticVec.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let mut v = staticvec![Box::new(1), Box::new(2), Box::new(3), Box::new(4)];
+  /// v.clear_from(1);
+  /// assert_eq!(v, [Box::new(1)]);
+  /// v.clear_from(1);
+  /// assert_eq!(v, [Box::new(1)]);
+  /// ```
+  #[inline]
+  pub fn clear_from(&mut self, index: usize) {
+    assert!(
+      index <= self.length,
+      "Bounds check failure in `StaticVec::clear_from`!"
+    );
+    self.truncate(index);
+  }
+
+  /// Drops and removes the specified range of elements from the StaticVec, shifting all elements
+  /// after the end of the range to the left to close the resulting gap.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the range's starting point is greater than the end point or if the end point is
+  /// greater than the length of the StaticVec.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let mut v = staticvec![Box::new(1), Box::new(2), Box::new(3), Box::new(4), Box::new(5)];
+  /// v.clear_range(1..3);
+  /// assert_eq!(v, [Box::new(1), Box::new(4), Box::new(5)]);
+  /// v.clear_range(..);
+  /// assert_eq!(v, []);
+  /// ```
+  #[inline]
+  pub fn clear_range<R: RangeBounds<usize>>(&mut self, range: R) {
+    let old_length = self.length;
+    let (start, end) =
+      self.decode_range(range, "Bounds check failure in `StaticVec::clear_range`!");
+    assert!(
+      start <= end && end <= old_length,
+      "Bounds check failure in `StaticVec::clear_range`!"
+    );
+    unsafe {
+      // Set the length to `start` first, so that if any of the destructors panic the worst that
+      // can happen is that the elements after the range are leaked (as opposed to double-dropped).
+      self.set_len(start);
+      let mp = self.as_mut_ptr();
+      ptr::drop_in_place(from_raw_parts_mut(mp.add(start), end - start));
+      mp.add(end).copy_to(mp.add(start), old_length - end);
+      self.set_len(old_length - (end - start));
+    }
+  }
+
+  /// Keeps the first `n` elements of the StaticVec, dropping and removing the rest. This has the
+  /// same semantics as [`truncate`](crate::StaticVec::truncate) (and is identical to
+  /// [`clear_from`](crate::StaticVec::clear_from)), but is provided as the natural counterpart to
+  /// [`keep_last`](crate::StaticVec::keep_last).
+  ///
+  /// # Panics
+  ///
+  /// Panics if `n` is greater than the current length of the StaticVec.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let mut v = staticvec![1, 2, 3, 4, 5];
+  /// v.keep_first(2);
+  /// assert_eq!(v, [1, 2]);
+  /// ```
+  #[inline]
+  pub fn keep_first(&mut self, n: usize) {
+    assert!(
+      n <= self.length,
+      "Bounds check failure in `StaticVec::keep_first`!"
+    );
+    self.truncate(n);
+  }
+
+  /// Keeps the last `n` elements of the StaticVec, dropping and removing the first
+  /// `self.len() - n` elements and shifting the remaining ones to the front.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `n` is greater than the current length of the StaticVec.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let mut v = staticvec![Box::new(1), Box::new(2), Box::new(3), Box::new(4), Box::new(5)];
+  /// v.keep_last(2);
+  /// assert_eq!(v, [Box::new(4), Box::new(5)]);
+  /// ```
+  #[inline]
+  pub fn keep_last(&mut self, n: usize) {
+    let old_length = self.length;
+    assert!(
+      n <= old_length,
+      "Bounds check failure in `StaticVec::keep_last`!"
+    );
+    let drop_count = old_length - n;
+    if drop_count > 0 {
+      unsafe {
+        // As in `clear_range`, setting the length to zero first means a panicking destructor can
+        // at worst leak the kept elements, as opposed to double-dropping anything.
+        self.set_len(0);
+        let mp = self.as_mut_ptr();
+        ptr::drop_in_place(from_raw_parts_mut(mp, drop_count));
+        mp.add(drop_count).copy_to(mp, n);
+        self.set_len(n);
+      }
+    }
+  }
+
+  /// Splits one StaticVec into two at the given index, returning the second half without consuming
+  /// the first half. The original StaticVec will contain all elements within the exclusive range
+  /// `0..at`, and the new one will contain all elements within the exclusive range
+  /// `at..self.len()`. This function will panic if `at` is greater than `self.len()`.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let mut v1 = staticvec![1, 2, 3];
+  /// let v2 = v1.split_off(1);
+  /// assert_eq!(v1, [1]);
+  /// assert_eq!(v2, [2, 3]);
+  /// ```
+  #[inline]
+  pub const fn split_off(&mut self, at: usize) -> Self {
+    let old_length = self.length;
+    assert!(
+      at <= old_length,
+      "Bounds check failure in `StaticVec::split_off`!"
+    );
+    let split_length = old_length - at;
+    Self {
+      data: unsafe {
+        self.set_len(at);
+        let mut split = Self::new_data_uninit();
+        self
+          .ptr_at_unchecked(at)
+          .copy_to_nonoverlapping(Self::first_ptr_mut(&mut split), split_length);
+        split
+      },
+      length: split_length,
+    }
+  }
+
+  /// A version of [`split_off`](crate::StaticVec::split_off) that moves the elements within the
+  /// exclusive range `at..self.len()` into a new StaticVec with an explicitly specified capacity of
+  /// `M`, as opposed to one with the same capacity as the original. The original StaticVec will
+  /// contain all elements within the exclusive range `0..at`.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `at` is greater than `self.len()`, or if `self.len() - at` is greater than `M`.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let mut v1 = StaticVec::<i32, 64>::from([1, 2, 3, 4]);
+  /// let v2: StaticVec<i32, 2> = v1.split_off_into(2);
+  /// assert_eq!(v1, [1, 2]);
+  /// assert_eq!(v2, [3, 4]);
+  /// ```
+  #[inline]
+  pub const fn split_off_into<const M: usize>(&mut self, at: usize) -> StaticVec<T, M> {
+    let old_length = self.length;
+    assert!(
+      at <= old_length,
+      "Bounds check failure in `StaticVec::split_off_into`!"
+    );
+    let split_length = old_length - at;
+    assert!(
+      split_length <= M,
+      "Insufficient capacity in `StaticVec::split_off_into`!"
+    );
+    StaticVec {
+      data: unsafe {
+        self.set_len(at);
+        let mut split = StaticVec::<T, M>::new_data_uninit();
+        self
+          .ptr_at_unchecked(at)
+          .copy_to_nonoverlapping(StaticVec::<T, M>::first_ptr_mut(&mut split), split_length);
+        split
+      },
+      length: split_length,
+    }
+  }
+
+  /// Finds the first element of the StaticVec for which `predicate` returns true and splits the
+  /// StaticVec at its index, exactly as [`split_off`](crate::StaticVec::split_off) would, returning
+  /// the new StaticVec (which begins with the matching element) in `Some`. The original StaticVec
+  /// will contain all elements before the match. If no element matches, `None` is returned and
+  /// the original StaticVec is left untouched.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let mut v = staticvec![1, 2, 0, 3, 0, 4];
+  /// assert_eq!(v.split_off_when(|x| *x == 0), Some(staticvec![0, 3, 0, 4]));
+  /// assert_eq!(v, [1, 2]);
+  /// assert_eq!(v.split_off_when(|x| *x == 0), None);
+  /// assert_eq!(v, [1, 2]);
+  /// ```
+  #[inline]
+  pub fn split_off_when<F: FnMut(&T) -> bool>(&mut self, predicate: F) -> Option<Self> {
+    match self.iter().position(predicate) {
+      Some(index) => Some(self.split_off(index)),
+      None => None,
+    }
+  }
+
+  /// Splits one StaticVec into two new ones at index `M` and returns them in a tuple, while
+  /// consuming the original. The first new one will contain all elements within the exclusive range
+  /// `0..M`, and the second new one will contain all elements within the exclusive range
+  /// `M..self.len()`. This function will panic if `M` is greater than `self.len()`.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let v1 = staticvec![Box::new(1), Box::new(2), Box::new(3), Box::new(4), Box::new(5), Box::new(6)];
+  /// let t1 = v1.split_at::<0>();
+  /// assert_eq!(t1.0, []);
+  /// assert_eq!(t1.1, [Box::new(1), Box::new(2), Box::new(3), Box::new(4), Box::new(5), Box::new(6)]);
+  /// let v2 = staticvec![Box::new(1), Box::new(2), Box::new(3), Box::new(4), Box::new(5), Box::new(6)];
+  /// let t2 = v2.split_at::<2>();
+  /// assert_eq!(t2.0, [Box::new(1), Box::new(2)]);
+  /// assert_eq!(t2.1, [Box::new(3), Box::new(4), Box::new(5), Box::new(6)]);
+  /// let v3 = staticvec![Box::new(1), Box::new(2), Box::new(3), Box::new(4), Box::new(5), Box::new(6)];
+  /// let t3 = v3.split_at::<6>();
+  /// assert_eq!(t3.0, [Box::new(1), Box::new(2), Box::new(3), Box::new(4), Box::new(5), Box::new(6)]);
+  /// assert_eq!(t3.1, []);
+  #[inline]
+  pub const fn split_at<const M: usize>(self) -> (StaticVec<T, M>, StaticVec<T, { N - M }>) {
+    let old_length = self.length;
+    assert!(
+      M <= old_length,
+      "Bounds check failure in `StaticVec::split_at'!"
+    );
+    let mut left = MaybeUninit::<[T; M]>::uninit();
+    let mut right = MaybeUninit::<[T; N - M]>::uninit();
+    let self_ptr = self.as_ptr();
+    let left_ptr = StaticVec::first_ptr_mut(&mut left);
+    let right_ptr = StaticVec::first_ptr_mut(&mut right);
+    let right_length = old_length - M;
+    unsafe {
+      self_ptr.copy_to_nonoverlapping(left_ptr, M);
+      self_ptr
+        .add(M)
+        .copy_to_nonoverlapping(right_ptr, right_length);
+      mem::forget(self);
+      (
+        StaticVec {
+          data: left,
+          length: M,
+        },
+        StaticVec {
+          data: right,
+          length: right_length,
+        },
+      )
+    }
+  }
+
+  /// Divides the inhabited area of the StaticVec into two immutable slices at index `mid`, with the
+  /// first containing all elements within the exclusive range `0..mid` and the second containing
+  /// all elements within the exclusive range `mid..self.len()`.
+  ///
+  /// This is equivalent to calling the slice method `split_at` on the result of
+  /// [`as_slice`](crate::StaticVec::as_slice), but avoids any deref-related inference issues in
+  /// generic contexts. It is named `split_at_ref` as the consuming, const-generic
+  /// [`split_at`](crate::StaticVec::split_at) already exists.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `mid` is greater than `self.len()`.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let v = staticvec![1, 2, 3, 4, 5];
+  /// assert_eq!(v.split_at_ref(2), (&[1, 2][..], &[3, 4, 5][..]));
+  /// assert_eq!(v.split_at_ref(5), (&[1, 2, 3, 4, 5][..], &[][..]));
+  /// ```
+  #[inline]
+  pub const fn split_at_ref(&self, mid: usize) -> (&[T], &[T]) {
+    assert!(
+      mid <= self.length,
+      "Bounds check failure in `StaticVec::split_at_ref`!"
+    );
+    // Safety: `mid` has been bounds checked above, so both slices lie within the inhabited area.
+    unsafe {
+      (
+        from_raw_parts(self.as_ptr(), mid),
+        from_raw_parts(self.ptr_at_unchecked(mid), self.length - mid),
+      )
+    }
+  }
+
+  /// Divides the inhabited area of the StaticVec into two mutable slices at index `mid`, with the
+  /// first containing all elements within the exclusive range `0..mid` and the second containing
+  /// all elements within the exclusive range `mid..self.len()`.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `mid` is greater than `self.len()`.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let mut v = staticvec![1, 2, 3, 4, 5];
+  /// let (a, b) = v.split_at_mut(2);
+  /// a[0] = 6;
+  /// b[0] = 7;
+  /// assert_eq!(v, [6, 2, 7, 4, 5]);
+  /// ```
+  #[inline]
+  pub const fn split_at_mut(&mut self, mid: usize) -> (&mut [T], &mut [T]) {
+    let length = self.length;
+    assert!(
+      mid <= length,
+      "Bounds check failure in `StaticVec::split_at_mut`!"
+    );
+    // Safety: `mid` has been bounds checked above, so both slices lie within the inhabited area
+    // and do not overlap.
+    unsafe {
+      (
+        from_raw_parts_mut(self.as_mut_ptr(), mid),
+        from_raw_parts_mut(self.mut_ptr_at_unchecked(mid), length - mid),
+      )
+    }
+  }
+
+  /// Divides the inhabited area of the StaticVec into two immutable slices around the first
+  /// element for which `predicate` returns true, with the first slice containing everything
+  /// before that element and the second containing everything after it. The matching element
+  /// itself is not included in either slice. Returns `None` if no element matches.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let v = StaticVec::<u8, 16>::from(*b"key=value=more");
+  /// assert_eq!(v.split_once(|&b| b == b'='), Some((&b"key"[..], &b"value=more"[..])));
+  /// assert_eq!(v.split_once(|&b| b == b'&'), None);
+  /// ```
+  #[inline]
+  pub fn split_once<F: FnMut(&T) -> bool>(&self, predicate: F) -> Option<(&[T], &[T])> {
+    let index = self.iter().position(predicate)?;
+    let (before, after) = self.split_at_ref(index);
+    Some((before, &after[1..]))
+  }
+
+  /// Divides the inhabited area of the StaticVec into two immutable slices around the last
+  /// element for which `predicate` returns true, with the first slice containing everything
+  /// before that element and the second containing everything after it. The matching element
+  /// itself is not included in either slice. Returns `None` if no element matches.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let v = StaticVec::<u8, 16>::from(*b"key=value=more");
+  /// assert_eq!(v.rsplit_once(|&b| b == b'='), Some((&b"key=value"[..], &b"more"[..])));
+  /// assert_eq!(v.rsplit_once(|&b| b == b'&'), None);
+  /// ```
+  #[inline]
+  pub fn rsplit_once<F: FnMut(&T) -> bool>(&self, predicate: F) -> Option<(&[T], &[T])> {
+    let index = self.iter().rposition(predicate)?;
+    let (before, after) = self.split_at_ref(index);
+    Some((before, &after[1..]))
+  }
+
+  /// Divides the inhabited area of the StaticVec into two immutable slices at index
+  /// `self.len() / 2`. If the StaticVec has an odd length, the second slice is the longer one.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// assert_eq!(staticvec![1, 2, 3, 4].halves(), (&[1, 2][..], &[3, 4][..]));
+  /// assert_eq!(staticvec![1, 2, 3].halves(), (&[1][..], &[2, 3][..]));
+  /// ```
+  #[inline(always)]
+  pub const fn halves(&self) -> (&[T], &[T]) {
+    self.split_at_ref(self.length / 2)
+  }
+
+  /// Removes all but the first of consecutive elements in the StaticVec satisfying a given equality
+  /// relation.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let mut v = staticvec!["aaa", "bbb", "BBB", "ccc", "ddd"];
+  /// v.dedup_by(|a, b| a.eq_ignore_ascii_case(b));
+  /// assert_eq!(v, ["aaa", "bbb", "ccc", "ddd"]);
+  /// ```
+  #[inline(always)]
+  pub fn dedup_by<F: FnMut(&mut T, &mut T) -> bool>(&mut self, same_bucket: F) {
+    // Mostly the same as Vec's version.
+    let new_length = self.as_mut_slice().partition_dedup_by(same_bucket).0.len();
+    self.truncate(new_length);
+  }
+
+  /// Removes consecutive repeated elements in the StaticVec according to the
+  /// locally required [`PartialEq`](core::cmp::PartialEq) trait implementation for `T`.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let mut v = staticvec![1, 2, 2, 3, 2];
+  /// v.dedup();
+  /// assert_eq!(v, [1, 2, 3, 2]);
+  /// ```
+  #[inline(always)]
+  pub fn dedup(&mut self)
+  where T: PartialEq {
+    // Exactly the same as Vec's version.
+    self.dedup_by(|a, b| a == b)
+  }
+
+  /// Removes all but the first of consecutive elements in the StaticVec that
+  /// resolve to the same key.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let mut v = staticvec![10, 20, 21, 30, 20];
+  /// v.dedup_by_key(|i| *i / 10);
+  /// assert_eq!(v, [10, 20, 30, 20]);
+  /// ```
+  #[inline(always)]
+  pub fn dedup_by_key<F: FnMut(&mut T) -> K, K: PartialEq<K>>(&mut self, mut key: F) {
+    // Exactly the same as Vec's version.
+    self.dedup_by(|a, b| key(a) == key(b))
+  }
+
+  /// Removes all but the last of consecutive elements in the StaticVec satisfying a given
+  /// equality relation. This is the counterpart to [`dedup_by`](crate::StaticVec::dedup_by), which
+  /// keeps the first of each run instead.
+  ///
+  /// As with [`dedup_by`](crate::StaticVec::dedup_by), `same_bucket` is called with each element
+  /// first and the element directly preceding it second.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let mut v = staticvec!["aaa", "bbb", "BBB", "ccc", "ddd"];
+  /// v.dedup_by_keeping_last(|a, b| a.eq_ignore_ascii_case(b));
+  /// assert_eq!(v, ["aaa", "BBB", "ccc", "ddd"]);
+  /// ```
+  #[inline]
+  pub fn dedup_by_keeping_last<F: FnMut(&mut T, &mut T) -> bool>(&mut self, mut same_bucket: F) {
+    let old_length = self.length;
+    self.retain_compacting(|i, _, mp| {
+      // Each element is compared against the one after it, which has not been moved yet. If
+      // they're in the same bucket, the current element is not the last of its run, and so gets
+      // dropped.
+      i + 1 >= old_length || unsafe { !same_bucket(&mut *mp.add(i + 1), &mut *mp.add(i)) }
+    });
+  }
+
+  /// Removes all but the last of consecutive repeated elements in the StaticVec according to the
+  /// locally required [`PartialEq`](core::cmp::PartialEq) trait implementation for `T`. This is
+  /// the counterpart to [`dedup`](crate::StaticVec::dedup), which keeps the first of each run
+  /// instead.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let mut v = staticvec![1, 2, 2, 3, 2];
+  /// v.dedup_keeping_last();
+  /// assert_eq!(v, [1, 2, 3, 2]);
+  /// ```
+  #[inline(always)]
+  pub fn dedup_keeping_last(&mut self)
+  where T: PartialEq {
+    self.dedup_by_keeping_last(|a, b| a == b)
+  }
+
+  /// Removes each element of the StaticVec for which `same` returns true when called with it and
+  /// any one of the (at most) `window` most recently kept elements before it. With a `window` of
+  /// `1` this is equivalent to [`dedup_by`](crate::StaticVec::dedup_by) (aside from the references
+  /// being constant), while larger windows allow for removing near-duplicates that aren't directly
+  /// adjacent to one another, such as in noisy time-series data. A `window` of `0` removes nothing.
+  ///
+  /// `same` is always called with the element being considered first, and the previously kept
+  /// element being compared against second.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let mut v = staticvec![1, 2, 1, 3, 4, 5, 1];
+  /// v.dedup_by_window(2, |a, b| a == b);
+  /// assert_eq!(v, [1, 2, 3, 4, 5, 1]);
+  /// ```
+  #[inline]
+  pub fn dedup_by_window<F: FnMut(&T, &T) -> bool>(&mut self, window: usize, mut same: F) {
+    self.retain_compacting(|i, kept, mp| unsafe {
+      // The kept elements are always compacted into `0..kept`, so the last `window` of them are
+      // simply the ones directly before `kept`.
+      let current = &*mp.add(i);
+      !(kept.saturating_sub(window)..kept).any(|k| same(current, &*mp.add(k)))
+    });
+  }
+
+  /// Removes consecutive repeated elements in the StaticVec according to the locally required
+  /// [`PartialEq`](core::cmp::PartialEq) trait implementation for `T` (keeping the first element
+  /// of each run, exactly as [`dedup`](crate::StaticVec::dedup) does) and returns a StaticVec
+  /// containing the length of each run, in order. The original contents can be reconstructed from
+  /// the two if so desired.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let mut v = staticvec!["a", "a", "b", "c", "c", "c", "a"];
+  /// let runs = v.dedup_runs();
+  /// assert_eq!(v, ["a", "b", "c", "a"]);
+  /// assert_eq!(runs, [2, 1, 3, 1]);
+  /// ```
+  #[inline]
+  pub fn dedup_runs(&mut self) -> StaticVec<usize, N>
+  where T: PartialEq {
+    let mut runs = StaticVec::new();
+    let slice = self.as_slice();
+    let length = slice.len();
+    let mut start = 0;
+    while start < length {
+      let mut end = start + 1;
+      while end < length && slice[end] == slice[start] {
+        end += 1;
+      }
+      // Safety: there can't possibly be more runs than there are elements in `self`, which itself
+      // cannot have more than `N` elements.
+      unsafe { runs.push_unchecked(end - start) };
+      start = end;
+    }
+    self.dedup();
+    runs
+  }
+
+  /// Removes all duplicate elements from a StaticVec that is already sorted in ascending order.
+  /// As all duplicates in a sorted StaticVec are necessarily consecutive, this is simply a more
+  /// self-documenting synonym for [`dedup`](crate::StaticVec::dedup).
+  ///
+  /// In debug builds, this function also asserts that the StaticVec is actually sorted, and will
+  /// panic if it is not. In release builds an unsorted StaticVec will simply only have its
+  /// consecutive duplicates removed, exactly as with [`dedup`](crate::StaticVec::dedup).
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let mut v = staticvec![1, 1, 2, 3, 3, 3, 4];
+  /// v.dedup_sorted();
+  /// assert_eq!(v, [1, 2, 3, 4]);
+  /// ```
+  #[inline(always)]
+  pub fn dedup_sorted(&mut self)
+  where T: PartialOrd {
+    debug_assert!(
+      self.windows(2).all(|pair| pair[0] <= pair[1]),
+      "`StaticVec::dedup_sorted` was called on an unsorted StaticVec!"
+    );
+    self.dedup()
+  }
+
+  /// Removes all duplicate elements from the StaticVec, regardless of whether or not they are
+  /// consecutive, while preserving the order in which the first occurrence of each one appears.
+  /// Locally requires that `T` implements both [`Eq`](core::cmp::Eq) and
+  /// [`Hash`](core::hash::Hash), as a [`HashSet`](std::collections::HashSet) is used internally
+  /// to keep track of which elements have already been seen.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let mut v = staticvec![1, 2, 1, 3, 2];
+  /// v.dedup_total();
+  /// assert_eq!(v, [1, 2, 3]);
+  /// ```
+  #[cfg(feature = "std")]
+  #[doc(cfg(feature = "std"))]
+  #[inline]
+  pub fn dedup_total(&mut self)
+  where T: Eq + Hash {
+    // We first determine which elements to keep while only holding constant references to them,
+    // as the `HashSet` needs to be gone before we can start moving anything around.
+    let keep = {
+      let mut seen = HashSet::with_capacity(self.length);
+      let mut keep = StaticVec::<bool, N>::new();
+      for item in self.iter() {
+        unsafe { keep.push_unchecked(seen.insert(item)) };
+      }
+      keep
+    };
+    self.retain_compacting(|i, _, _| unsafe { *keep.get_unchecked(i) });
+  }
+
+  /// Returns `true` if any two elements of the StaticVec are equal according to the locally
+  /// required [`PartialEq`](core::cmp::PartialEq) trait implementation for `T`, and `false`
+  /// otherwise (including when the StaticVec is empty). This compares every pair of elements
+  /// directly and so is `O(n^2)`, which is generally fine for a StaticVec with a small `N`. It
+  /// returns as soon as the first duplicate is found.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// assert!(staticvec![1, 2, 3, 2].has_duplicates());
+  /// assert!(!staticvec![1, 2, 3, 4].has_duplicates());
+  /// ```
+  #[inline]
+  pub fn has_duplicates(&self) -> bool
+  where T: PartialEq {
+    let slice = self.as_slice();
+    for (i, item) in slice.iter().enumerate() {
+      if slice[i + 1..].contains(item) {
+        return true;
+      }
+    }
+    false
+  }
+
+  /// Does the same thing as [`has_duplicates`](crate::StaticVec::has_duplicates), but in `O(n)`
+  /// time by way of keeping track of the elements seen so far in a
+  /// [`HashSet`](std::collections::HashSet). Locally requires that `T` implements both
+  /// [`Eq`](core::cmp::Eq) and [`Hash`](core::hash::Hash).
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// assert!(staticvec!["a", "b", "a"].has_duplicates_hashed());
+  /// assert!(!staticvec!["a", "b", "c"].has_duplicates_hashed());
+  /// ```
+  #[cfg(feature = "std")]
+  #[doc(cfg(feature = "std"))]
+  #[inline]
+  pub fn has_duplicates_hashed(&self) -> bool
+  where T: Eq + Hash {
+    let mut seen = HashSet::with_capacity(self.length);
+    // `all` short-circuits on the first item that was already present.
+    !self.iter().all(|item| seen.insert(item))
+  }
+
+  /// Collapses each run of consecutive equal elements in the StaticVec into a `(value, count)`
+  /// pair (where `value` is a clone of the first element of the run) and returns all such pairs,
+  /// in order, in a new StaticVec with a capacity of `M`.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the number of runs is greater than `M`.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let v = staticvec![1, 1, 2, 3, 3, 3];
+  /// let runs: StaticVec<(i32, usize), 3> = v.rle_into();
+  /// assert_eq!(runs, [(1, 2), (2, 1), (3, 3)]);
+  /// ```
+  #[inline]
+  pub fn rle_into<const M: usize>(&self) -> StaticVec<(T, usize), M>
+  where T: PartialEq + Clone {
+    let mut res = StaticVec::<(T, usize), M>::new();
+    let mut i = 0;
+    let length = self.length;
+    while i < length {
+      let current = unsafe { self.get_unchecked(i) };
+      let mut count = 1;
+      while i + count < length && unsafe { self.get_unchecked(i + count) } == current {
+        count += 1;
+      }
+      assert!(
+        res.is_not_full(),
+        "Insufficient capacity in `StaticVec::rle_into`!"
+      );
+      unsafe { res.push_unchecked((current.clone(), count)) };
+      i += count;
+    }
+    res
+  }
+
+  /// Returns a new StaticVec representing the difference of `self` and `other` (that is,
+  /// all items present in `self`, but *not* present in `other`.)
+  ///
+  /// The `N2` parameter does not need to be provided explicitly, and can be inferred from `other`
+  /// itself.
+  ///
+  /// Locally requires that `T` implements [`Clone`](core::clone::Clone) to avoid soundness issues
+  /// while accommodating for more types than [`Copy`](core::marker::Copy) would appropriately for
+  /// this function, and [`PartialEq`](core::cmp::PartialEq) to make the item comparisons possible.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::staticvec;
+  /// assert_eq!(
+  ///   staticvec![4, 5, 6, 7].difference(&staticvec![1, 2, 3, 7]),
+  ///   [4, 5, 6]
+  /// );
+  /// ```
+  #[inline]
+  pub fn difference<const N2: usize>(&self, other: &StaticVec<T, N2>) -> Self
+  where T: Clone + PartialEq {
+    let mut res = Self::new();
+    for left in self {
+      let mut found = false;
+      for right in other {
+        if left == right {
+          found = true;
+          break;
+        }
+      }
+      if !found {
+        unsafe { res.push_unchecked(left.clone()) }
+      }
+    }
+    res
+  }
+
+  /// Returns a new StaticVec representing the symmetric difference of `self` and `other` (that is,
+  /// all items present in at least one of `self` or `other`, but *not* present in both.)
+  ///
+  /// The `N2` parameter does not need to be provided explicitly, and can be inferred from `other`
+  /// itself.
+  ///
+  /// Locally requires that `T` implements [`Clone`](core::clone::Clone) to avoid soundness issues
+  /// while accommodating for more types than [`Copy`](core::marker::Copy) would appropriately for
+  /// this function, and [`PartialEq`](core::cmp::PartialEq) to make the item comparisons possible.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::staticvec;
+  /// assert_eq!(
+  ///   staticvec![1, 2, 3].symmetric_difference(&staticvec![3, 4, 5]),
+  ///   [1, 2, 4, 5]
+  /// );
+  /// ```
+  #[inline]
+  pub fn symmetric_difference<const N2: usize>(
+    &self,
+    other: &StaticVec<T, N2>,
+  ) -> StaticVec<T, { N + N2 }>
+  where
+    T: Clone + PartialEq,
+  {
+    let mut res = StaticVec::new();
+    for left in self {
+      let mut found = false;
+      for right in other {
+        if left == right {
+          found = true;
+          break;
+        }
+      }
+      if !found {
+        unsafe { res.push_unchecked(left.clone()) }
+      }
+    }
+    for right in other {
+      let mut found = false;
+      for left in self {
+        if right == left {
+          found = true;
+          break;
+        }
+      }
+      if !found {
+        unsafe { res.push_unchecked(right.clone()) }
+      }
+    }
+    res
+  }
+
+  /// Returns a new StaticVec representing the intersection of `self` and `other` (that is,
+  /// all items present in both `self` and `other`.)
+  ///
+  /// The `N2` parameter does not need to be provided explicitly, and can be inferred from `other`
+  /// itself.
+  ///
+  /// Locally requires that `T` implements [`Clone`](core::clone::Clone) to avoid soundness issues
+  /// while accommodating for more types than [`Copy`](core::marker::Copy) would appropriately for
+  /// this function, and [`PartialEq`](core::cmp::PartialEq) to make the item comparisons possible.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::staticvec;
+  /// assert_eq!(
+  ///   staticvec![4, 5, 6, 7].intersection(&staticvec![1, 2, 3, 7, 4]),
+  ///   [4, 7],
+  /// );
+  /// ```
+  #[inline]
+  pub fn intersection<const N2: usize>(&self, other: &StaticVec<T, N2>) -> Self
+  where T: Clone + PartialEq {
+    let mut res = Self::new();
+    for left in self {
+      let mut found = false;
+      for right in other {
+        if left == right {
+          found = true;
+          break;
+        }
+      }
+      if found && !res.contains(left) {
+        unsafe { res.push_unchecked(left.clone()) }
+      }
+    }
+    res
+  }
+
+  /// Returns a new StaticVec representing the union of `self` and `other` (that is, the full
+  /// contents of both `self` and `other`, minus any duplicates.)
+  ///
+  /// The `N2` parameter does not need to be provided explicitly, and can be inferred from `other`
+  /// itself.
+  ///
+  /// Locally requires that `T` implements [`Clone`](core::clone::Clone) to avoid soundness issues
+  /// while accommodating for more types than [`Copy`](core::marker::Copy) would appropriately for
+  /// this function, and [`PartialEq`](core::cmp::PartialEq) to make the item comparisons possible.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::staticvec;
+  /// assert_eq!(
+  ///   staticvec![1, 2, 3].union(&staticvec![4, 2, 3, 4]),
+  ///   [1, 2, 3, 4],
+  /// );
+  /// ```
+  #[allow(clippy::from_iter_instead_of_collect)]
+  #[inline]
+  #[rustfmt::skip]
+  pub fn union<const N2: usize>(&self, other: &StaticVec<T, N2>) -> StaticVec<T, { N + N2 }>
+  where T: Clone + PartialEq {
+    if self.length <= other.length {
+      let mut res = StaticVec::from_iter(self.iter().chain(other.difference(self).iter()).cloned());
+      res.dedup();
+      res
+    } else {
+      let mut res = StaticVec::from_iter(other.iter().chain(self.difference(other).iter()).cloned());
+      res.dedup();
+      res
+    }
+  }
+
+  /// A concept borrowed from the widely-used `SmallVec` crate, this function
+  /// returns a tuple consisting of a constant pointer to the first element of the StaticVec,
+  /// the length of the StaticVec, and the capacity of the StaticVec.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// static V: StaticVec<usize, 4> = staticvec![4, 5, 6, 7];
+  /// assert_eq!(V.triple(), (V.as_ptr(), 4, 4));
+  /// ```
+  #[inline(always)]
+  pub const fn triple(&self) -> (*const T, usize, usize) {
+    (self.as_ptr(), self.length, N)
+  }
+
+  /// A mutable version of [`triple`](crate::StaticVec::triple). This implementation differs from
+  /// the one found in `SmallVec` in that it only provides the first element of the StaticVec as
+  /// a mutable pointer, not also the length as a mutable reference.
+  ///
+  /// Example:
+  /// ```
+  /// # use::staticvec::*;
+  /// let mut v = staticvec![4, 5, 6, 7];
+  /// let mp = v.as_mut_ptr();
+  /// let t = v.triple_mut();
+  /// assert_eq!(t, (mp, 4, 4));
+  /// unsafe { *t.0 = 8 };
+  /// assert_eq!(v, [8, 5, 6, 7]);
+  /// ```
+  #[inline(always)]
+  pub const fn triple_mut(&mut self) -> (*mut T, usize, usize) {
+    (self.as_mut_ptr(), self.length, N)
+  }
+
+  /// Decomposes the StaticVec into its raw components, namely its backing array (still wrapped in
+  /// `MaybeUninit`) and its length, without running any destructors. The caller becomes
+  /// responsible for the first `length` elements of the returned array, which are the only ones
+  /// that are initialized. The StaticVec can be reassembled afterwards with
+  /// [`from_raw_parts`](crate::StaticVec::from_raw_parts).
+  ///
+  /// Note that unlike `Vec::into_raw_parts`, this returns the backing storage itself as opposed to
+  /// a pointer to it, as a StaticVec's storage lives inline and so any pointer to it would not
+  /// remain valid once the StaticVec was moved or forgotten. The capacity is always just `N`.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let v = staticvec![Box::new(1), Box::new(2), Box::new(3)];
+  /// let (data, length) = v.into_raw_parts();
+  /// assert_eq!(length, 3);
+  /// let v2 = unsafe { StaticVec::from_raw_parts(data, length) };
+  /// assert_eq!(v2, [Box::new(1), Box::new(2), Box::new(3)]);
+  /// ```
+  #[inline(always)]
+  pub const fn into_raw_parts(self) -> (MaybeUninit<[T; N]>, usize) {
+    let mut this = MaybeUninit::new(self);
+    // Safety: `this` was just initialized above, and since it's wrapped in `MaybeUninit` it will
+    // never be dropped, so moving `data` out of it cannot lead to double-drops.
+    let this = unsafe { this.assume_init_mut() };
+    (mem::replace(&mut this.data, Self::new_data_uninit()), this.length)
+  }
+
+  /// Creates a StaticVec directly from a backing array (wrapped in `MaybeUninit`) and a length,
+  /// such as those returned by [`into_raw_parts`](crate::StaticVec::into_raw_parts).
+  ///
+  /// # Safety
+  ///
+  /// `length` must not be greater than `N`, and the first `length` elements of `data` must be
+  /// initialized, as the resulting StaticVec will treat them as such (including dropping them). The
+  /// caller must also not use or drop those elements by any other means afterwards.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// use core::mem::MaybeUninit;
+  /// let mut data = MaybeUninit::<[i32; 4]>::uninit();
+  /// unsafe { (data.as_mut_ptr() as *mut i32).write(7) };
+  /// let v = unsafe { StaticVec::from_raw_parts(data, 1) };
+  /// assert_eq!(v, [7]);
+  /// ```
+  #[inline(always)]
+  pub const unsafe fn from_raw_parts(data: MaybeUninit<[T; N]>, length: usize) -> Self {
+    debug_assert!(
+      length <= N,
+      "In `StaticVec::from_raw_parts`, provided length exceeds the maximum capacity!"
+    );
+    Self { data, length }
+  }
+
+  /// Combines the elements of the StaticVec with those of `other` pairwise using `f`, and returns
+  /// the results in a new StaticVec with a capacity of `M`. Only the first
+  /// `self.len().min(other.len())` elements of each are combined, so the result has that length.
+  ///
+  /// The `N2` parameter does not need to be provided explicitly, and can be inferred from `other`
+  /// itself.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `self.len().min(other.len())` is greater than `M`.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let a = staticvec![1, 2, 3, 4];
+  /// let b = staticvec![10.0, 20.0, 30.0];
+  /// let c: StaticVec<f64, 3> = a.zip_with(&b, |x, y| *x as f64 * *y);
+  /// assert_eq!(c, [10.0, 40.0, 90.0]);
+  /// ```
+  #[inline]
+  pub fn zip_with<U, V, F: FnMut(&T, &U) -> V, const N2: usize, const M: usize>(
+    &self,
+    other: &StaticVec<U, N2>,
+    mut f: F,
+  ) -> StaticVec<V, M> {
+    let length = self.length.min(other.length);
+    assert!(length <= M, "Insufficient capacity in `StaticVec::zip_with`!");
+    let mut res = StaticVec::new();
+    for i in 0..length {
+      unsafe {
+        res.push_unchecked(f(self.get_unchecked(i), other.get_unchecked(i)));
+      }
+    }
+    res
+  }
+
+  /// Linearly adds (in a mathematical sense) the contents of two same-capacity
+  /// StaticVecs and returns the results in a new one of equal capacity.
+  ///
+  /// Locally requires that `T` implements [`Copy`](core::marker::Copy) to allow
+  /// for an efficient implementation, and [`Add`](core::ops::Add) to make it possible
+  /// to add the elements.
+  ///
+  /// Only the first `self.len().min(other.len())` elements of each are processed, so the
+  /// returned StaticVec has a length equal to that of the shorter of the two.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::{staticvec, StaticVec};
+  /// const A: StaticVec<f64, 4> = staticvec![4.0, 5.0, 6.0, 7.0];
+  /// const B: StaticVec<f64, 4> = staticvec![2.0, 3.0, 4.0, 5.0];
+  /// assert_eq!(A.added(&B), [6.0, 8.0, 10.0, 12.0]);
+  /// assert_eq!(A.added(&StaticVec::new_from_slice(&[2.0, 3.0])), [6.0, 8.0]);
+  /// ```
+  #[inline(always)]
+  pub fn added(&self, other: &Self) -> Self
+  where T: Copy + Add<Output = T> {
+    let length = self.length.min(other.length);
+    let mut res = Self::new();
+    for i in 0..length {
+      unsafe {
+        res
+          .mut_ptr_at_unchecked(i)
+          .write(*self.get_unchecked(i) + *other.get_unchecked(i));
+      }
+    }
+    res.length = length;
+    res
+  }
+
+  /// Linearly subtracts (in a mathematical sense) the contents of two same-capacity
+  /// StaticVecs and returns the results in a new one of equal capacity.
+  ///
+  /// Locally requires that `T` implements [`Copy`](core::marker::Copy) to allow
+  /// for an efficient implementation, and [`Sub`](core::ops::Sub) to make it possible
+  /// to subtract the elements.
+  ///
+  /// Only the first `self.len().min(other.len())` elements of each are processed, so the
+  /// returned StaticVec has a length equal to that of the shorter of the two.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::{staticvec, StaticVec};
+  /// const A: StaticVec<f64, 4> = staticvec![4.0, 5.0, 6.0, 7.0];
+  /// const B: StaticVec<f64, 4> = staticvec![2.0, 3.0, 4.0, 5.0];
+  /// assert_eq!(A.subtracted(&B), [2.0, 2.0, 2.0, 2.0]);
+  /// assert_eq!(A.subtracted(&StaticVec::new_from_slice(&[2.0, 3.0])), [2.0, 2.0]);
+  /// ```
+  #[inline(always)]
+  pub fn subtracted(&self, other: &Self) -> Self
+  where T: Copy + Sub<Output = T> {
+    let length = self.length.min(other.length);
+    let mut res = Self::new();
+    for i in 0..length {
+      unsafe {
+        res
+          .mut_ptr_at_unchecked(i)
+          .write(*self.get_unchecked(i) - *other.get_unchecked(i));
+      }
+    }
+    res.length = length;
+    res
+  }
+
+  /// Linearly multiplies (in a mathematical sense) the contents of two same-capacity
+  /// StaticVecs and returns the results in a new one of equal capacity.
+  ///
+  /// Locally requires that `T` implements [`Copy`](core::marker::Copy) to allow
+  /// for an efficient implementation, and [`Mul`](core::ops::Mul) to make it possible
+  /// to multiply the elements.
+  ///
+  /// Only the first `self.len().min(other.len())` elements of each are processed, so the
+  /// returned StaticVec has a length equal to that of the shorter of the two.
   ///
   /// # Example usage:
   /// ```
-  /// # use staticvec::*;
-  /// let mut v = staticvec![1, 2, 3, 4, 5];
-  /// v.truncate(2);
-  /// assert_eq!(v, [1, 2]);
+  /// # use staticvec::{staticvec, StaticVec};
+  /// const A: StaticVec<f64, 4> = staticvec![4.0, 5.0, 6.0, 7.0];
+  /// const B: StaticVec<f64, 4> = staticvec![2.0, 3.0, 4.0, 5.0];
+  /// assert_eq!(A.multiplied(&B), [8.0, 15.0, 24.0, 35.0]);
+  /// assert_eq!(A.multiplied(&StaticVec::new_from_slice(&[2.0, 3.0])), [8.0, 15.0]);
   /// ```
   #[inline(always)]
-  pub fn truncate(&mut self, length: usize) {
-    if length < self.length {
-      let old_length = self.length;
+  pub fn multiplied(&self, other: &Self) -> Self
+  where T: Copy + Mul<Output = T> {
+    let length = self.length.min(other.length);
+    let mut res = Self::new();
+    for i in 0..length {
       unsafe {
-        self.set_len(length);
-        ptr::drop_in_place(from_raw_parts_mut(
-          self.mut_ptr_at_unchecked(length),
-          old_length - length,
-        ));
+        res
+          .mut_ptr_at_unchecked(i)
+          .write(*self.get_unchecked(i) * *other.get_unchecked(i));
       }
     }
+    res.length = length;
+    res
   }
 
-  /// Splits one StaticVec into two at the given index, returning the second half without consuming
-  /// the first half. The original StaticVec will contain all elements within the exclusive range
-  /// `0..at`, and the new one will contain all elements within the exclusive range
-  /// `at..self.len()`. This function will panic if `at` is greater than `self.len()`.
+  /// Linearly divides (in a mathematical sense) the contents of two same-capacity
+  /// StaticVecs and returns the results in a new one of equal capacity.
+  ///
+  /// Locally requires that `T` implements [`Copy`](core::marker::Copy) to allow
+  /// for an efficient implementation, and [`Div`](core::ops::Div) to make it possible
+  /// to divide the elements.
+  ///
+  /// Only the first `self.len().min(other.len())` elements of each are processed, so the
+  /// returned StaticVec has a length equal to that of the shorter of the two.
   ///
   /// # Example usage:
   /// ```
-  /// # use staticvec::*;
-  /// let mut v1 = staticvec![1, 2, 3];
-  /// let v2 = v1.split_off(1);
-  /// assert_eq!(v1, [1]);
-  /// assert_eq!(v2, [2, 3]);
+  /// # use staticvec::{staticvec, StaticVec};
+  /// const A: StaticVec<f64, 4> = staticvec![4.0, 5.0, 6.0, 7.0];
+  /// const B: StaticVec<f64, 4> = staticvec![2.0, 3.0, 4.0, 5.0];
+  /// assert_eq!(A.divided(&B), [2.0, 1.6666666666666667, 1.5, 1.4]);
+  /// assert_eq!(A.divided(&StaticVec::new_from_slice(&[2.0, 3.0])), [2.0, 1.6666666666666667]);
   /// ```
-  #[inline]
-  pub const fn split_off(&mut self, at: usize) -> Self {
-    let old_length = self.length;
-    assert!(
-      at <= old_length,
-      "Bounds check failure in `StaticVec::split_off`!"
-    );
-    let split_length = old_length - at;
-    Self {
-      data: unsafe {
-        self.set_len(at);
-        let mut split = Self::new_data_uninit();
-        self
-          .ptr_at_unchecked(at)
-          .copy_to_nonoverlapping(Self::first_ptr_mut(&mut split), split_length);
-        split
-      },
-      length: split_length,
+  #[inline(always)]
+  pub fn divided(&self, other: &Self) -> Self
+  where T: Copy + Div<Output = T> {
+    let length = self.length.min(other.length);
+    let mut res = Self::new();
+    for i in 0..length {
+      unsafe {
+        res
+          .mut_ptr_at_unchecked(i)
+          .write(*self.get_unchecked(i) / *other.get_unchecked(i));
+      }
     }
+    res.length = length;
+    res
   }
 
-  /// Splits one StaticVec into two new ones at index `M` and returns them in a tuple, while
-  /// consuming the original. The first new one will contain all elements within the exclusive range
-  /// `0..M`, and the second new one will contain all elements within the exclusive range
-  /// `M..self.len()`. This function will panic if `M` is greater than `self.len()`.
+  /// Linearly adds the contents of two same-capacity StaticVecs of primitive integers using
+  /// saturating arithmetic, and returns the results in a new one of equal capacity. Any result
+  /// that would overflow is clamped to `T::MAX` or `T::MIN` instead.
+  ///
+  /// Only the first `self.len().min(other.len())` elements of each are processed, so the
+  /// returned StaticVec has a length equal to that of the shorter of the two.
   ///
   /// # Example usage:
   /// ```
   /// # use staticvec::*;
-  /// let v1 = staticvec![Box::new(1), Box::new(2), Box::new(3), Box::new(4), Box::new(5), Box::new(6)];
-  /// let t1 = v1.split_at::<0>();
-  /// assert_eq!(t1.0, []);
-  /// assert_eq!(t1.1, [Box::new(1), Box::new(2), Box::new(3), Box::new(4), Box::new(5), Box::new(6)]);
-  /// let v2 = staticvec![Box::new(1), Box::new(2), Box::new(3), Box::new(4), Box::new(5), Box::new(6)];
-  /// let t2 = v2.split_at::<2>();
-  /// assert_eq!(t2.0, [Box::new(1), Box::new(2)]);
-  /// assert_eq!(t2.1, [Box::new(3), Box::new(4), Box::new(5), Box::new(6)]);
-  /// let v3 = staticvec![Box::new(1), Box::new(2), Box::new(3), Box::new(4), Box::new(5), Box::new(6)];
-  /// let t3 = v3.split_at::<6>();
-  /// assert_eq!(t3.0, [Box::new(1), Box::new(2), Box::new(3), Box::new(4), Box::new(5), Box::new(6)]);
-  /// assert_eq!(t3.1, []);
+  /// let a = staticvec![i32::MAX - 1, 2, i32::MIN + 1];
+  /// let b = staticvec![2, 3, 2];
+  /// assert_eq!(a.added_saturating(&b), [i32::MAX, 5, i32::MIN + 3]);
+  /// ```
   #[inline]
-  pub const fn split_at<const M: usize>(self) -> (StaticVec<T, M>, StaticVec<T, { N - M }>) {
-    let old_length = self.length;
-    assert!(
-      M <= old_length,
-      "Bounds check failure in `StaticVec::split_at'!"
-    );
-    let mut left = MaybeUninit::<[T; M]>::uninit();
-    let mut right = MaybeUninit::<[T; N - M]>::uninit();
-    let self_ptr = self.as_ptr();
-    let left_ptr = StaticVec::first_ptr_mut(&mut left);
-    let right_ptr = StaticVec::first_ptr_mut(&mut right);
-    let right_length = old_length - M;
-    unsafe {
-      self_ptr.copy_to_nonoverlapping(left_ptr, M);
-      self_ptr
-        .add(M)
-        .copy_to_nonoverlapping(right_ptr, right_length);
-      mem::forget(self);
-      (
-        StaticVec {
-          data: left,
-          length: M,
-        },
-        StaticVec {
-          data: right,
-          length: right_length,
-        },
-      )
+  pub fn added_saturating(&self, other: &Self) -> Self
+  where T: IntegerArithmetic {
+    let length = self.length.min(other.length);
+    let mut res = Self::new();
+    for i in 0..length {
+      unsafe {
+        res
+          .mut_ptr_at_unchecked(i)
+          .write(self.get_unchecked(i).saturating_add(*other.get_unchecked(i)));
+      }
     }
+    res.length = length;
+    res
   }
 
-  /// Removes all but the first of consecutive elements in the StaticVec satisfying a given equality
-  /// relation.
+  /// Linearly subtracts the contents of two same-capacity StaticVecs of primitive integers using
+  /// saturating arithmetic, and returns the results in a new one of equal capacity. Any result
+  /// that would overflow is clamped to `T::MAX` or `T::MIN` instead.
+  ///
+  /// Only the first `self.len().min(other.len())` elements of each are processed, so the
+  /// returned StaticVec has a length equal to that of the shorter of the two.
   ///
   /// # Example usage:
   /// ```
   /// # use staticvec::*;
-  /// let mut v = staticvec!["aaa", "bbb", "BBB", "ccc", "ddd"];
-  /// v.dedup_by(|a, b| a.eq_ignore_ascii_case(b));
-  /// assert_eq!(v, ["aaa", "bbb", "ccc", "ddd"]);
+  /// let a = staticvec![i32::MAX - 1, 2, i32::MIN + 1];
+  /// let b = staticvec![2, 3, 2];
+  /// assert_eq!(a.subtracted_saturating(&b), [i32::MAX - 3, -1, i32::MIN]);
   /// ```
-  #[inline(always)]
-  pub fn dedup_by<F: FnMut(&mut T, &mut T) -> bool>(&mut self, same_bucket: F) {
-    // Mostly the same as Vec's version.
-    let new_length = self.as_mut_slice().partition_dedup_by(same_bucket).0.len();
-    self.truncate(new_length);
+  #[inline]
+  pub fn subtracted_saturating(&self, other: &Self) -> Self
+  where T: IntegerArithmetic {
+    let length = self.length.min(other.length);
+    let mut res = Self::new();
+    for i in 0..length {
+      unsafe {
+        res
+          .mut_ptr_at_unchecked(i)
+          .write(self.get_unchecked(i).saturating_sub(*other.get_unchecked(i)));
+      }
+    }
+    res.length = length;
+    res
   }
 
-  /// Removes consecutive repeated elements in the StaticVec according to the
-  /// locally required [`PartialEq`](core::cmp::PartialEq) trait implementation for `T`.
+  /// Linearly multiplies the contents of two same-capacity StaticVecs of primitive integers using
+  /// saturating arithmetic, and returns the results in a new one of equal capacity. Any result
+  /// that would overflow is clamped to `T::MAX` or `T::MIN` instead.
+  ///
+  /// Only the first `self.len().min(other.len())` elements of each are processed, so the
+  /// returned StaticVec has a length equal to that of the shorter of the two.
   ///
   /// # Example usage:
   /// ```
   /// # use staticvec::*;
-  /// let mut v = staticvec![1, 2, 2, 3, 2];
-  /// v.dedup();
-  /// assert_eq!(v, [1, 2, 3, 2]);
+  /// let a = staticvec![i32::MAX - 1, 2, i32::MIN + 1];
+  /// let b = staticvec![2, 3, 2];
+  /// assert_eq!(a.multiplied_saturating(&b), [i32::MAX, 6, i32::MIN]);
   /// ```
-  #[inline(always)]
-  pub fn dedup(&mut self)
-  where T: PartialEq {
-    // Exactly the same as Vec's version.
-    self.dedup_by(|a, b| a == b)
+  #[inline]
+  pub fn multiplied_saturating(&self, other: &Self) -> Self
+  where T: IntegerArithmetic {
+    let length = self.length.min(other.length);
+    let mut res = Self::new();
+    for i in 0..length {
+      unsafe {
+        res
+          .mut_ptr_at_unchecked(i)
+          .write(self.get_unchecked(i).saturating_mul(*other.get_unchecked(i)));
+      }
+    }
+    res.length = length;
+    res
   }
 
-  /// Removes all but the first of consecutive elements in the StaticVec that
-  /// resolve to the same key.
+  /// Linearly adds the contents of two same-capacity StaticVecs of primitive integers using
+  /// checked arithmetic, and returns the results in a new one of equal capacity in `Some`, or
+  /// returns `None` if any individual operation overflows.
+  ///
+  /// Only the first `self.len().min(other.len())` elements of each are processed, so the
+  /// returned StaticVec has a length equal to that of the shorter of the two.
   ///
   /// # Example usage:
   /// ```
   /// # use staticvec::*;
-  /// let mut v = staticvec![10, 20, 21, 30, 20];
-  /// v.dedup_by_key(|i| *i / 10);
-  /// assert_eq!(v, [10, 20, 30, 20]);
+  /// let a = staticvec![1u8, 2, 3];
+  /// assert_eq!(a.checked_added(&staticvec![1, 2, 3]), Some(staticvec![2, 4, 6]));
+  /// assert_eq!(a.checked_added(&staticvec![1, 255, 3]), None);
   /// ```
-  #[inline(always)]
-  pub fn dedup_by_key<F: FnMut(&mut T) -> K, K: PartialEq<K>>(&mut self, mut key: F) {
-    // Exactly the same as Vec's version.
-    self.dedup_by(|a, b| key(a) == key(b))
+  #[inline]
+  pub fn checked_added(&self, other: &Self) -> Option<Self>
+  where T: IntegerArithmetic {
+    let length = self.length.min(other.length);
+    let mut res = Self::new();
+    for i in 0..length {
+      unsafe {
+        res
+          .mut_ptr_at_unchecked(i)
+          .write(self.get_unchecked(i).checked_add(*other.get_unchecked(i))?);
+      }
+    }
+    res.length = length;
+    Some(res)
   }
 
-  /// Returns a new StaticVec representing the difference of `self` and `other` (that is,
-  /// all items present in `self`, but *not* present in `other`.)
-  ///
-  /// The `N2` parameter does not need to be provided explicitly, and can be inferred from `other`
-  /// itself.
+  /// Linearly subtracts the contents of two same-capacity StaticVecs of primitive integers using
+  /// checked arithmetic, and returns the results in a new one of equal capacity in `Some`, or
+  /// returns `None` if any individual operation overflows.
   ///
-  /// Locally requires that `T` implements [`Clone`](core::clone::Clone) to avoid soundness issues
-  /// while accommodating for more types than [`Copy`](core::marker::Copy) would appropriately for
-  /// this function, and [`PartialEq`](core::cmp::PartialEq) to make the item comparisons possible.
+  /// Only the first `self.len().min(other.len())` elements of each are processed, so the
+  /// returned StaticVec has a length equal to that of the shorter of the two.
   ///
   /// # Example usage:
   /// ```
-  /// # use staticvec::staticvec;
-  /// assert_eq!(
-  ///   staticvec![4, 5, 6, 7].difference(&staticvec![1, 2, 3, 7]),
-  ///   [4, 5, 6]
-  /// );
+  /// # use staticvec::*;
+  /// let a = staticvec![1u8, 2, 3];
+  /// assert_eq!(a.checked_subtracted(&staticvec![1, 1, 1]), Some(staticvec![0, 1, 2]));
+  /// assert_eq!(a.checked_subtracted(&staticvec![1, 3, 1]), None);
   /// ```
   #[inline]
-  pub fn difference<const N2: usize>(&self, other: &StaticVec<T, N2>) -> Self
-  where T: Clone + PartialEq {
+  pub fn checked_subtracted(&self, other: &Self) -> Option<Self>
+  where T: IntegerArithmetic {
+    let length = self.length.min(other.length);
     let mut res = Self::new();
-    for left in self {
-      let mut found = false;
-      for right in other {
-        if left == right {
-          found = true;
-          break;
-        }
-      }
-      if !found {
-        unsafe { res.push_unchecked(left.clone()) }
+    for i in 0..length {
+      unsafe {
+        res
+          .mut_ptr_at_unchecked(i)
+          .write(self.get_unchecked(i).checked_sub(*other.get_unchecked(i))?);
       }
     }
-    res
+    res.length = length;
+    Some(res)
   }
 
-  /// Returns a new StaticVec representing the symmetric difference of `self` and `other` (that is,
-  /// all items present in at least one of `self` or `other`, but *not* present in both.)
-  ///
-  /// The `N2` parameter does not need to be provided explicitly, and can be inferred from `other`
-  /// itself.
+  /// Linearly multiplies the contents of two same-capacity StaticVecs of primitive integers using
+  /// checked arithmetic, and returns the results in a new one of equal capacity in `Some`, or
+  /// returns `None` if any individual operation overflows.
   ///
-  /// Locally requires that `T` implements [`Clone`](core::clone::Clone) to avoid soundness issues
-  /// while accommodating for more types than [`Copy`](core::marker::Copy) would appropriately for
-  /// this function, and [`PartialEq`](core::cmp::PartialEq) to make the item comparisons possible.
+  /// Only the first `self.len().min(other.len())` elements of each are processed, so the
+  /// returned StaticVec has a length equal to that of the shorter of the two.
   ///
   /// # Example usage:
   /// ```
-  /// # use staticvec::staticvec;
-  /// assert_eq!(
-  ///   staticvec![1, 2, 3].symmetric_difference(&staticvec![3, 4, 5]),
-  ///   [1, 2, 4, 5]
-  /// );
+  /// # use staticvec::*;
+  /// let a = staticvec![1u8, 2, 3];
+  /// assert_eq!(a.checked_multiplied(&staticvec![4, 5, 6]), Some(staticvec![4, 10, 18]));
+  /// assert_eq!(a.checked_multiplied(&staticvec![4, 128, 6]), None);
   /// ```
-  #[inline]
-  pub fn symmetric_difference<const N2: usize>(
-    &self,
-    other: &StaticVec<T, N2>,
-  ) -> StaticVec<T, { N + N2 }>
-  where
-    T: Clone + PartialEq,
-  {
-    let mut res = StaticVec::new();
-    for left in self {
-      let mut found = false;
-      for right in other {
-        if left == right {
-          found = true;
-          break;
-        }
-      }
-      if !found {
-        unsafe { res.push_unchecked(left.clone()) }
-      }
-    }
-    for right in other {
-      let mut found = false;
-      for left in self {
-        if right == left {
-          found = true;
-          break;
-        }
-      }
-      if !found {
-        unsafe { res.push_unchecked(right.clone()) }
+  #[inline]
+  pub fn checked_multiplied(&self, other: &Self) -> Option<Self>
+  where T: IntegerArithmetic {
+    let length = self.length.min(other.length);
+    let mut res = Self::new();
+    for i in 0..length {
+      unsafe {
+        res
+          .mut_ptr_at_unchecked(i)
+          .write(self.get_unchecked(i).checked_mul(*other.get_unchecked(i))?);
       }
     }
-    res
+    res.length = length;
+    Some(res)
   }
 
-  /// Returns a new StaticVec representing the intersection of `self` and `other` (that is,
-  /// all items present in both `self` and `other`.)
+  /// Linearly divides the contents of two same-capacity StaticVecs of primitive integers using
+  /// checked arithmetic, and returns the results in a new one of equal capacity in `Some`, or
+  /// returns `None` if any individual operation overflows or any element of `other` is zero.
   ///
-  /// The `N2` parameter does not need to be provided explicitly, and can be inferred from `other`
-  /// itself.
-  ///
-  /// Locally requires that `T` implements [`Clone`](core::clone::Clone) to avoid soundness issues
-  /// while accommodating for more types than [`Copy`](core::marker::Copy) would appropriately for
-  /// this function, and [`PartialEq`](core::cmp::PartialEq) to make the item comparisons possible.
+  /// Only the first `self.len().min(other.len())` elements of each are processed, so the
+  /// returned StaticVec has a length equal to that of the shorter of the two.
   ///
   /// # Example usage:
   /// ```
-  /// # use staticvec::staticvec;
-  /// assert_eq!(
-  ///   staticvec![4, 5, 6, 7].intersection(&staticvec![1, 2, 3, 7, 4]),
-  ///   [4, 7],
-  /// );
+  /// # use staticvec::*;
+  /// let a = staticvec![8u8, 6, 4];
+  /// assert_eq!(a.checked_divided(&staticvec![4, 3, 2]), Some(staticvec![2, 2, 2]));
+  /// assert_eq!(a.checked_divided(&staticvec![4, 0, 2]), None);
   /// ```
   #[inline]
-  pub fn intersection<const N2: usize>(&self, other: &StaticVec<T, N2>) -> Self
-  where T: Clone + PartialEq {
+  pub fn checked_divided(&self, other: &Self) -> Option<Self>
+  where T: IntegerArithmetic {
+    let length = self.length.min(other.length);
     let mut res = Self::new();
-    for left in self {
-      let mut found = false;
-      for right in other {
-        if left == right {
-          found = true;
-          break;
-        }
-      }
-      if found && !res.contains(left) {
-        unsafe { res.push_unchecked(left.clone()) }
+    for i in 0..length {
+      unsafe {
+        res
+          .mut_ptr_at_unchecked(i)
+          .write(self.get_unchecked(i).checked_div(*other.get_unchecked(i))?);
       }
     }
-    res
+    res.length = length;
+    Some(res)
   }
 
-  /// Returns a new StaticVec representing the union of `self` and `other` (that is, the full
-  /// contents of both `self` and `other`, minus any duplicates.)
-  ///
-  /// The `N2` parameter does not need to be provided explicitly, and can be inferred from `other`
-  /// itself.
+  /// Returns the dot product of `self` and `other`, that is, the sum of the products of each pair
+  /// of corresponding elements. Only the first `self.len().min(other.len())` elements of each are
+  /// considered, and `T::default()` is returned if that number is zero.
   ///
-  /// Locally requires that `T` implements [`Clone`](core::clone::Clone) to avoid soundness issues
-  /// while accommodating for more types than [`Copy`](core::marker::Copy) would appropriately for
-  /// this function, and [`PartialEq`](core::cmp::PartialEq) to make the item comparisons possible.
+  /// Locally requires that `T` implements [`Copy`](core::marker::Copy),
+  /// [`Mul`](core::ops::Mul), [`Add`](core::ops::Add), and [`Default`](core::default::Default),
+  /// with the default value of `T` expected to be its additive identity.
   ///
   /// # Example usage:
   /// ```
-  /// # use staticvec::staticvec;
-  /// assert_eq!(
-  ///   staticvec![1, 2, 3].union(&staticvec![4, 2, 3, 4]),
-  ///   [1, 2, 3, 4],
-  /// );
+  /// # use staticvec::*;
+  /// let a = staticvec![1.0, 2.0, 3.0];
+  /// let b = staticvec![4.0, 5.0, 6.0];
+  /// assert_eq!(a.dot(&b), 32.0);
+  /// assert_eq!(StaticVec::<f64, 3>::new().dot(&b), 0.0);
   /// ```
-  #[allow(clippy::from_iter_instead_of_collect)]
   #[inline]
-  #[rustfmt::skip]
-  pub fn union<const N2: usize>(&self, other: &StaticVec<T, N2>) -> StaticVec<T, { N + N2 }>
-  where T: Clone + PartialEq {
-    if self.length <= other.length {
-      let mut res = StaticVec::from_iter(self.iter().chain(other.difference(self).iter()).cloned());
-      res.dedup();
-      res
-    } else {
-      let mut res = StaticVec::from_iter(other.iter().chain(self.difference(other).iter()).cloned());
-      res.dedup();
-      res
+  pub fn dot(&self, other: &Self) -> T
+  where T: Copy + Mul<Output = T> + Add<Output = T> + Default {
+    let length = self.length.min(other.length);
+    let mut res = T::default();
+    for i in 0..length {
+      unsafe {
+        res = res + *self.get_unchecked(i) * *other.get_unchecked(i);
+      }
     }
+    res
   }
 
-  /// A concept borrowed from the widely-used `SmallVec` crate, this function
-  /// returns a tuple consisting of a constant pointer to the first element of the StaticVec,
-  /// the length of the StaticVec, and the capacity of the StaticVec.
+  /// Returns the sum of all elements of the StaticVec, which is `T`'s additive identity
+  /// (for example, `0` for integers) if the StaticVec is empty.
+  ///
+  /// Locally requires that `T` implements [`Copy`](core::marker::Copy) and
+  /// [`Sum`](core::iter::Sum).
   ///
   /// # Example usage:
   /// ```
   /// # use staticvec::*;
-  /// static V: StaticVec<usize, 4> = staticvec![4, 5, 6, 7];
-  /// assert_eq!(V.triple(), (V.as_ptr(), 4, 4));
+  /// assert_eq!(staticvec![1, 2, 3, 4].sum(), 10);
+  /// assert_eq!(StaticVec::<i32, 4>::new().sum(), 0);
   /// ```
   #[inline(always)]
-  pub const fn triple(&self) -> (*const T, usize, usize) {
-    (self.as_ptr(), self.length, N)
+  pub fn sum(&self) -> T
+  where T: Copy + Sum<T> {
+    self.iter().copied().sum()
   }
 
-  /// A mutable version of [`triple`](crate::StaticVec::triple). This implementation differs from
-  /// the one found in `SmallVec` in that it only provides the first element of the StaticVec as
-  /// a mutable pointer, not also the length as a mutable reference.
+  /// Returns the sum of all elements of the StaticVec in `Some`, or `None` if any of the
+  /// intermediate additions overflow. Unlike with [`sum`](crate::StaticVec::sum), which panics on
+  /// overflow in debug builds and wraps around silently in release builds, the result here is the
+  /// same regardless of the build configuration, making it well suited for use with untrusted
+  /// input. Returns `Some(0)` if the StaticVec is empty.
   ///
-  /// Example:
+  /// Locally requires that `T` is one of the primitive integer types.
+  ///
+  /// # Example usage:
   /// ```
-  /// # use::staticvec::*;
-  /// let mut v = staticvec![4, 5, 6, 7];
-  /// let mp = v.as_mut_ptr();
-  /// let t = v.triple_mut();
-  /// assert_eq!(t, (mp, 4, 4));
-  /// unsafe { *t.0 = 8 };
-  /// assert_eq!(v, [8, 5, 6, 7]);
+  /// # use staticvec::*;
+  /// assert_eq!(staticvec![100u8, 100, 55].checked_sum(), Some(255));
+  /// assert_eq!(staticvec![100u8, 100, 56].checked_sum(), None);
+  /// assert_eq!(StaticVec::<u8, 4>::new().checked_sum(), Some(0));
   /// ```
-  #[inline(always)]
-  pub const fn triple_mut(&mut self) -> (*mut T, usize, usize) {
-    (self.as_mut_ptr(), self.length, N)
+  #[inline]
+  pub fn checked_sum(&self) -> Option<T>
+  where T: IntegerArithmetic {
+    self.iter().try_fold(T::ZERO, |acc, &value| acc.checked_add(value))
   }
 
-  /// Linearly adds (in a mathematical sense) the contents of two same-capacity
-  /// StaticVecs and returns the results in a new one of equal capacity.
-  ///
-  /// Locally requires that `T` implements [`Copy`](core::marker::Copy) to allow
-  /// for an efficient implementation, and [`Add`](core::ops::Add) to make it possible
-  /// to add the elements.
+  /// Returns the product of all elements of the StaticVec, which is `T`'s multiplicative
+  /// identity (for example, `1` for integers) if the StaticVec is empty.
   ///
-  /// For both performance and safety reasons, this function requires that both `self`
-  /// and `other` are at full capacity, and will panic if that is not the case (that is,
-  /// if `self.is_full() && other.is_full()` is not equal to `true`.)
+  /// Locally requires that `T` implements [`Copy`](core::marker::Copy) and
+  /// [`Product`](core::iter::Product).
   ///
   /// # Example usage:
   /// ```
-  /// # use staticvec::{staticvec, StaticVec};
-  /// const A: StaticVec<f64, 4> = staticvec![4.0, 5.0, 6.0, 7.0];
-  /// const B: StaticVec<f64, 4> = staticvec![2.0, 3.0, 4.0, 5.0];
-  /// assert_eq!(A.added(&B), [6.0, 8.0, 10.0, 12.0]);
+  /// # use staticvec::*;
+  /// assert_eq!(staticvec![1, 2, 3, 4].product(), 24);
+  /// assert_eq!(StaticVec::<i32, 4>::new().product(), 1);
   /// ```
   #[inline(always)]
-  pub fn added(&self, other: &Self) -> Self
-  where T: Copy + Add<Output = T> {
-    assert!(
-      self.is_full() && other.is_full(),
-      "In `StaticVec::added`, both `self` and `other` must be at maximum capacity!"
-    );
-    let mut res = Self::new();
-    for i in 0..N {
-      unsafe {
-        res
-          .mut_ptr_at_unchecked(i)
-          .write(*self.get_unchecked(i) + *other.get_unchecked(i));
-      }
-    }
-    res.length = N;
-    res
+  pub fn product(&self) -> T
+  where T: Copy + Product<T> {
+    self.iter().copied().product()
   }
 
-  /// Linearly subtracts (in a mathematical sense) the contents of two same-capacity
-  /// StaticVecs and returns the results in a new one of equal capacity.
+  /// Returns the arithmetic mean of all elements of the StaticVec as an `f64` in `Some`, or `None`
+  /// if the StaticVec is empty.
   ///
-  /// Locally requires that `T` implements [`Copy`](core::marker::Copy) to allow
-  /// for an efficient implementation, and [`Sub`](core::ops::Sub) to make it possible
-  /// to subtract the elements.
+  /// Locally requires that `T` implements [`Copy`](core::marker::Copy) and `Into<f64>`.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// assert_eq!(staticvec![1.0f32, 2.0, 3.0, 6.0].mean(), Some(3.0));
+  /// assert_eq!(StaticVec::<u8, 4>::new().mean(), None);
+  /// ```
+  #[inline]
+  pub fn mean(&self) -> Option<f64>
+  where T: Copy + Into<f64> {
+    match self.length {
+      0 => None,
+      length => Some(self.iter().fold(0.0, |acc, &x| acc + x.into()) / length as f64),
+    }
+  }
+
+  /// Returns the population variance of all elements of the StaticVec as an `f64` in `Some`, or
+  /// `None` if the StaticVec is empty. This is computed in a single pass with Welford's algorithm,
+  /// which is considerably more numerically stable than the naive "mean of squares minus square of
+  /// mean" approach.
   ///
-  /// For both performance and safety reasons, this function requires that both `self`
-  /// and `other` are at full capacity, and will panic if that is not the case (that is,
-  /// if `self.is_full() && other.is_full()` is not equal to `true`.)
+  /// Locally requires that `T` implements [`Copy`](core::marker::Copy) and `Into<f64>`.
   ///
   /// # Example usage:
   /// ```
-  /// # use staticvec::{staticvec, StaticVec};
-  /// const A: StaticVec<f64, 4> = staticvec![4.0, 5.0, 6.0, 7.0];
-  /// const B: StaticVec<f64, 4> = staticvec![2.0, 3.0, 4.0, 5.0];
-  /// assert_eq!(A.subtracted(&B), [2.0, 2.0, 2.0, 2.0]);
+  /// # use staticvec::*;
+  /// assert_eq!(staticvec![2, 4, 4, 4, 5, 5, 7, 9].variance(), Some(4.0));
+  /// assert_eq!(StaticVec::<f64, 4>::new().variance(), None);
   /// ```
-  #[inline(always)]
-  pub fn subtracted(&self, other: &Self) -> Self
-  where T: Copy + Sub<Output = T> {
-    assert!(
-      self.is_full() && other.is_full(),
-      "In `StaticVec::subtracted`, both `self` and `other` must be at maximum capacity!"
-    );
-    let mut res = Self::new();
-    for i in 0..N {
-      unsafe {
-        res
-          .mut_ptr_at_unchecked(i)
-          .write(*self.get_unchecked(i) - *other.get_unchecked(i));
-      }
+  #[inline]
+  pub fn variance(&self) -> Option<f64>
+  where T: Copy + Into<f64> {
+    if self.length == 0 {
+      return None;
     }
-    res.length = N;
-    res
+    let mut mean = 0.0;
+    let mut squared_distances = 0.0;
+    for (i, &item) in self.iter().enumerate() {
+      let x: f64 = item.into();
+      let delta = x - mean;
+      mean += delta / (i + 1) as f64;
+      squared_distances += delta * (x - mean);
+    }
+    Some(squared_distances / self.length as f64)
   }
 
-  /// Linearly multiplies (in a mathematical sense) the contents of two same-capacity
-  /// StaticVecs and returns the results in a new one of equal capacity.
+  /// Returns a constant reference to the minimum element of the StaticVec in `Some`, or `None` if
+  /// the StaticVec is empty. If several elements are equally minimum, the first one is returned.
   ///
-  /// Locally requires that `T` implements [`Copy`](core::marker::Copy) to allow
-  /// for an efficient implementation, and [`Mul`](core::ops::Mul) to make it possible
-  /// to multiply the elements.
+  /// This is not simply named `min`, as on a StaticVec that implements
+  /// [`Ord`](core::cmp::Ord) itself, method call syntax would resolve to the by-value
+  /// [`Ord::min`](core::cmp::Ord::min) instead.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// assert_eq!(staticvec![3, 1, 2].min_element(), Some(&1));
+  /// assert_eq!(StaticVec::<i32, 4>::new().min_element(), None);
+  /// ```
+  #[inline(always)]
+  pub fn min_element(&self) -> Option<&T>
+  where T: Ord {
+    self.iter().min()
+  }
+
+  /// Returns a constant reference to the maximum element of the StaticVec in `Some`, or `None` if
+  /// the StaticVec is empty. If several elements are equally maximum, the last one is returned.
   ///
-  /// For both performance and safety reasons, this function requires that both `self`
-  /// and `other` are at full capacity, and will panic if that is not the case (that is,
-  /// if `self.is_full() && other.is_full()` is not equal to `true`.)
+  /// This is not simply named `max`, as on a StaticVec that implements
+  /// [`Ord`](core::cmp::Ord) itself, method call syntax would resolve to the by-value
+  /// [`Ord::max`](core::cmp::Ord::max) instead.
   ///
   /// # Example usage:
   /// ```
-  /// # use staticvec::{staticvec, StaticVec};
-  /// const A: StaticVec<f64, 4> = staticvec![4.0, 5.0, 6.0, 7.0];
-  /// const B: StaticVec<f64, 4> = staticvec![2.0, 3.0, 4.0, 5.0];
-  /// assert_eq!(A.multiplied(&B), [8.0, 15.0, 24.0, 35.0]);
+  /// # use staticvec::*;
+  /// assert_eq!(staticvec![3, 1, 2].max_element(), Some(&3));
+  /// assert_eq!(StaticVec::<i32, 4>::new().max_element(), None);
   /// ```
   #[inline(always)]
-  pub fn multiplied(&self, other: &Self) -> Self
-  where T: Copy + Mul<Output = T> {
-    assert!(
-      self.is_full() && other.is_full(),
-      "In `StaticVec::multiplied`, both `self` and `other` must be at maximum capacity!"
-    );
-    let mut res = Self::new();
-    for i in 0..N {
-      unsafe {
-        res
-          .mut_ptr_at_unchecked(i)
-          .write(*self.get_unchecked(i) * *other.get_unchecked(i));
+  pub fn max_element(&self) -> Option<&T>
+  where T: Ord {
+    self.iter().max()
+  }
+
+  /// Concatenates the string representations of all elements of the StaticVec (as given by
+  /// their [`AsRef<str>`](core::convert::AsRef) implementations) into a new
+  /// [`StaticString`](crate::string::StaticString) with a capacity of `M`, with `separator`
+  /// placed between each one, or returns a [`CapacityError`](crate::errors::CapacityError) if the
+  /// result would not fit within `M` bytes.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let v = staticvec!["a", "b", "c"];
+  /// assert_eq!(v.try_join_into::<5>(",").unwrap(), "a,b,c");
+  /// assert!(v.try_join_into::<4>(",").is_err());
+  /// ```
+  #[inline]
+  pub fn try_join_into<const M: usize>(
+    &self,
+    separator: &str,
+  ) -> Result<StaticString<M>, CapacityError<M>>
+  where
+    T: AsRef<str>,
+  {
+    let mut res = StaticString::new();
+    for (i, item) in self.iter().enumerate() {
+      if i > 0 {
+        res.try_push_str(separator)?;
       }
+      res.try_push_str(item)?;
     }
-    res.length = N;
-    res
+    Ok(res)
   }
 
-  /// Linearly divides (in a mathematical sense) the contents of two same-capacity
-  /// StaticVecs and returns the results in a new one of equal capacity.
+  /// A version of [`try_join_into`](crate::StaticVec::try_join_into) that panics if the result
+  /// would not fit within `M` bytes, instead of returning an error.
   ///
-  /// Locally requires that `T` implements [`Copy`](core::marker::Copy) to allow
-  /// for an efficient implementation, and [`Div`](core::ops::Div) to make it possible
-  /// to divide the elements.
+  /// # Panics
   ///
-  /// For both performance and safety reasons, this function requires that both `self`
-  /// and `other` are at full capacity, and will panic if that is not the case (that is,
-  /// if `self.is_full() && other.is_full()` is not equal to `true`.)
+  /// Panics if the total length of the result in bytes exceeds `M`.
   ///
   /// # Example usage:
   /// ```
-  /// # use staticvec::{staticvec, StaticVec};
-  /// const A: StaticVec<f64, 4> = staticvec![4.0, 5.0, 6.0, 7.0];
-  /// const B: StaticVec<f64, 4> = staticvec![2.0, 3.0, 4.0, 5.0];
-  /// assert_eq!(A.divided(&B), [2.0, 1.6666666666666667, 1.5, 1.4]);
+  /// # use staticvec::*;
+  /// let s: StaticString<16> = staticvec!["usr", "local", "bin"].join_into("/");
+  /// assert_eq!(s, "usr/local/bin");
   /// ```
-  #[inline(always)]
-  pub fn divided(&self, other: &Self) -> Self
-  where T: Copy + Div<Output = T> {
-    assert!(
-      self.is_full() && other.is_full(),
-      "In `StaticVec::divided`, both `self` and `other` must be at maximum capacity!"
-    );
-    let mut res = Self::new();
-    for i in 0..N {
-      unsafe {
-        res
-          .mut_ptr_at_unchecked(i)
-          .write(*self.get_unchecked(i) / *other.get_unchecked(i));
-      }
+  #[inline]
+  pub fn join_into<const M: usize>(&self, separator: &str) -> StaticString<M>
+  where T: AsRef<str> {
+    match self.try_join_into(separator) {
+      Ok(res) => res,
+      Err(_) => panic!("Insufficient capacity in `StaticVec::join_into`!"),
     }
-    res.length = N;
-    res
   }
 
   /// An internal convenience function to get an *uninitialized* instance of
@@ -2665,6 +6088,95 @@ impl<T, const N: usize> StaticVec<T, N> {
     }
     res
   }
+
+  /// An internal convenience function to turn any `RangeBounds<usize>` into a concrete pair of
+  /// `start` and `end` indices, with an unbounded end mapping to the current length. Panics with
+  /// `msg` if converting an `Excluded` start or `Included` end would overflow, but otherwise leaves
+  /// it up to the caller to check the returned indices against whatever limits it cares about.
+  #[inline(always)]
+  fn decode_range(&self, range: impl RangeBounds<usize>, msg: &'static str) -> (usize, usize) {
+    let start = match range.start_bound() {
+      Included(&idx) => idx,
+      Excluded(&idx) => idx.checked_add(1).expect(msg),
+      Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+      Included(&idx) => idx.checked_add(1).expect(msg),
+      Excluded(&idx) => idx,
+      Unbounded => self.length,
+    };
+    (start, end)
+  }
+
+  /// An internal convenience function that walks the StaticVec from front to back, calling `keep`
+  /// with the index of each element, the number of elements kept so far, and a pointer to the
+  /// start of the StaticVec's data. Elements for which `keep` returns false are dropped, while the
+  /// rest are compacted into `0..kept` in their original order. When `keep` is called for index
+  /// `i`, the positions `0..kept` and `i..old_length` are guaranteed to be initialized, and nothing
+  /// else is. If `keep` or a destructor panics, the StaticVec is left empty and the remaining
+  /// elements are leaked rather than double-dropped.
+  #[inline(always)]
+  fn retain_compacting<F: FnMut(usize, usize, *mut T) -> bool>(&mut self, mut keep: F) {
+    let old_length = self.length;
+    // Temporarily set our length to 0 to avoid double drops and such if anything goes wrong in
+    // the loop.
+    self.length = 0;
+    let mut kept = 0;
+    unsafe {
+      let mp = self.as_mut_ptr();
+      for i in 0..old_length {
+        if keep(i, kept, mp) {
+          if i != kept {
+            mp.add(i).copy_to_nonoverlapping(mp.add(kept), 1);
+          }
+          kept += 1;
+        } else {
+          ptr::drop_in_place(mp.add(i));
+        }
+      }
+    }
+    self.length = kept;
+  }
+}
+
+impl<T, const C: usize, const N: usize> StaticVec<[T; C], N> {
+  /// Consumes a StaticVec of arrays, moving the elements of each array (in sequence) into a new
+  /// StaticVec with a capacity of `M`, and returns it. As this always produces exactly
+  /// `self.len() * C` elements, it acts as an alternative to returning a
+  /// `StaticVec<T, { N * C }>`, which is not possible to express generically in a useful way.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `self.len() * C` is greater than `M`.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let v = staticvec![[1, 2], [3, 4], [5, 6]];
+  /// let flat: StaticVec<i32, 6> = v.flatten_into();
+  /// assert_eq!(flat, [1, 2, 3, 4, 5, 6]);
+  /// ```
+  #[inline]
+  pub const fn flatten_into<const M: usize>(self) -> StaticVec<T, M> {
+    let res_length = self.length * C;
+    assert!(
+      res_length <= M,
+      "Insufficient capacity in `StaticVec::flatten_into`!"
+    );
+    let mut res = StaticVec::<T, M>::new_data_uninit();
+    unsafe {
+      // `[[T; C]; N]` has exactly the same layout as `[T; N * C]` would, so we can just copy
+      // everything over in one go.
+      (self.as_ptr() as *const T)
+        .copy_to_nonoverlapping(StaticVec::<T, M>::first_ptr_mut(&mut res), res_length);
+    }
+    // Everything in `self` has been moved out at this point, so it must not be dropped.
+    mem::forget(self);
+    StaticVec {
+      data: res,
+      length: res_length,
+    }
+  }
 }
 
 impl<const N: usize> StaticVec<u8, N> {
@@ -30,6 +30,43 @@ macro_rules! staticvec {
   };
 }
 
+/// Creates a new [`StaticVec`](crate::StaticVec) with an explicitly specified capacity from a
+/// [`vec!`](alloc::vec::Vec)-style list of expressions, returning it in `Ok` if all of the elements
+/// fit or a [`CapacityError`](crate::errors::CapacityError) otherwise. Unlike
+/// [`staticvec!`](crate::staticvec), this macro is intended for cases where the number of elements
+/// is not necessarily the same as (or even known to be less than) the desired capacity.
+///
+/// The expressions are evaluated and pushed in order, and evaluation stops at the first one that
+/// does not fit.
+///
+/// # Example usage:
+/// ```
+/// use staticvec::{try_staticvec, CapacityError, StaticVec};
+///
+/// let v = try_staticvec![cap: 4; 1, 2, 3];
+/// assert_eq!(v, Ok(StaticVec::<i32, 4>::from([1, 2, 3])));
+///
+/// let v2 = try_staticvec![cap: 2; 1, 2, 3];
+/// assert_eq!(v2, Err(CapacityError::<2> {}));
+/// ```
+#[macro_export]
+macro_rules! try_staticvec {
+  (cap: $n:expr; $($val:expr),* $(,)*) => {{
+    let mut res = $crate::StaticVec::<_, $n>::new();
+    let mut fits = true;
+    $(
+      if fits && res.try_push($val).is_err() {
+        fits = false;
+      }
+    )*
+    if fits {
+      Ok(res)
+    } else {
+      Err($crate::CapacityError::<$n> {})
+    }
+  };};
+}
+
 /// Creates a new [`StaticString`](crate::string::StaticString) from an `&str` literal. This macro can be used in const contexts,
 /// in keeping with the other ones in this crate.
 ///
@@ -116,6 +153,34 @@ macro_rules! staticstring {
   };};
 }
 
+/// Creates a new [`StaticString`](crate::string::StaticString) with a capacity of `N` from a
+/// [`format!`](alloc::format)-style format string and arguments, using the
+/// [`fmt::Write`](core::fmt::Write) implementation of [`StaticString`](crate::string::StaticString).
+/// Returns the result in `Ok` if the formatted output fits within the capacity, or
+/// [`fmt::Error`](core::fmt::Error) otherwise. This makes it usable for things like logging in
+/// `no_std` contexts where a heap-allocated `format!` is not available.
+///
+/// # Example usage:
+/// ```
+/// use staticvec::{format_staticstring, StaticString};
+///
+/// let s = format_staticstring!(16; "x = {}", 42);
+/// assert_eq!(s.unwrap(), "x = 42");
+///
+/// let s2 = format_staticstring!(4; "{}-{}", 1234, 5678);
+/// assert_eq!(s2, Err(core::fmt::Error));
+/// ```
+#[macro_export]
+macro_rules! format_staticstring {
+  ($n:expr; $($arg:tt)*) => {{
+    let mut res = $crate::StaticString::<$n>::new();
+    match ::core::fmt::Write::write_fmt(&mut res, ::core::format_args!($($arg)*)) {
+      Ok(()) => Ok(res),
+      Err(e) => Err(e),
+    }
+  };};
+}
+
 /// This is the same macro available in my actual `staticsort` crate, which I previously had as
 /// a dependency for this crate but decided to "inline" here as considering I wrote it myself it
 /// seems silly to have a mandatory dependency for no real reason.
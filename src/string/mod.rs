@@ -2,7 +2,7 @@ use core::char::{decode_utf16, REPLACEMENT_CHARACTER};
 use core::ops::{Bound, RangeBounds};
 use core::str::{
   self, from_utf8, from_utf8_unchecked,
-  pattern::{Pattern, Searcher},
+  pattern::{DoubleEndedSearcher, Pattern, ReverseSearcher, Searcher},
 };
 
 pub use self::string_errors::StringError;
@@ -319,6 +319,23 @@ impl<const N: usize> StaticString<N> {
     Ok(Self::try_from_str(from_utf8(slice.as_ref())?)?)
   }
 
+  /// Creates a new StaticString by taking ownership of an existing `StaticVec<u8, N>`, without
+  /// copying it, returning [`StringError::Utf8`] if its contents are not valid UTF-8. This is the
+  /// inverse of [`into_bytes`](crate::string::StaticString::into_bytes).
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::*;
+  /// let string = StaticString::try_from_staticvec(staticvec![104, 105]).unwrap();
+  /// assert_eq!(string, "hi");
+  /// assert!(StaticString::try_from_staticvec(staticvec![0, 159, 146, 150]).unwrap_err().is_utf8());
+  /// ```
+  #[inline(always)]
+  pub fn try_from_staticvec(vec: StaticVec<u8, N>) -> Result<Self, StringError> {
+    from_utf8(vec.as_slice())?;
+    Ok(Self { vec })
+  }
+
   /// Creates a new StaticString instance from the provided `u16` slice, replacing invalid UTF-16
   /// data with `REPLACEMENT_CHARACTER` (�), and truncating the input slice as necessary if
   /// it has a length greater than the declared capacity of the StaticString being created.
@@ -441,6 +458,108 @@ impl<const N: usize> StaticString<N> {
     self.vec.as_slice()
   }
 
+  /// Returns an iterator over the `char`s of the StaticString. Equivalent to
+  /// `self.as_str().chars()`.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::StaticString;
+  /// let s = StaticString::<20>::from("a¢€");
+  /// let mut c = s.chars();
+  /// assert_eq!(c.next(), Some('a'));
+  /// assert_eq!(c.next(), Some('¢'));
+  /// assert_eq!(c.next(), Some('€'));
+  /// assert_eq!(c.next(), None);
+  /// ```
+  #[inline(always)]
+  pub fn chars(&self) -> str::Chars {
+    self.as_str().chars()
+  }
+
+  /// Returns an iterator over the `char`s of the StaticString and their byte offsets.
+  /// Equivalent to `self.as_str().char_indices()`.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::StaticString;
+  /// let s = StaticString::<20>::from("a¢€b");
+  /// let v: Vec<(usize, char)> = s.char_indices().collect();
+  /// assert_eq!(v, [(0, 'a'), (1, '¢'), (3, '€'), (6, 'b')]);
+  /// ```
+  #[inline(always)]
+  pub fn char_indices(&self) -> str::CharIndices {
+    self.as_str().char_indices()
+  }
+
+  /// Returns an iterator over the bytes of the StaticString. Equivalent to
+  /// `self.as_str().bytes()`.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::StaticString;
+  /// let s = StaticString::<20>::from("a¢");
+  /// let v: Vec<u8> = s.bytes().collect();
+  /// assert_eq!(v, [b'a', 0xC2, 0xA2]);
+  /// ```
+  #[inline(always)]
+  pub fn bytes(&self) -> str::Bytes {
+    self.as_str().bytes()
+  }
+
+  /// Returns `true` if the StaticString starts with the given pattern, which may be a `char`, a
+  /// `&str`, a slice of `char`s, or a closure that determines whether a `char` matches. Equivalent
+  /// to `self.as_str().starts_with(pat)`, and so always returns `true` for an empty `&str` pattern.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::StaticString;
+  /// let s = StaticString::<20>::from("bananas");
+  /// assert!(s.starts_with("ban"));
+  /// assert!(s.starts_with('b'));
+  /// assert!(s.starts_with(char::is_alphabetic));
+  /// assert!(!s.starts_with("nan"));
+  /// ```
+  #[inline(always)]
+  pub fn starts_with<'a, P: Pattern<'a>>(&'a self, pat: P) -> bool {
+    self.as_str().starts_with(pat)
+  }
+
+  /// Returns `true` if the StaticString ends with the given pattern, which may be a `char`, a
+  /// `&str`, a slice of `char`s, or a closure that determines whether a `char` matches. Equivalent
+  /// to `self.as_str().ends_with(pat)`, and so always returns `true` for an empty `&str` pattern.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::StaticString;
+  /// let s = StaticString::<20>::from("bananas");
+  /// assert!(s.ends_with("nas"));
+  /// assert!(s.ends_with('s'));
+  /// assert!(!s.ends_with("nan"));
+  /// ```
+  #[inline(always)]
+  pub fn ends_with<'a, P: Pattern<'a>>(&'a self, pat: P) -> bool
+  where P::Searcher: ReverseSearcher<'a> {
+    self.as_str().ends_with(pat)
+  }
+
+  /// Returns `true` if the given pattern, which may be a `char`, a `&str`, a slice of `char`s, or a
+  /// closure that determines whether a `char` matches, matches anywhere within the StaticString.
+  /// Equivalent to `self.as_str().contains(pat)`, and so always returns `true` for an empty `&str`
+  /// pattern.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::StaticString;
+  /// let s = StaticString::<20>::from("bananas");
+  /// assert!(s.contains("nan"));
+  /// assert!(s.contains('s'));
+  /// assert!(!s.contains("apple"));
+  /// ```
+  #[inline(always)]
+  pub fn contains<'a, P: Pattern<'a>>(&'a self, pat: P) -> bool {
+    self.as_str().contains(pat)
+  }
+
   /// Returns the StaticString's internal instance of `StaticVec<u8, N>`.
   /// Note that using this function consumes the StaticString.
   ///
@@ -596,6 +715,106 @@ impl<const N: usize> StaticString<N> {
     unsafe { self.push_str_unchecked(truncate_str(string.as_ref(), self.remaining_capacity())) };
   }
 
+  /// Pushes as many whole characters from the start of `string` to the StaticString as will fit
+  /// within its remaining capacity, and returns the number of bytes that were actually written.
+  /// Unlike [`push_str`](crate::string::StaticString::push_str) this never panics, and unlike
+  /// [`push_str_truncating`](crate::string::StaticString::push_str_truncating) it tells you how
+  /// much of `string` was consumed. A multi-byte character is never split: if only part of one
+  /// would fit, none of it is written.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::StaticString;
+  /// let mut s = StaticString::<6>::from("ab");
+  /// // '€' is three bytes long, so only one of them fits.
+  /// assert_eq!(s.push_str_bounded("€€"), 3);
+  /// assert_eq!(s, "ab€");
+  /// assert_eq!(s.push_str_bounded("€"), 0);
+  /// assert_eq!(s.push_str_bounded("x€"), 1);
+  /// assert_eq!(s, "ab€x");
+  /// ```
+  #[inline(always)]
+  pub fn push_str_bounded<S: AsRef<str>>(&mut self, string: S) -> usize {
+    let truncated = truncate_str(string.as_ref(), self.remaining_capacity());
+    unsafe { self.push_str_unchecked(truncated) };
+    truncated.len()
+  }
+
+  /// Appends copies of `fill` to the end of the StaticString until it contains `width` characters
+  /// (as opposed to bytes), doing nothing if it already contains at least that many. If the
+  /// necessary padding would exceed the StaticString's remaining capacity, only as many copies of
+  /// `fill` as fit are appended.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::StaticString;
+  /// let mut s = StaticString::<8>::from("ab");
+  /// s.pad_right(5, '.');
+  /// assert_eq!(s, "ab...");
+  /// s.pad_right(12, '.');
+  /// assert_eq!(s, "ab......");
+  /// ```
+  #[inline]
+  pub fn pad_right(&mut self, width: usize, fill: char) {
+    let count = self.pad_count(width, fill);
+    for _ in 0..count {
+      // Safety: `pad_count` never returns more copies of `fill` than will fit.
+      unsafe { self.push_unchecked(fill) };
+    }
+  }
+
+  /// Prepends copies of `fill` to the start of the StaticString until it contains `width`
+  /// characters (as opposed to bytes), doing nothing if it already contains at least that many. If
+  /// the necessary padding would exceed the StaticString's remaining capacity, only as many copies
+  /// of `fill` as fit are prepended.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::StaticString;
+  /// let mut s = StaticString::<8>::from("€");
+  /// s.pad_left(3, '0');
+  /// assert_eq!(s, "00€");
+  /// // Only one more three-byte '€' fits.
+  /// s.pad_left(6, '€');
+  /// assert_eq!(s, "€00€");
+  /// ```
+  #[inline]
+  pub fn pad_left(&mut self, width: usize, fill: char) {
+    let count = self.pad_count(width, fill);
+    if count == 0 {
+      return;
+    }
+    let mut buf = [0; 4];
+    let fill_bytes = fill.encode_utf8(&mut buf).as_bytes();
+    let fill_length = fill_bytes.len();
+    let total_length = count * fill_length;
+    // Safety: `pad_count` never returns more copies of `fill` than will fit, and index 0 is always a
+    // valid character boundary.
+    unsafe {
+      shift_right_unchecked!(self, 0, total_length);
+      let mp = self.vec.as_mut_ptr();
+      for i in 0..count {
+        fill_bytes
+          .as_ptr()
+          .copy_to_nonoverlapping(mp.add(i * fill_length), fill_length);
+      }
+      self.vec.set_len(self.len() + total_length);
+    }
+  }
+
+  /// Returns the number of copies of `fill` that [`pad_left`](StaticString::pad_left) and
+  /// [`pad_right`](StaticString::pad_right) should add to reach `width` characters, bounded by the
+  /// StaticString's remaining capacity.
+  #[inline(always)]
+  fn pad_count(&self, width: usize, fill: char) -> usize {
+    let char_count = self.chars().count();
+    if char_count >= width {
+      0
+    } else {
+      (width - char_count).min(self.remaining_capacity() / fill.len_utf8())
+    }
+  }
+
   /// Pushes `string` to the StaticString if `self.len() + string.len()` does not exceed
   /// the StaticString's total capacity, or returns a
   /// [`CapacityError`](crate::errors::CapacityError) otherwise.
@@ -744,7 +963,8 @@ impl<const N: usize> StaticString<N> {
     })
   }
 
-  /// Removes all whitespace from the beginning and end of the StaticString, if any is present.
+  /// Removes all whitespace (as defined by [`char::is_whitespace`], and so including tabs and
+  /// newlines) from the beginning and end of the StaticString, if any is present.
   ///
   /// # Example usage:
   /// ```
@@ -756,36 +976,91 @@ impl<const N: usize> StaticString<N> {
   /// let mut string = StaticString::<20>::try_from_str("   🤔")?;
   /// string.trim();
   /// assert_eq!(string.as_str(), "🤔");
+  /// let mut string = StaticString::<20>::try_from_str("\t\n tabs and newlines\r\n")?;
+  /// string.trim();
+  /// assert_eq!(string.as_str(), "tabs and newlines");
   /// # Ok(())
   /// # }
   /// ```
   #[inline]
   pub fn trim(&mut self) {
-    let is_whitespace = |bytes: &[u8], index: usize| {
-      debug_assert!(index < bytes.len());
-      unsafe { bytes.get_unchecked(index) == &b' ' }
+    self.trim_end();
+    self.trim_start();
+  }
+
+  /// Removes all whitespace (as defined by [`char::is_whitespace`]) from the beginning of the
+  /// StaticString, if any is present.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::StaticString;
+  /// let mut string = StaticString::<20>::from("\t\n  text  ");
+  /// string.trim_start();
+  /// assert_eq!(string.as_str(), "text  ");
+  /// ```
+  #[inline]
+  pub fn trim_start(&mut self) {
+    let old_length = self.len();
+    let start = old_length - self.as_str().trim_start().len();
+    // Safety: `str::trim_start` always returns a subslice ending on our own end, and starting at a
+    // valid character boundary.
+    unsafe { self.retain_byte_range(start, old_length) };
+  }
+
+  /// Removes all whitespace (as defined by [`char::is_whitespace`]) from the end of the
+  /// StaticString, if any is present.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::StaticString;
+  /// let mut string = StaticString::<20>::from("  text \r\n\t");
+  /// string.trim_end();
+  /// assert_eq!(string.as_str(), "  text");
+  /// ```
+  #[inline]
+  pub fn trim_end(&mut self) {
+    let end = self.as_str().trim_end().len();
+    // Safety: `str::trim_end` always returns a subslice starting at our own start, and ending at a
+    // valid character boundary.
+    unsafe { self.vec.set_len(end) };
+  }
+
+  /// Repeatedly removes all matches of pattern `pat` from both the beginning and end of the
+  /// StaticString, using [`str::trim_matches`] to determine what remains.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::StaticString;
+  /// let mut string = StaticString::<20>::from("xxabcxdxx");
+  /// string.trim_matches('x');
+  /// assert_eq!(string.as_str(), "abcxd");
+  /// let mut string = StaticString::<20>::from("123foo1bar123");
+  /// string.trim_matches(char::is_numeric);
+  /// assert_eq!(string.as_str(), "foo1bar");
+  /// ```
+  #[inline]
+  pub fn trim_matches<P: for<'x> Pattern<'x>>(&mut self, pat: P)
+  where for<'x> <P as Pattern<'x>>::Searcher: DoubleEndedSearcher<'x> {
+    let (start, end) = {
+      let trimmed = self.as_str().trim_matches(pat);
+      let start = trimmed.as_ptr() as usize - self.as_ptr() as usize;
+      (start, start + trimmed.len())
     };
-    let (mut start, mut end, mut leave) = (0_usize, self.len(), 0_usize);
-    while start < end && leave < 2 {
-      leave = 0;
-      if is_whitespace(self.as_bytes(), start) {
-        start += 1;
-        if start >= end {
-          continue;
-        };
-      } else {
-        leave += 1;
-      }
-      if start < end && is_whitespace(self.as_bytes(), end - 1) {
-        end -= 1;
-      } else {
-        leave += 1;
-      }
-    }
-    unsafe {
+    // Safety: `str::trim_matches` always returns a subslice of our contents beginning and ending
+    // at valid character boundaries.
+    unsafe { self.retain_byte_range(start, end) };
+  }
+
+  /// Moves the bytes within `start..end` to the beginning of the StaticString, and sets its length
+  /// to `end - start`. Both `start` and `end` must lie at valid character boundaries, with `start
+  /// <= end <= self.len()`.
+  #[inline(always)]
+  unsafe fn retain_byte_range(&mut self, start: usize, end: usize) {
+    self.vec.set_len(end);
+    if start > 0 {
       shift_left_unchecked!(self, start, 0usize);
-      self.vec.set_len(end - start)
-    };
+    }
+    self.vec.set_len(end - start);
   }
 
   /// Removes the char at `index` from the StaticString if `index` is both less than `self.len()`
@@ -888,6 +1163,23 @@ impl<const N: usize> StaticString<N> {
     *self = Self::from_chars(self.as_str().chars().filter(|c| f(*c)));
   }
 
+  /// Reverses the order of the characters (as opposed to the bytes) in the StaticString, such
+  /// that it always remains valid UTF-8 even if it contains multi-byte characters.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::StaticString;
+  /// let mut s = StaticString::<20>::from("a😀b");
+  /// s.reverse();
+  /// assert_eq!(s, "b😀a");
+  /// ```
+  #[inline(always)]
+  pub fn reverse(&mut self) {
+    // Rebuilding from an iterator is done for the same reason as it is in `retain`. The total
+    // length can't change, so nothing is ever truncated here.
+    *self = Self::from_chars(self.as_str().chars().rev());
+  }
+
   /// Inserts `character` at `index`, shifting any values that exist in positions greater than
   /// `index` to the right.
   ///
@@ -1041,6 +1333,39 @@ impl<const N: usize> StaticString<N> {
     unsafe { self.insert_str_unchecked(index, string_ref) };
   }
 
+  /// Inserts as many whole characters from the start of `string` as will fit within the
+  /// StaticString's remaining capacity at `index`, shifting any values that exist in positions
+  /// greater than `index` to the right. As with
+  /// [`push_str_truncating`](crate::string::StaticString::push_str_truncating), a multi-byte
+  /// character is never split: if only part of one would fit, none of it is inserted.
+  ///
+  /// Panics if `index` is greater than the length of the StaticString or if it does not lie
+  /// at a valid UTF-8 character boundary.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::StaticString;
+  /// let mut s = StaticString::<8>::from("AD");
+  /// s.insert_str_truncating(1, "BC");
+  /// assert_eq!(s, "ABCD");
+  /// // '🤔' is four bytes long, so only one of them fits.
+  /// s.insert_str_truncating(2, "🤔🤔");
+  /// assert_eq!(s, "AB🤔CD");
+  /// ```
+  #[inline(always)]
+  pub fn insert_str_truncating<S: AsRef<str>>(&mut self, index: usize, string: S) {
+    assert!(
+      index <= self.len() && str_is_char_boundary(self.as_str(), index),
+      "Out of bounds or invalid character boundary!"
+    );
+    unsafe {
+      self.insert_str_unchecked(
+        index,
+        truncate_str(string.as_ref(), self.remaining_capacity()),
+      )
+    };
+  }
+
   /// Inserts `string` at `index`, shifting any values that exist in positions greater than
   /// `index` to the right.
   ///
@@ -1155,6 +1480,35 @@ impl<const N: usize> StaticString<N> {
     }
   }
 
+  /// A version of [`split_off`](crate::string::StaticString::split_off) that moves the split-off
+  /// portion into a StaticString with an explicitly specified capacity of `M`, as opposed to one
+  /// with the same capacity as `self`. This is useful when the tail is known to be small.
+  ///
+  /// Panics if `at` is greater than the length of the StaticString or if it does not
+  /// lie at a valid UTF-8 character boundary, as well as if `self.len() - at` exceeds `M`.
+  ///
+  /// # Example usage:
+  /// ```
+  /// # use staticvec::StaticString;
+  /// let mut s = StaticString::<32>::from("key=value");
+  /// let value: StaticString<5> = s.split_off_into(4);
+  /// assert_eq!(s, "key=");
+  /// assert_eq!(value, "value");
+  /// ```
+  #[inline]
+  pub fn split_off_into<const M: usize>(&mut self, at: usize) -> StaticString<M> {
+    assert!(
+      at <= self.len() && str_is_char_boundary(self.as_str(), at),
+      "Out of bounds or invalid character boundary!"
+    );
+    assert!(self.len() - at <= M, "Insufficient capacity!");
+    unsafe {
+      let res = StaticString::from_str_unchecked(self.as_str().get_unchecked(at..));
+      self.vec.set_len(at);
+      res
+    }
+  }
+
   /// Removes all contents from the StaticString and sets its length back to zero.
   ///
   /// # Example usage:
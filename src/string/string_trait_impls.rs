@@ -360,6 +360,27 @@ impl<const N: usize> PartialEq<String> for StaticString<N> {
   }
 }
 
+impl<const N: usize> PartialEq<[u8]> for StaticString<N> {
+  #[inline(always)]
+  fn eq(&self, other: &[u8]) -> bool {
+    self.as_bytes().eq(other)
+  }
+}
+
+impl<const N: usize> PartialEq<&[u8]> for StaticString<N> {
+  #[inline(always)]
+  fn eq(&self, other: &&[u8]) -> bool {
+    self.as_bytes().eq(*other)
+  }
+}
+
+impl<const N1: usize, const N2: usize> PartialEq<StaticVec<u8, N2>> for StaticString<N1> {
+  #[inline(always)]
+  fn eq(&self, other: &StaticVec<u8, N2>) -> bool {
+    self.as_bytes().eq(other.as_slice())
+  }
+}
+
 impl<const N: usize> PartialOrd for StaticString<N> {
   #[inline(always)]
   fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
@@ -391,11 +412,18 @@ impl<const N: usize> PartialOrd<String> for StaticString<N> {
 }
 
 impl<const N: usize> Write for StaticString<N> {
+  /// Appends `s` to the StaticString if it fits within the remaining capacity, or returns
+  /// [`fmt::Error`](core::fmt::Error) without writing anything otherwise. Individual writes are
+  /// all-or-nothing, so the StaticString is always left containing valid UTF-8. When used through
+  /// `write!`, this means that on overflow the StaticString contains everything written by the
+  /// formatting machinery up until (but not including) the first fragment that did not fit.
   #[inline(always)]
   fn write_str(&mut self, s: &str) -> fmt::Result {
     self.vec.write_str(s)
   }
 
+  /// Appends `c` to the StaticString if it fits within the remaining capacity, or returns
+  /// [`fmt::Error`](core::fmt::Error) without writing anything otherwise.
   #[inline(always)]
   fn write_char(&mut self, c: char) -> fmt::Result {
     self.vec.write_char(c)
@@ -208,7 +208,8 @@ impl<T, const N1: usize, const N2: usize> ExtendEx<T, StaticVec<T, N1>> for Stat
   default fn extend_ex(&mut self, iter: StaticVec<T, N1>) {
     // We just reuse most of the `extend_from_slice` code here.
     let old_length = self.length;
-    let added_length = iter.len().min(N2 - old_length);
+    let iter_length = iter.len();
+    let added_length = iter_length.min(N2 - old_length);
     // Safety: added_length is <= our remaining capacity and `iter.len()`.
     unsafe {
       iter
@@ -217,38 +218,43 @@ impl<T, const N1: usize, const N2: usize> ExtendEx<T, StaticVec<T, N1>> for Stat
       self.set_len(old_length + added_length);
       // Wrap the values in a MaybeUninit to inhibit their destructors (if any),
       // then manually drop any excess ones. This is the same kind of "trick" as
-      // is used in `new_from_array`, as you may or may not have noticed.
+      // is used in `new_from_array`, as you may or may not have noticed. Note that
+      // the excess elements are specifically the ones within `added_length..iter_length`,
+      // as neither the current length of `self` nor the capacity of `iter` have any
+      // bearing on how many of them were actually moved.
       let mut forgotten = MaybeUninit::new(iter);
       ptr::drop_in_place(
         forgotten
           .assume_init_mut()
           .as_mut_slice()
-          .get_unchecked_mut(N1.min(N2)..N1),
+          .get_unchecked_mut(added_length..iter_length),
       );
     }
   }
 
   #[inline]
   default fn from_iter_ex(iter: StaticVec<T, N1>) -> Self {
+    let iter_length = iter.len();
+    let added_length = iter_length.min(N2);
     Self {
       data: {
         unsafe {
           let mut data = Self::new_data_uninit();
           iter
             .as_ptr()
-            .copy_to_nonoverlapping(Self::first_ptr_mut(&mut data), N1.min(N2));
+            .copy_to_nonoverlapping(Self::first_ptr_mut(&mut data), added_length);
           // Same thing as above here.
           let mut forgotten = MaybeUninit::new(iter);
           ptr::drop_in_place(
             forgotten
               .assume_init_mut()
               .as_mut_slice()
-              .get_unchecked_mut(N1.min(N2)..N1),
+              .get_unchecked_mut(added_length..iter_length),
           );
           data
         }
       },
-      length: N1.min(N2),
+      length: added_length,
     }
   }
 }
@@ -482,6 +488,10 @@ impl<'a, T: 'a + Copy, const N: usize> FromIterator<&'a T> for StaticVec<T, N> {
 }
 
 impl<T: Hash, const N: usize> Hash for StaticVec<T, N> {
+  /// Hashes only the initialized elements of the StaticVec, by forwarding directly to the `Hash`
+  /// implementation of the slice returned by `as_slice`. Neither the capacity nor anything in the
+  /// uninitialized part of the StaticVec is hashed, and so a StaticVec will always hash identically
+  /// to a slice, array, or [`Vec`](alloc::vec::Vec) with the same contents.
   #[inline(always)]
   fn hash<H: Hasher>(&self, state: &mut H) {
     self.as_slice().hash(state);
@@ -756,6 +766,10 @@ impl<T, const N: usize> const IntoIterator for StaticVec<T, N> {
 }
 
 impl<T: Ord, const N: usize> Ord for StaticVec<T, N> {
+  /// Compares the inhabited areas of the two StaticVecs lexicographically, exactly as is done for
+  /// slices. Note that as `Ord` can only compare a type against itself, comparisons between
+  /// StaticVecs of differing capacities are instead provided by the more general `PartialOrd` impls
+  /// below, which have the same lexicographic semantics.
   #[inline(always)]
   fn cmp(&self, other: &Self) -> Ordering {
     Ord::cmp(self.as_slice(), other.as_slice())
@@ -1,6 +1,7 @@
 use core::cmp::{Ordering, PartialOrd};
 use core::intrinsics::{assume, const_eval_select};
-use core::mem::{size_of, MaybeUninit};
+use core::marker::PhantomData;
+use core::mem::{align_of, size_of, MaybeUninit};
 
 use crate::StaticVec;
 
@@ -108,6 +109,31 @@ where T: Copy {
   }
 }
 
+/// Used by [`StaticVec::into_empty_of`](crate::StaticVec::into_empty_of) to check at compile time
+/// that `T` and `U` have identical sizes and alignments. Referencing `ASSERT` from a function that
+/// is generic over `T` and `U` causes a compilation error for any instantiation where they don't.
+pub(crate) struct SameLayout<T, U>(PhantomData<(T, U)>);
+
+impl<T, U> SameLayout<T, U> {
+  pub(crate) const ASSERT: () = assert!(
+    size_of::<T>() == size_of::<U>() && align_of::<T>() == align_of::<U>(),
+    "`T` and `U` do not have the same size and alignment!"
+  );
+}
+
+/// Used by [`StaticVec::assert_capacity_at_least`](crate::StaticVec::assert_capacity_at_least)
+/// to check at compile time that `N` is at least `MIN`. This works the same way as
+/// [`SameLayout`], with the failing assertion being turned into a compilation error by the
+/// `const_panic` feature.
+pub(crate) struct MinCapacity<const N: usize, const MIN: usize>;
+
+impl<const N: usize, const MIN: usize> MinCapacity<N, MIN> {
+  pub(crate) const ASSERT: () = assert!(
+    N >= MIN,
+    "Statically asserted minimum capacity of a StaticVec is greater than `N`!"
+  );
+}
+
 #[inline(always)]
 pub(crate) const fn compiletime_zst_ptr_add<T>(ptr: *const T, count: usize) -> *const T {
   (ptr as *const u8).wrapping_add(count) as *const T
@@ -225,3 +251,110 @@ pub(crate) const fn quicksort_internal<T: Copy + ~const PartialOrd>(
     }
   }
 }
+
+/// A helper trait, implemented for all of the primitive integer types, that provides the checked
+/// and saturating operations used by methods such as
+/// [`StaticVec::added_saturating`](crate::StaticVec::added_saturating) and
+/// [`StaticVec::checked_added`](crate::StaticVec::checked_added), as well as by
+/// [`StaticVec::checked_sum`](crate::StaticVec::checked_sum). Each method simply forwards to the
+/// inherent method of the same name on the integer type itself.
+///
+/// This trait is sealed, and so cannot be implemented outside of this crate.
+pub trait IntegerArithmetic: Copy + sealed::Sealed {
+  /// The additive identity (that is, `0`) of the integer type.
+  const ZERO: Self;
+  /// Saturating integer addition.
+  fn saturating_add(self, rhs: Self) -> Self;
+  /// Saturating integer subtraction.
+  fn saturating_sub(self, rhs: Self) -> Self;
+  /// Saturating integer multiplication.
+  fn saturating_mul(self, rhs: Self) -> Self;
+  /// Checked integer addition, returning `None` on overflow.
+  fn checked_add(self, rhs: Self) -> Option<Self>;
+  /// Checked integer subtraction, returning `None` on overflow.
+  fn checked_sub(self, rhs: Self) -> Option<Self>;
+  /// Checked integer multiplication, returning `None` on overflow.
+  fn checked_mul(self, rhs: Self) -> Option<Self>;
+  /// Checked integer division, returning `None` on overflow or if `rhs` is zero.
+  fn checked_div(self, rhs: Self) -> Option<Self>;
+}
+
+mod sealed {
+  /// Prevents [`IntegerArithmetic`](super::IntegerArithmetic) from being implemented for anything
+  /// other than the primitive integer types.
+  pub trait Sealed {}
+}
+
+macro_rules! impl_integer_arithmetic {
+  ($($t:ty),*) => {
+    $(
+      impl sealed::Sealed for $t {}
+
+      impl IntegerArithmetic for $t {
+        const ZERO: Self = 0;
+
+        #[inline(always)]
+        fn saturating_add(self, rhs: Self) -> Self {
+          <$t>::saturating_add(self, rhs)
+        }
+
+        #[inline(always)]
+        fn saturating_sub(self, rhs: Self) -> Self {
+          <$t>::saturating_sub(self, rhs)
+        }
+
+        #[inline(always)]
+        fn saturating_mul(self, rhs: Self) -> Self {
+          <$t>::saturating_mul(self, rhs)
+        }
+
+        #[inline(always)]
+        fn checked_add(self, rhs: Self) -> Option<Self> {
+          <$t>::checked_add(self, rhs)
+        }
+
+        #[inline(always)]
+        fn checked_sub(self, rhs: Self) -> Option<Self> {
+          <$t>::checked_sub(self, rhs)
+        }
+
+        #[inline(always)]
+        fn checked_mul(self, rhs: Self) -> Option<Self> {
+          <$t>::checked_mul(self, rhs)
+        }
+
+        #[inline(always)]
+        fn checked_div(self, rhs: Self) -> Option<Self> {
+          <$t>::checked_div(self, rhs)
+        }
+      }
+    )*
+  };
+}
+
+impl_integer_arithmetic!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// A marker trait for "plain old data" types, that is, `Copy` types that have no padding bytes
+/// and for which every possible bit pattern of the appropriate size is a valid value. It is used
+/// by [`StaticVec::as_byte_slice`](crate::StaticVec::as_byte_slice) and
+/// [`StaticVec::try_from_byte_slice`](crate::StaticVec::try_from_byte_slice) to safely reinterpret
+/// the contents of a StaticVec as raw bytes and back. It is implemented for all of the primitive
+/// integer and floating point types, as well as arrays of any type that implements it.
+///
+/// # Safety
+///
+/// Implementing this trait for a type that contains padding, pointers, or any field with invalid
+/// bit patterns (such as `bool`, `char`, references, or most enums) is undefined behavior.
+pub unsafe trait Pod: Copy + 'static {}
+
+macro_rules! impl_pod {
+  ($($t:ty),*) => {
+    $(
+      unsafe impl Pod for $t {}
+    )*
+  };
+}
+
+impl_pod!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+
+unsafe impl<T: Pod, const N: usize> Pod for [T; N] {}
@@ -36,6 +36,32 @@ fn append_to_empty() {
   assert!(b.is_empty());
 }
 
+#[test]
+fn append_staticvec() {
+  let mut a = StaticHeap::<i32, 8>::from(staticvec![-10, 1, 2, 3, 3]);
+  let mut b = staticvec![-20, 5, 43];
+  a.append_staticvec(&mut b);
+  assert_eq!(a.peek(), Some(&43));
+  assert_eq!(a.into_sorted_staticvec(), [-20, -10, 1, 2, 3, 3, 5, 43]);
+  assert!(b.is_empty());
+  // Few items added to a large heap, which sifts each item up individually.
+  let mut c = MyStaticHeap::from_iter(0..60);
+  let mut d = staticvec![100, -1, 30, 200, 500, 600];
+  c.append_staticvec(&mut d);
+  assert_eq!(d, [500, 600]);
+  assert_eq!(c.len(), 64);
+  assert_eq!(c.pop(), Some(200));
+  assert_eq!(c.pop(), Some(100));
+  assert_eq!(c.pop(), Some(59));
+  assert_eq!(
+    c.into_sorted_staticvec(),
+    MyStaticVec::from_iter((0..59).chain([-1, 30])).sorted()
+  );
+  let mut e = StaticHeap::<i32, 4>::new();
+  e.append_staticvec(&mut StaticVec::<i32, 4>::new());
+  assert!(e.is_empty());
+}
+
 fn check_exact_size_iterator<I: ExactSizeIterator>(len: usize, it: I) {
   let mut it = it;
   for i in 0..it.len() {
@@ -154,6 +180,28 @@ fn exact_size_iterator() {
   check_exact_size_iterator(heap.len(), heap.clone().drain_sorted());
 }
 
+#[test]
+fn extend_from_slice() {
+  let mut a = MyStaticHeap::new();
+  a.extend_from_slice(&[5, 1, 8, 3, 9, 2]);
+  assert_eq!(a.peek(), Some(&9));
+  assert_eq!(a.len(), 6);
+  a.extend_from_slice(&[4]);
+  a.extend_from_slice(&[]);
+  assert_eq!(a.into_sorted_staticvec(), [1, 2, 3, 4, 5, 8, 9]);
+  let mut b = MyStaticHeap::from_iter(0..50);
+  b.extend_from_slice(&[75, -5, 25]);
+  assert_eq!(b.pop(), Some(75));
+  assert_eq!(b.pop(), Some(49));
+  assert_eq!(
+    b.into_sorted_staticvec(),
+    MyStaticVec::from_iter((0..49).chain([-5, 25])).sorted()
+  );
+  let mut c = StaticHeap::<i32, 3>::from([1]);
+  c.extend_from_slice(&[2, 3, 4, 5]);
+  assert_eq!(c.into_sorted_staticvec(), [1, 2, 3]);
+}
+
 #[test]
 fn extend_ref() {
   let mut a = MyStaticHeap::new();
@@ -191,6 +239,38 @@ fn from_iter() {
   }
 }
 
+#[test]
+fn from_sorted_ascending() {
+  // One spare slot, so that pushing afterwards works.
+  let vec = StaticVec::<i32, 7>::from([-3, 1, 1, 4, 9, 12]);
+  let mut heap = StaticHeap::from_sorted_ascending(vec);
+  assert_eq!(heap.len(), 6);
+  assert_eq!(heap.peek(), Some(&12));
+  heap.push(5);
+  let mut popped = StaticVec::<i32, 7>::new();
+  while let Some(x) = heap.pop() {
+    popped.push(x);
+  }
+  assert_eq!(popped, [12, 9, 5, 4, 1, 1, -3]);
+  let empty = StaticHeap::from_sorted_ascending(StaticVec::<i32, 4>::new());
+  assert!(empty.is_empty());
+}
+
+#[test]
+fn from_sorted_descending() {
+  // One spare slot, so that pushing afterwards works.
+  let vec = StaticVec::<i32, 7>::from([12, 9, 4, 1, 1, -3]);
+  let mut heap = StaticHeap::from_sorted_descending(vec);
+  assert_eq!(heap.len(), 6);
+  assert_eq!(heap.peek(), Some(&12));
+  heap.push(5);
+  let mut popped = StaticVec::<i32, 7>::new();
+  while let Some(x) = heap.pop() {
+    popped.push(x);
+  }
+  assert_eq!(popped, [12, 9, 5, 4, 1, 1, -3]);
+}
+
 #[test]
 fn is_empty() {
   let a = StaticHeap::<i32, 4>::new();
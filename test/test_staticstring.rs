@@ -1,6 +1,6 @@
 #![allow(clippy::all, unused_variables)]
 
-use staticvec::{staticstring, staticvec, StaticString, StaticVec};
+use staticvec::{format_staticstring, staticstring, staticvec, StaticString, StaticVec};
 
 type MyString = StaticString<255>;
 
@@ -24,6 +24,39 @@ fn add_assign() {
   assert_eq!(s.as_str(), "abcประเทศไทย中华Việt Nam");
 }
 
+#[test]
+fn bytes() {
+  let s = MyString::from("a€");
+  let mut b = s.bytes();
+  assert_eq!(b.len(), 4);
+  assert_eq!(b.next(), Some(b'a'));
+  assert_eq!(b.next(), Some(0xE2));
+  assert_eq!(b.next(), Some(0x82));
+  assert_eq!(b.next(), Some(0xAC));
+  assert_eq!(b.next(), None);
+}
+
+#[test]
+fn char_indices() {
+  let s = MyString::from("aประ¢b𤭢");
+  let mut c = s.char_indices();
+  assert_eq!(c.next(), Some((0, 'a')));
+  assert_eq!(c.next(), Some((1, 'ป')));
+  assert_eq!(c.next(), Some((4, 'ร')));
+  assert_eq!(c.next(), Some((7, '¢')));
+  assert_eq!(c.next(), Some((9, 'b')));
+  assert_eq!(c.next(), Some((10, '𤭢')));
+  assert_eq!(c.next(), None);
+  assert_eq!(MyString::new().char_indices().next(), None);
+}
+
+#[test]
+fn chars() {
+  let s = MyString::from("a¢€");
+  assert_eq!(s.chars().rev().collect::<String>(), "€¢a");
+  assert_eq!(s.chars().count(), 3);
+}
+
 #[test]
 fn clear() {
   let mut s = MyString::from("12345");
@@ -32,6 +65,47 @@ fn clear() {
   assert_eq!(s, "");
 }
 
+#[test]
+fn contains() {
+  let s = StaticString::<20>::from("héllo wörld");
+  assert!(s.contains("lo w"));
+  assert!(s.contains('ö'));
+  assert!(s.contains(char::is_whitespace));
+  assert!(s.contains(""));
+  assert!(!s.contains("hello"));
+  assert!(!s.contains('x'));
+  assert!(StaticString::<4>::new().contains(""));
+  assert!(!StaticString::<4>::new().contains('a'));
+}
+
+#[test]
+fn ends_with() {
+  let s = StaticString::<20>::from("héllo wörld");
+  assert!(s.ends_with("wörld"));
+  assert!(s.ends_with('d'));
+  assert!(s.ends_with(&['d', 'x'][..]));
+  assert!(s.ends_with(""));
+  assert!(!s.ends_with("héllo"));
+  assert!(!s.ends_with('l'));
+  assert!(StaticString::<4>::new().ends_with(""));
+}
+
+#[test]
+fn format_staticstring_macro() {
+  let x = 42;
+  let s = format_staticstring!(16; "x = {}", x);
+  assert_eq!(s.unwrap(), "x = 42");
+  let s2 = format_staticstring!(6; "x = {}", x);
+  assert_eq!(s2.unwrap(), "x = 42");
+  let s3 = format_staticstring!(5; "x = {}", x);
+  assert_eq!(s3, Err(core::fmt::Error));
+  let s4 = format_staticstring!(9; "{:>4}|{:<2}", "€", 'a');
+  assert_eq!(s4.unwrap(), "   €|a ");
+  let s5 = format_staticstring!(0; "");
+  assert_eq!(s5.unwrap(), "");
+  assert!(format_staticstring!(1; "{}", '€').is_err());
+}
+
 #[test]
 fn from_chars() {
   let s = StaticString::<20>::from_chars("My String".chars());
@@ -219,6 +293,54 @@ fn insert_str_invalid2() {
   assert_eq!(s.as_str(), "0".repeat(20).as_str());
 }
 
+#[test]
+fn insert_str_truncating() {
+  // Fully fits.
+  let mut s = StaticString::<13>::from("AD");
+  s.insert_str_truncating(1, "BC");
+  assert_eq!(s, "ABCD");
+  // Partially fits: there's room for 9 more bytes, but the two-byte 'ö' would start at the ninth,
+  // so only the first 8 are inserted.
+  s.insert_str_truncating(2, "héllo wörld");
+  assert_eq!(s, "ABhéllo wCD");
+  assert_eq!(s.len(), 12);
+  s.insert_str_truncating(0, "é");
+  assert_eq!(s, "ABhéllo wCD");
+  s.insert_str_truncating(0, "!");
+  assert_eq!(s, "!ABhéllo wCD");
+  assert!(s.is_full());
+  s.insert_str_truncating(s.len(), "more");
+  assert_eq!(s, "!ABhéllo wCD");
+  let mut s2 = StaticString::<4>::new();
+  s2.insert_str_truncating(0, "日本");
+  assert_eq!(s2, "日");
+}
+
+#[test]
+#[should_panic(expected = "Out of bounds or invalid character boundary!")]
+fn insert_str_truncating_invalid1() {
+  let mut s = StaticString::<20>::from("ệ");
+  s.insert_str_truncating(1, "t");
+}
+
+#[test]
+#[should_panic(expected = "Out of bounds or invalid character boundary!")]
+fn insert_str_truncating_invalid2() {
+  let mut s = StaticString::<20>::from("ABC");
+  s.insert_str_truncating(4, "t");
+}
+
+#[test]
+fn into_staticvec() {
+  let s = StaticString::<8>::from("héllo");
+  let v: StaticVec<u8, 8> = s.clone().into();
+  assert_eq!(v, [104, 195, 169, 108, 108, 111]);
+  assert_eq!(v.capacity(), 8);
+  assert_eq!(StaticVec::<u8, 8>::from(s), v);
+  let v2: StaticVec<u8, 4> = StaticString::<4>::new().into();
+  assert!(v2.is_empty());
+}
+
 #[test]
 fn macro_constructor() {
   let s1 = staticstring!("ABCDEFGHIJ");
@@ -264,6 +386,59 @@ fn macro_constructor_invalid() {
   let s: StaticString<0> = staticstring!("AAAAAA", 0);
 }
 
+#[test]
+fn pad_left() {
+  let mut s = StaticString::<10>::from("abc");
+  s.pad_left(6, ' ');
+  assert_eq!(s, "   abc");
+  s.pad_left(4, ' ');
+  assert_eq!(s, "   abc");
+  let mut s2 = StaticString::<16>::from("héé");
+  s2.pad_left(5, 'ü');
+  assert_eq!(s2, "üühéé");
+  assert_eq!(s2.len(), 9);
+  let mut s3 = StaticString::<5>::from("ab");
+  s3.pad_left(10, '-');
+  assert_eq!(s3, "---ab");
+  let mut s4 = StaticString::<6>::from("ab");
+  s4.pad_left(10, '€');
+  assert_eq!(s4, "€ab");
+}
+
+#[test]
+fn pad_right() {
+  let mut s = StaticString::<10>::from("abc");
+  s.pad_right(6, '.');
+  assert_eq!(s, "abc...");
+  s.pad_right(2, '.');
+  assert_eq!(s, "abc...");
+  let mut s2 = StaticString::<16>::from("🤔");
+  s2.pad_right(3, 'é');
+  assert_eq!(s2, "🤔éé");
+  assert_eq!(s2.chars().count(), 3);
+  let mut s3 = StaticString::<5>::from("ab");
+  s3.pad_right(10, '-');
+  assert_eq!(s3, "ab---");
+  let mut s4 = StaticString::<7>::from("ab");
+  s4.pad_right(10, '€');
+  assert_eq!(s4, "ab€");
+}
+
+#[test]
+fn partial_eq_bytes() {
+  let s = MyString::from("ABC");
+  assert!(s == *b"ABC".as_slice());
+  assert!(s != *b"ABD".as_slice());
+  assert!(s == b"ABC".as_slice());
+  assert!(s != b"AB".as_slice());
+  assert!(s == staticvec![b'A', b'B', b'C']);
+  assert!(s != staticvec![b'A', b'B', b'C', b'D']);
+  assert!(s == StaticVec::<u8, 3>::from(b"ABC"));
+  assert!(MyString::new() == StaticVec::<u8, 0>::new());
+  let s2 = StaticString::<8>::from("ประเทศ");
+  assert!(s2 == "ประเทศ".as_bytes());
+}
+
 #[test]
 fn pop() {
   let mut data = MyString::from("ประเทศไทย中华b¢€𤭢");
@@ -305,6 +480,25 @@ fn push_str() {
   assert_eq!(&s[0..], "abcประเทศไทย中华Việt Nam");
 }
 
+#[test]
+fn push_str_bounded() {
+  let mut s = StaticString::<10>::from("ab");
+  assert_eq!(s.push_str_bounded(""), 0);
+  // "ประเทศ" is 18 bytes long, with each character being 3 bytes, so only 2 of them fit.
+  assert_eq!(s.push_str_bounded("ประเทศ"), 6);
+  assert_eq!(s, "abปร");
+  assert_eq!(s.len(), 8);
+  // Only '¢' fits, after which there is no room for any part of '€'.
+  assert_eq!(s.push_str_bounded("¢€"), 2);
+  assert_eq!(s, "abปร¢");
+  assert!(s.is_full());
+  assert_eq!(s.push_str_bounded("a"), 0);
+  let mut s2 = StaticString::<4>::new();
+  assert_eq!(s2.push_str_bounded(String::from("abc")), 3);
+  assert_eq!(s2.push_str_bounded("𤭢"), 0);
+  assert_eq!(s2, "abc");
+}
+
 #[test]
 fn push_str_unchecked() {
   let mut s = MyString::new();
@@ -424,6 +618,26 @@ fn replace_range_unbounded() {
   assert_eq!(s, "");
 }
 
+#[test]
+fn reverse() {
+  let mut s = MyString::from("ABCDE");
+  s.reverse();
+  assert_eq!(s, "EDCBA");
+  let mut s2 = StaticString::<6>::from("a😀b");
+  s2.reverse();
+  assert_eq!(s2, "b😀a");
+  assert_eq!(s2.len(), 6);
+  assert!(core::str::from_utf8(s2.as_bytes()).is_ok());
+  let mut s3 = MyString::from("ประเทศไทย中华Việt Nam");
+  s3.reverse();
+  assert_eq!(s3, "maN tệiV华中ยทไศทเะรป");
+  s3.reverse();
+  assert_eq!(s3, "ประเทศไทย中华Việt Nam");
+  let mut s4 = MyString::new();
+  s4.reverse();
+  assert_eq!(s4, "");
+}
+
 #[test]
 fn retain() {
   let mut s = MyString::from("α_β_γ");
@@ -464,6 +678,38 @@ fn split_off_empty() {
   assert!(empty.is_empty());
 }
 
+#[test]
+fn split_off_into() {
+  let mut s = StaticString::<32>::from("日本語 text");
+  let tail: StaticString<5> = s.split_off_into("日本語 ".len());
+  assert_eq!(s, "日本語 ");
+  assert_eq!(tail, "text");
+  assert_eq!(tail.capacity(), 5);
+  let go: StaticString<4> = s.split_off_into("日本".len());
+  assert_eq!(s, "日本");
+  assert_eq!(go, "語 ");
+  let empty: StaticString<0> = s.split_off_into(s.len());
+  assert!(empty.is_empty());
+  assert_eq!(s, "日本");
+  let all: StaticString<6> = s.split_off_into(0);
+  assert!(s.is_empty());
+  assert_eq!(all, "日本");
+}
+
+#[test]
+#[should_panic(expected = "Insufficient capacity!")]
+fn split_off_into_capacity() {
+  let mut s = StaticString::<16>::from("Hello, world!");
+  let _tail: StaticString<4> = s.split_off_into(7);
+}
+
+#[test]
+#[should_panic(expected = "Out of bounds or invalid character boundary!")]
+fn split_off_into_mid_char() {
+  let mut s = StaticString::<16>::from("山");
+  let _tail: StaticString<16> = s.split_off_into(1);
+}
+
 #[test]
 #[should_panic]
 fn split_off_mid_char() {
@@ -487,6 +733,72 @@ fn split_off_unicode() {
   assert_eq!(go, "語");
 }
 
+#[test]
+fn starts_with() {
+  let s = StaticString::<20>::from("héllo wörld");
+  assert!(s.starts_with("hé"));
+  assert!(s.starts_with('h'));
+  assert!(s.starts_with(|c: char| c.is_ascii_lowercase()));
+  assert!(s.starts_with(""));
+  assert!(!s.starts_with("wörld"));
+  assert!(!s.starts_with('é'));
+  assert!(StaticString::<4>::new().starts_with(""));
+  assert!(!StaticString::<4>::new().starts_with('a'));
+}
+
+#[test]
+fn trim() {
+  let mut s = StaticString::<32>::from(" \t\n  trim me\t \r\n");
+  s.trim();
+  assert_eq!(s, "trim me");
+  let mut s2 = StaticString::<32>::from("\u{3000}wide\u{2003}");
+  s2.trim();
+  assert_eq!(s2, "wide");
+  let mut s3 = StaticString::<8>::from(" \t\n ");
+  s3.trim();
+  assert_eq!(s3, "");
+  let mut s4 = StaticString::<8>::from("x");
+  s4.trim();
+  assert_eq!(s4, "x");
+}
+
+#[test]
+fn trim_end() {
+  let mut s = StaticString::<32>::from("\t keep \n\t\r\n");
+  s.trim_end();
+  assert_eq!(s, "\t keep");
+  assert_eq!(s.len(), 6);
+}
+
+#[test]
+fn trim_matches() {
+  let mut s = StaticString::<32>::from("--a-b--");
+  s.trim_matches('-');
+  assert_eq!(s, "a-b");
+  let mut s2 = StaticString::<32>::from("123foo1bar123");
+  s2.trim_matches(char::is_numeric);
+  assert_eq!(s2, "foo1bar");
+  let mut s3 = StaticString::<32>::from("🤔🤔x🤔");
+  s3.trim_matches('🤔');
+  assert_eq!(s3, "x");
+  let mut s4 = StaticString::<32>::from("aaa");
+  s4.trim_matches('a');
+  assert_eq!(s4, "");
+  let mut s5 = StaticString::<32>::from("xyxy");
+  s5.trim_matches(&['x', 'y'][..]);
+  assert_eq!(s5, "");
+}
+
+#[test]
+fn trim_start() {
+  let mut s = StaticString::<32>::from("\n\t\r  keep \t");
+  s.trim_start();
+  assert_eq!(s, "keep \t");
+  let mut s2 = StaticString::<32>::from("no leading");
+  s2.trim_start();
+  assert_eq!(s2, "no leading");
+}
+
 #[test]
 fn truncate() {
   let mut s = MyString::from("12345");
@@ -532,3 +844,57 @@ fn try_insert_str() {
   assert_eq!(s, "ABCABBCD🤔");
   assert!(s.try_insert_str(0, "0".repeat(30)).is_err());
 }
+
+#[test]
+fn try_from_staticvec() {
+  let v = StaticVec::<u8, 8>::from("héllo".as_bytes());
+  let s = StaticString::try_from_staticvec(v).unwrap();
+  assert_eq!(s, "héllo");
+  assert_eq!(s.capacity(), 8);
+  assert_eq!(StaticString::try_from_staticvec(s.into_bytes()).unwrap(), "héllo");
+  assert!(StaticString::try_from_staticvec(StaticVec::<u8, 4>::new()).unwrap().is_empty());
+  let invalid = staticvec![0, 159, 146, 150];
+  assert!(StaticString::try_from_staticvec(invalid).unwrap_err().is_utf8());
+  let truncated = StaticVec::<u8, 8>::from(&"héllo".as_bytes()[..2]);
+  assert!(StaticString::try_from_staticvec(truncated).unwrap_err().is_utf8());
+}
+
+mod fmt_write_tests {
+  use core::fmt::Write;
+  use staticvec::StaticString;
+
+  #[test]
+  fn write() {
+    let mut s = StaticString::<16>::new();
+    write!(s, "{}-{}", 12, "ab").unwrap();
+    assert_eq!(s, "12-ab");
+    writeln!(s, "{:>4}", 'x').unwrap();
+    assert_eq!(s, "12-ab   x\n");
+  }
+
+  #[test]
+  fn write_overflow() {
+    let mut s = StaticString::<6>::new();
+    // "abc" and "-" fit, but "defg" does not, so it is not written at all.
+    assert!(write!(s, "{}-{}", "abc", "defg").is_err());
+    assert_eq!(s, "abc-");
+    assert!(s.write_str("xyz").is_err());
+    assert_eq!(s, "abc-");
+    s.write_str("xy").unwrap();
+    assert_eq!(s, "abc-xy");
+    assert!(s.is_full());
+  }
+
+  #[test]
+  fn write_char() {
+    let mut s = StaticString::<4>::new();
+    s.write_char('a').unwrap();
+    s.write_char('¢').unwrap();
+    assert_eq!(s, "a¢");
+    // '€' is three bytes long, and so doesn't fit.
+    assert!(s.write_char('€').is_err());
+    assert_eq!(s, "a¢");
+    s.write_char('b').unwrap();
+    assert_eq!(s, "a¢b");
+  }
+}
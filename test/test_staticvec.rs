@@ -15,7 +15,10 @@
 // mean, "weird stuff done with heap memory" is significantly more likely to set Miri off than
 // "weird stuff done with stack memory".
 
-use staticvec::{sortedstaticvec, staticvec, CapacityError, StaticVec};
+use staticvec::{
+  sortedstaticvec, staticvec, try_staticvec, CapacityError, StaticString, StaticVec,
+  StaticVecIntoIter,
+};
 
 use core::cell;
 
@@ -103,7 +106,7 @@ impl Drop for Struct {
   }
 }
 
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd)]
 struct ZST {}
 
 impl Drop for ZST {
@@ -117,6 +120,32 @@ impl Drop for ZST {
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 struct CloneableZST;
 
+#[test]
+fn align_to() {
+  let bytes: StaticVec<u8, 16> = (1..=13).collect();
+  let (head, mid, tail) = unsafe { bytes.align_to::<u32>() };
+  // The exact split depends on where the backing array happens to land in memory (and `align_to`
+  // is allowed to leave the middle empty regardless), but the three parts must always cover the
+  // whole inhabited area in order.
+  assert_eq!(head.len() + mid.len() * 4 + tail.len(), 13);
+  assert_eq!(head, &bytes[..head.len()]);
+  assert_eq!(tail, &bytes[13 - tail.len()..]);
+  for (i, &word) in mid.iter().enumerate() {
+    let start = head.len() + i * 4;
+    let expected = u32::from_ne_bytes([
+      bytes[start],
+      bytes[start + 1],
+      bytes[start + 2],
+      bytes[start + 3],
+    ]);
+    assert_eq!(word, expected);
+  }
+  assert!(mid.len() <= 3);
+  let empty = StaticVec::<u8, 16>::new();
+  let (head, mid, tail) = unsafe { empty.align_to::<u32>() };
+  assert!(head.is_empty() && mid.is_empty() && tail.is_empty());
+}
+
 #[test]
 fn append() {
   let mut a = staticvec![
@@ -164,6 +193,71 @@ fn append() {
   assert_eq!(i, [Box::new(Struct { s: "B" })]);
 }
 
+#[test]
+fn apply() {
+  let mut v = staticvec![Box::new(1), Box::new(2), Box::new(3)];
+  v.apply(|x| **x *= 2);
+  assert_eq!(v, [Box::new(2), Box::new(4), Box::new(6)]);
+  let mut v2 = StaticVec::<Box<i32>, 4>::new();
+  v2.apply(|_| panic!());
+}
+
+#[test]
+fn apply_indexed() {
+  let mut v = staticvec![Box::new(9), Box::new(9), Box::new(9)];
+  v.apply_indexed(|i, x| *x = Box::new(i as i32));
+  assert_eq!(v, [Box::new(0), Box::new(1), Box::new(2)]);
+  let mut indices = StaticVec::<usize, 3>::new();
+  v.apply_indexed(|i, _| indices.push(i));
+  assert_eq!(indices, [0, 1, 2]);
+}
+
+#[test]
+fn as_array_ref() {
+  let v1 = staticvec![Box::new(1), Box::new(2), Box::new(3)];
+  assert_eq!(v1.as_array_ref(), Some(&[Box::new(1), Box::new(2), Box::new(3)]));
+  let mut v2 = StaticVec::<Box<i32>, 4>::from([Box::new(1), Box::new(2), Box::new(3)]);
+  assert_eq!(v2.as_array_ref(), None);
+  v2.push(Box::new(4));
+  assert_eq!(
+    v2.as_array_ref(),
+    Some(&[Box::new(1), Box::new(2), Box::new(3), Box::new(4)])
+  );
+  let v3 = StaticVec::<Box<i32>, 0>::new();
+  assert_eq!(v3.as_array_ref(), Some(&[]));
+}
+
+#[test]
+fn as_array_mut() {
+  let mut v1 = staticvec![Box::new(1), Box::new(2), Box::new(3)];
+  let a = v1.as_array_mut().unwrap();
+  a[0] = Box::new(4);
+  a.swap(1, 2);
+  assert_eq!(v1, [Box::new(4), Box::new(3), Box::new(2)]);
+  v1.pop();
+  assert_eq!(v1.as_array_mut(), None);
+}
+
+#[test]
+fn as_byte_slice() {
+  let v = staticvec![0x01020304u32, 0x05060708];
+  let bytes = v.as_byte_slice();
+  assert_eq!(bytes.len(), 8);
+  assert_eq!(bytes[0..4], 0x01020304u32.to_ne_bytes());
+  assert_eq!(bytes[4..8], 0x05060708u32.to_ne_bytes());
+  #[cfg(target_endian = "little")]
+  assert_eq!(bytes, [4, 3, 2, 1, 8, 7, 6, 5]);
+  #[cfg(target_endian = "big")]
+  assert_eq!(bytes, [1, 2, 3, 4, 5, 6, 7, 8]);
+  // Only the inhabited area should be included.
+  let mut v2 = StaticVec::<u16, 8>::new();
+  assert_eq!(v2.as_byte_slice(), []);
+  v2.push(7);
+  assert_eq!(v2.as_byte_slice(), 7u16.to_ne_bytes());
+  let v3 = staticvec![[1u8, 2], [3, 4]];
+  assert_eq!(v3.as_byte_slice(), [1, 2, 3, 4]);
+}
+
 #[test]
 fn as_mut_ptr() {
   let mut v = staticvec![1, 2, 3];
@@ -188,6 +282,37 @@ fn as_slice() {
   assert_eq!(buffer.as_slice(), &[1, 2, 3, 5, 8]);
 }
 
+#[test]
+fn assert_capacity_at_least() {
+  StaticVec::<i32, 4>::assert_capacity_at_least::<0>();
+  StaticVec::<i32, 4>::assert_capacity_at_least::<4>();
+  StaticVec::<i32, 0>::assert_capacity_at_least::<0>();
+  const _: () = StaticVec::<u8, 16>::assert_capacity_at_least::<8>();
+}
+
+#[test]
+fn binary_search_in() {
+  let v = staticvec![5, 6, 7, 1, 3, 5, 7, 9, 2, 4];
+  assert_eq!(v.binary_search_in(3..8, &1), Ok(3));
+  assert_eq!(v.binary_search_in(3..8, &7), Ok(6));
+  assert_eq!(v.binary_search_in(3..8, &9), Ok(7));
+  assert_eq!(v.binary_search_in(3..=7, &9), Ok(7));
+  assert_eq!(v.binary_search_in(3..8, &0), Err(3));
+  assert_eq!(v.binary_search_in(3..8, &4), Err(5));
+  assert_eq!(v.binary_search_in(3..8, &10), Err(8));
+  assert_eq!(v.binary_search_in(..3, &6), Ok(1));
+  assert_eq!(v.binary_search_in(8.., &3), Err(9));
+  assert_eq!(v.binary_search_in(4..4, &3), Err(4));
+  assert_eq!(StaticVec::<i32, 4>::new().binary_search_in(.., &1), Err(0));
+}
+
+#[test]
+#[should_panic(expected = "Bounds check failure in `StaticVec::binary_search_in`!")]
+fn binary_search_in_panic() {
+  let v = staticvec![1, 2, 3];
+  let _ = v.binary_search_in(1..4, &2);
+}
+
 #[cfg(feature = "std")]
 #[test]
 fn bounds_to_string() {
@@ -233,6 +358,79 @@ fn capacity() {
   assert_eq!(vec.capacity(), 10);
 }
 
+#[test]
+fn checked_arithmetic() {
+  let a = staticvec![i32::MAX, 1, 2, 3];
+  let b = staticvec![0, 1, 2, 3];
+  assert_eq!(a.checked_added(&b), Some(staticvec![i32::MAX, 2, 4, 6]));
+  assert_eq!(a.checked_added(&staticvec![0, 1, 1, 1]), Some(staticvec![i32::MAX, 2, 3, 4]));
+  assert_eq!(a.checked_added(&staticvec![1, 1, 1, 1]), None);
+  assert_eq!(b.checked_added(&staticvec![1, 1, 1, i32::MAX]), None);
+  assert_eq!(
+    staticvec![i32::MIN, 1, 2, 3].checked_subtracted(&b),
+    Some(staticvec![i32::MIN, 0, 0, 0])
+  );
+  assert_eq!(staticvec![i32::MIN, 1, 2, 3].checked_subtracted(&a), None);
+  assert_eq!(a.checked_multiplied(&staticvec![1, 2, 3]), Some(staticvec![i32::MAX, 2, 6]));
+  assert_eq!(a.checked_multiplied(&staticvec![2, 2, 3]), None);
+  assert_eq!(a.checked_divided(&staticvec![1, 1, 2, 3]), Some(staticvec![i32::MAX, 1, 1, 1]));
+  assert_eq!(a.checked_divided(&b), None);
+  assert_eq!(staticvec![i32::MIN].checked_divided(&staticvec![-1]), None);
+  assert_eq!(a.checked_added(&StaticVec::new()), Some(StaticVec::new()));
+}
+
+#[test]
+fn checked_insert_many() {
+  let mut v = StaticVec::<Box<i32>, 6>::from([Box::new(1), Box::new(4)]);
+  assert!(v.checked_insert_many(1, staticvec![Box::new(2), Box::new(3)]).is_ok());
+  assert_eq!(v, [Box::new(1), Box::new(2), Box::new(3), Box::new(4)]);
+  assert!(v.checked_insert_many(4, staticvec![Box::new(5)]).is_ok());
+  assert!(v.checked_insert_many(0, staticvec![Box::new(0)]).is_ok());
+  assert_eq!(v, [Box::new(0), Box::new(1), Box::new(2), Box::new(3), Box::new(4), Box::new(5)]);
+  assert!(v.checked_insert_many(3, StaticVec::<Box<i32>, 2>::new()).is_ok());
+  assert_eq!(v.len(), 6);
+  // Out of bounds index.
+  let mut v2 = staticvec![1, 2, 3, 4, 5];
+  v2.pop();
+  assert_eq!(v2.checked_insert_many(5, staticvec![6]), Err(CapacityError::<5> {}));
+  assert_eq!(v2, [1, 2, 3, 4]);
+  // Insufficient capacity, with the StaticVec left unchanged.
+  assert_eq!(v2.checked_insert_many(1, staticvec![6, 7]), Err(CapacityError::<5> {}));
+  assert_eq!(v2, [1, 2, 3, 4]);
+  assert!(v2.checked_insert_many(1, staticvec![6]).is_ok());
+  assert_eq!(v2, [1, 6, 2, 3, 4]);
+}
+
+#[test]
+fn checked_sum() {
+  assert_eq!(staticvec![1u8, 2, 3, 4].checked_sum(), Some(10));
+  assert_eq!(staticvec![200u8, 55].checked_sum(), Some(255));
+  assert_eq!(staticvec![200u8, 50, 10].checked_sum(), None);
+  // An overflow partway through is still caught even if a later value would bring the total back
+  // into range.
+  assert_eq!(staticvec![i8::MAX, 1, -2].checked_sum(), None);
+  assert_eq!(staticvec![i8::MAX, -2, 1].checked_sum(), Some(126));
+  assert_eq!(staticvec![u64::MAX].checked_sum(), Some(u64::MAX));
+  assert_eq!(StaticVec::<i32, 4>::new().checked_sum(), Some(0));
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn choose() {
+  use rand::{rngs::StdRng, SeedableRng};
+  let mut rng = StdRng::seed_from_u64(1234);
+  let v = staticvec![Box::new(1), Box::new(2), Box::new(3)];
+  for _ in 0..16 {
+    assert!(v.contains(v.choose(&mut rng).unwrap()));
+  }
+  assert_eq!(
+    v.choose(&mut StdRng::seed_from_u64(99)),
+    v.choose(&mut StdRng::seed_from_u64(99))
+  );
+  assert_eq!(staticvec![Box::new(7)].choose(&mut rng), Some(&Box::new(7)));
+  assert_eq!(StaticVec::<Box<i32>, 4>::new().choose(&mut rng), None);
+}
+
 #[test]
 fn clear() {
   let mut v = staticvec![1, 2, 3];
@@ -240,6 +438,72 @@ fn clear() {
   assert!(v.is_empty());
 }
 
+#[test]
+fn clear_from() {
+  let mut v = staticvec![Box::new(1), Box::new(2), Box::new(3), Box::new(4)];
+  v.clear_from(4);
+  assert_eq!(v, [Box::new(1), Box::new(2), Box::new(3), Box::new(4)]);
+  v.clear_from(2);
+  assert_eq!(v, [Box::new(1), Box::new(2)]);
+  v.clear_from(0);
+  assert_eq!(v, []);
+  let counter = LifespanCounter::default();
+  let mut v2 = StaticVec::<LifespanCountingInstance, 5>::filled_with(|| counter.instance());
+  v2.clear_from(3);
+  assert_eq!(v2.len(), 3);
+  assert_eq!(counter.init_count(), 5);
+  assert_eq!(counter.drop_count(), 2);
+  drop(v2);
+  assert_eq!(counter.drop_count(), 5);
+}
+
+#[test]
+#[should_panic]
+fn clear_from_panic() {
+  let mut v = staticvec![Box::new(1), Box::new(2)];
+  v.clear_from(3);
+}
+
+#[test]
+fn clear_range() {
+  let mut v = staticvec![
+    Box::new(1),
+    Box::new(2),
+    Box::new(3),
+    Box::new(4),
+    Box::new(5),
+    Box::new(6)
+  ];
+  v.clear_range(1..3);
+  assert_eq!(v, [Box::new(1), Box::new(4), Box::new(5), Box::new(6)]);
+  v.clear_range(2..2);
+  assert_eq!(v, [Box::new(1), Box::new(4), Box::new(5), Box::new(6)]);
+  v.clear_range(2..);
+  assert_eq!(v, [Box::new(1), Box::new(4)]);
+  v.clear_range(..=0);
+  assert_eq!(v, [Box::new(4)]);
+  v.clear_range(..);
+  assert_eq!(v, []);
+  let counter = LifespanCounter::default();
+  let mut v2 = StaticVec::<LifespanCountingInstance, 6>::filled_with(|| counter.instance());
+  v2.clear_range(1..4);
+  assert_eq!(v2.len(), 3);
+  assert_eq!(counter.init_count(), 6);
+  assert_eq!(counter.drop_count(), 3);
+  drop(v2);
+  assert_eq!(counter.drop_count(), 6);
+  let mut v3 = staticvec![ZST {}, ZST {}, ZST {}, ZST {}];
+  v3.clear_range(1..3);
+  assert_eq!(v3.len(), 2);
+}
+
+#[test]
+#[should_panic]
+fn clear_range_panic() {
+  let mut v = staticvec![Box::new(1), Box::new(2)];
+  v.clear_range(1..3);
+}
+
 #[test]
 fn clone() {
   let v = staticvec![1, 2, 3, 4, 5, 6, 7, 8];
@@ -263,6 +527,32 @@ fn clone_from_longer() {
   assert_eq!(dst, src);
 }
 
+#[test]
+fn clone_from_slice() {
+  let mut v = staticvec![Box::new(1), Box::new(2), Box::new(3), Box::new(4)];
+  v.clone_from_slice(&[Box::new(5), Box::new(6)]);
+  assert_eq!(v, [Box::new(5), Box::new(6)]);
+  v.clone_from_slice(&[Box::new(7), Box::new(8), Box::new(9), Box::new(10)]);
+  assert_eq!(v, [Box::new(7), Box::new(8), Box::new(9), Box::new(10)]);
+  v.clone_from_slice(&[]);
+  assert_eq!(v, []);
+  let mut v2 = StaticVec::<String, 4>::new();
+  v2.clone_from_slice(&[String::from("a"), String::from("b"), String::from("c")]);
+  assert_eq!(v2, ["a", "b", "c"]);
+  v2.clone_from_slice(&[String::from("d")]);
+  assert_eq!(v2, ["d"]);
+  let mut v3 = staticvec![ZST {}, ZST {}];
+  v3.clone_from_slice(&[ZST {}]);
+  assert_eq!(v3.len(), 1);
+}
+
+#[test]
+#[should_panic]
+fn clone_from_slice_panic() {
+  let mut v = staticvec![Box::new(1), Box::new(2)];
+  v.clone_from_slice(&[Box::new(3), Box::new(4), Box::new(5)]);
+}
+
 #[cfg(feature = "std")]
 #[test]
 fn panicking_clone() {
@@ -356,6 +646,41 @@ fn concat_clone() {
   assert_eq!(v2, [Box::new(4), Box::new(5), Box::new(6), Box::new(1), Box::new(2), Box::new(3)]);
 }
 
+#[test]
+fn concat_into() {
+  let v: StaticVec<i32, 6> = staticvec![4, 5, 6].concat_into(&staticvec![1, 2, 3]);
+  assert_eq!(v, [4, 5, 6, 1, 2, 3]);
+  assert!(v.is_full());
+  let v2: StaticVec<i32, 8> = staticvec![4, 5].concat_into(&StaticVec::<i32, 4>::new());
+  assert_eq!(v2, [4, 5]);
+  assert_eq!(v2.capacity(), 8);
+  let v3: StaticVec<i32, 0> = StaticVec::<i32, 2>::new().concat_into(&StaticVec::<i32, 2>::new());
+  assert_eq!(v3, []);
+}
+
+#[test]
+#[should_panic]
+fn concat_into_panic() {
+  let _v: StaticVec<i32, 5> = staticvec![4, 5, 6].concat_into(&staticvec![1, 2, 3]);
+}
+
+#[test]
+fn concat_clone_into() {
+  let v: StaticVec<Box<i32>, 4> =
+    staticvec![Box::new(1), Box::new(2)].concat_clone_into(&staticvec![Box::new(3), Box::new(4)]);
+  assert_eq!(v, [Box::new(1), Box::new(2), Box::new(3), Box::new(4)]);
+  let v2: StaticVec<Box<i32>, 4> =
+    staticvec![Box::new(1)].concat_clone_into(&StaticVec::<Box<i32>, 9>::new());
+  assert_eq!(v2, [Box::new(1)]);
+}
+
+#[test]
+#[should_panic]
+fn concat_clone_into_panic() {
+  let _v: StaticVec<Box<i32>, 3> =
+    staticvec![Box::new(1), Box::new(2)].concat_clone_into(&staticvec![Box::new(3), Box::new(4)]);
+}
+
 #[test]
 fn contains() {
   assert_eq!(staticvec![1, 2, 3].contains(&2), true);
@@ -363,6 +688,122 @@ fn contains() {
   assert_eq!(staticvec![].contains(&2), false);
 }
 
+#[test]
+fn copy_from_slice() {
+  let mut v = staticvec![1, 2, 3, 4, 5];
+  v.copy_from_slice(&[6, 7]);
+  assert_eq!(v, [6, 7]);
+  v.copy_from_slice(&[1, 2, 3, 4, 5]);
+  assert_eq!(v, [1, 2, 3, 4, 5]);
+  v.copy_from_slice(&[]);
+  assert!(v.is_empty());
+}
+
+#[test]
+#[should_panic]
+fn copy_from_slice_panic() {
+  let mut v = staticvec![1, 2];
+  v.copy_from_slice(&[3, 4, 5]);
+}
+
+#[test]
+fn copy_within() {
+  let mut v = staticvec![1, 2, 3, 4, 5, 6];
+  // Overlapping, copying forwards.
+  v.copy_within(0..4, 2);
+  assert_eq!(v, [1, 2, 1, 2, 3, 4]);
+  // Overlapping, copying backwards.
+  v.copy_within(2.., 0);
+  assert_eq!(v, [1, 2, 3, 4, 3, 4]);
+  // Non-overlapping.
+  v.copy_within(..=1, 4);
+  assert_eq!(v, [1, 2, 3, 4, 1, 2]);
+  // Empty source range.
+  v.copy_within(3..3, 6);
+  assert_eq!(v, [1, 2, 3, 4, 1, 2]);
+}
+
+#[test]
+#[should_panic]
+fn copy_within_panic() {
+  let mut v = staticvec![1, 2, 3, 4];
+  v.copy_within(1..3, 3);
+}
+
+#[test]
+fn count() {
+  let v = staticvec![Box::new(1), Box::new(2), Box::new(3), Box::new(4), Box::new(6)];
+  assert_eq!(v.count(|x| **x % 2 == 0), 3);
+  assert_eq!(v.count(|x| **x > 10), 0);
+  assert_eq!(StaticVec::<i32, 4>::new().count(|_| true), 0);
+}
+
+#[test]
+fn count_eq() {
+  let v = staticvec![Box::new(7), Box::new(2), Box::new(7), Box::new(7), Box::new(3)];
+  assert_eq!(v.count_eq(&Box::new(7)), 3);
+  assert_eq!(v.count_eq(&Box::new(2)), 1);
+  assert_eq!(v.count_eq(&Box::new(5)), 0);
+  let v2 = staticvec![ZST {}, ZST {}];
+  assert_eq!(v2.count_eq(&ZST {}), 2);
+}
+
+#[test]
+fn cycle_fill() {
+  let mut v = StaticVec::<Box<i32>, 7>::new();
+  v.cycle_fill(&[Box::new(1), Box::new(2), Box::new(3)]);
+  assert_eq!(
+    v,
+    [
+      Box::new(1),
+      Box::new(2),
+      Box::new(3),
+      Box::new(1),
+      Box::new(2),
+      Box::new(3),
+      Box::new(1)
+    ]
+  );
+  // Already full, so nothing should happen.
+  v.cycle_fill(&[Box::new(9)]);
+  assert_eq!(v.len(), 7);
+  assert_eq!(v[6], Box::new(1));
+  let mut v2 = StaticVec::<i32, 5>::from([7, 8]);
+  v2.cycle_fill(&[0]);
+  assert_eq!(v2, [7, 8, 0, 0, 0]);
+  let mut v3 = StaticVec::<i32, 2>::new();
+  v3.cycle_fill(&[1, 2, 3, 4]);
+  assert_eq!(v3, [1, 2]);
+}
+
+#[test]
+#[should_panic(expected = "`StaticVec::cycle_fill` was called with an empty `pattern`!")]
+fn cycle_fill_panic() {
+  let mut v = StaticVec::<i32, 4>::new();
+  v.cycle_fill(&[]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn cycle_fill_panic_safety() {
+  struct PanicOnThirdClone<'a>(&'a cell::Cell<u32>);
+  impl<'a> Clone for PanicOnThirdClone<'a> {
+    fn clone(&self) -> Self {
+      self.0.set(self.0.get() + 1);
+      if self.0.get() == 3 {
+        panic!("Clone correctly panicked during a test");
+      }
+      PanicOnThirdClone(self.0)
+    }
+  }
+  let clones = cell::Cell::new(0);
+  let pattern = [PanicOnThirdClone(&clones), PanicOnThirdClone(&clones)];
+  let mut v = StaticVec::<PanicOnThirdClone, 6>::new();
+  let result = panic::catch_unwind(AssertUnwindSafe(|| v.cycle_fill(&pattern)));
+  assert!(result.is_err());
+  assert_eq!(v.len(), 2);
+}
+
 #[test]
 fn dedup() {
   let mut vec = staticvec![1, 2, 2, 3, 2];
@@ -377,6 +818,36 @@ fn dedup_by() {
   assert_eq!(vec, ["foo", "bar", "baz", "bar"]);
 }
 
+#[test]
+fn dedup_by_keeping_last() {
+  // The second value of each pair distinguishes the first element of each run from the last one.
+  let mut v = staticvec![(1, 'a'), (1, 'b'), (2, 'c'), (3, 'd'), (3, 'e')];
+  v.dedup_by_keeping_last(|a, b| a.0 == b.0);
+  assert_eq!(v, [(1, 'b'), (2, 'c'), (3, 'e')]);
+  let mut v2 = staticvec![
+    (1, String::from("a")),
+    (1, String::from("b")),
+    (1, String::from("c")),
+    (2, String::from("d")),
+    (1, String::from("e"))
+  ];
+  v2.dedup_by_keeping_last(|a, b| a.0 == b.0);
+  assert_eq!(
+    v2,
+    [(1, String::from("c")), (2, String::from("d")), (1, String::from("e"))]
+  );
+  let count = LifespanCounter::default();
+  let mut v3 = staticvec![count.instance(), count.instance(), count.instance()];
+  v3.dedup_by_keeping_last(|_, _| true);
+  assert_eq!(v3.len(), 1);
+  assert_eq!(count.drop_count(), 2);
+  drop(v3);
+  assert_eq!(count.drop_count(), 3);
+  let mut v4 = StaticVec::<i32, 4>::new();
+  v4.dedup_by_keeping_last(|_, _| true);
+  assert!(v4.is_empty());
+}
+
 #[test]
 fn dedup_by_key() {
   let mut vec = staticvec![10, 20, 21, 30, 20];
@@ -384,6 +855,120 @@ fn dedup_by_key() {
   assert_eq!(vec, [10, 20, 30, 20]);
 }
 
+#[test]
+fn dedup_by_window() {
+  let mut v = staticvec![
+    Box::new(1),
+    Box::new(2),
+    Box::new(1),
+    Box::new(3),
+    Box::new(4),
+    Box::new(1)
+  ];
+  // The second `1` is two kept elements back from the first, so it's within the window, whereas
+  // the third is three kept elements back from the first, so it's outside of it.
+  v.dedup_by_window(2, |a, b| a == b);
+  assert_eq!(v, [Box::new(1), Box::new(2), Box::new(3), Box::new(4), Box::new(1)]);
+  let mut v2 = staticvec![1, 2, 1, 2, 1];
+  v2.dedup_by_window(1, |a, b| a == b);
+  assert_eq!(v2, [1, 2, 1, 2, 1]);
+  v2.dedup_by_window(0, |_, _| true);
+  assert_eq!(v2, [1, 2, 1, 2, 1]);
+  v2.dedup_by_window(usize::MAX, |a, b| a == b);
+  assert_eq!(v2, [1, 2]);
+  // Near-duplicate removal, comparing within a tolerance.
+  let mut v3 = staticvec![10, 20, 11, 30, 19, 40];
+  v3.dedup_by_window(3, |a: &i32, b: &i32| (a - b).abs() <= 1);
+  assert_eq!(v3, [10, 20, 30, 40]);
+  let count = LifespanCounter::default();
+  let mut v4 = staticvec![count.instance(), count.instance(), count.instance()];
+  v4.dedup_by_window(5, |_, _| true);
+  assert_eq!(v4.len(), 1);
+  assert_eq!(count.drop_count(), 2);
+  drop(v4);
+  assert_eq!(count.drop_count(), 3);
+  let mut v5 = StaticVec::<i32, 4>::new();
+  v5.dedup_by_window(2, |_, _| true);
+  assert!(v5.is_empty());
+}
+
+#[test]
+fn dedup_keeping_last() {
+  let mut v = staticvec![Box::new(1), Box::new(1), Box::new(2), Box::new(3), Box::new(3)];
+  v.dedup_keeping_last();
+  assert_eq!(v, [Box::new(1), Box::new(2), Box::new(3)]);
+  let mut v2 = staticvec![1, 2, 2, 3, 2];
+  v2.dedup_keeping_last();
+  assert_eq!(v2, [1, 2, 3, 2]);
+}
+
+#[test]
+fn dedup_runs() {
+  let mut v = staticvec!["a", "a", "b"];
+  assert_eq!(v.dedup_runs(), [2, 1]);
+  assert_eq!(v, ["a", "b"]);
+  let mut v2 = staticvec![
+    String::from("x"),
+    String::from("x"),
+    String::from("x"),
+    String::from("y"),
+    String::from("x"),
+    String::from("x")
+  ];
+  assert_eq!(v2.dedup_runs(), [3, 1, 2]);
+  assert_eq!(v2, ["x", "y", "x"]);
+  let mut v3 = StaticVec::<String, 4>::new();
+  assert_eq!(v3.dedup_runs(), []);
+  let mut v4 = staticvec![ZST {}, ZST {}, ZST {}];
+  assert_eq!(v4.dedup_runs(), [3]);
+  assert_eq!(v4.len(), 1);
+}
+
+#[test]
+fn dedup_sorted() {
+  let mut v = staticvec![Box::new(1), Box::new(1), Box::new(2), Box::new(3), Box::new(3)];
+  v.dedup_sorted();
+  assert_eq!(v, [Box::new(1), Box::new(2), Box::new(3)]);
+  let mut v2 = staticvec![1, 2, 3];
+  v2.dedup_sorted();
+  assert_eq!(v2, [1, 2, 3]);
+  let mut v3 = StaticVec::<i32, 4>::new();
+  v3.dedup_sorted();
+  assert!(v3.is_empty());
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic]
+fn dedup_sorted_unsorted_panic() {
+  let mut v = staticvec![3, 1, 1, 2];
+  v.dedup_sorted();
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn dedup_total() {
+  let mut v = staticvec![1, 2, 1, 3, 2];
+  v.dedup_total();
+  assert_eq!(v, [1, 2, 3]);
+  let mut v2 = staticvec![
+    String::from("b"),
+    String::from("a"),
+    String::from("b"),
+    String::from("c"),
+    String::from("a"),
+    String::from("b")
+  ];
+  v2.dedup_total();
+  assert_eq!(v2, [String::from("b"), String::from("a"), String::from("c")]);
+  let mut v3 = staticvec![1, 2, 3];
+  v3.dedup_total();
+  assert_eq!(v3, [1, 2, 3]);
+  let mut v4 = StaticVec::<String, 4>::new();
+  v4.dedup_total();
+  assert_eq!(v4, []);
+}
+
 #[test]
 fn difference() {
   assert_eq!(
@@ -393,6 +978,16 @@ fn difference() {
   assert_eq!(staticvec![1, 2, 3].difference(&staticvec![3, 4, 5]), [1, 2]);
 }
 
+#[test]
+fn dot() {
+  let a = staticvec![1.5, 2.0, 3.0];
+  let b = staticvec![2.0, 4.0, 0.5];
+  assert_eq!(a.dot(&b), 12.5);
+  assert_eq!(a.dot(&StaticVec::new_from_slice(&[2.0])), 3.0);
+  assert_eq!(StaticVec::<f64, 3>::new().dot(&b), 0.0);
+  assert_eq!(staticvec![1, 2, 3, 4].dot(&staticvec![4, 3, 2, 1]), 20);
+}
+
 #[test]
 fn drain() {
   let mut v = staticvec![1, 2, 3];
@@ -440,6 +1035,73 @@ fn drain_panic() {
   v3.drain(0..=0);
 }
 
+#[test]
+fn drain_empty_ranges() {
+  let mut v = staticvec![Box::new(1), Box::new(2), Box::new(3)];
+  assert_eq!(v.drain(0..0), []);
+  assert_eq!(v, [Box::new(1), Box::new(2), Box::new(3)]);
+  assert_eq!(v.drain(1..1), []);
+  assert_eq!(v, [Box::new(1), Box::new(2), Box::new(3)]);
+  assert_eq!(v.drain(3..3), []);
+  assert_eq!(v, [Box::new(1), Box::new(2), Box::new(3)]);
+  assert_eq!(v.drain(3..), []);
+  assert_eq!(v.len(), 3);
+  assert_eq!(v.drain(..), [Box::new(1), Box::new(2), Box::new(3)]);
+  assert_eq!(v, []);
+  assert_eq!(v.drain(..), []);
+  assert_eq!(v.drain(0..0), []);
+  let mut v2 = staticvec![Box::new(1), Box::new(2), Box::new(3)];
+  assert_eq!(v2.drain_iter(3..3).count(), 0);
+  assert_eq!(v2.drain_iter(1..1).count(), 0);
+  assert_eq!(v2.drain_iter(0..0).count(), 0);
+  assert_eq!(v2, [Box::new(1), Box::new(2), Box::new(3)]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn drain_panic_safety() {
+  let mut v = staticvec![Box::new(1), Box::new(2), Box::new(3)];
+  let result = panic::catch_unwind(AssertUnwindSafe(|| {
+    v.drain(3..4);
+  }));
+  assert!(result.is_err());
+  assert_eq!(v, [Box::new(1), Box::new(2), Box::new(3)]);
+  let result = panic::catch_unwind(AssertUnwindSafe(|| {
+    v.drain((core::ops::Bound::Excluded(usize::MAX), core::ops::Bound::Unbounded));
+  }));
+  assert!(result.is_err());
+  assert_eq!(v, [Box::new(1), Box::new(2), Box::new(3)]);
+  let result = panic::catch_unwind(AssertUnwindSafe(|| {
+    v.drain_iter(..=usize::MAX);
+  }));
+  assert!(result.is_err());
+  assert_eq!(v, [Box::new(1), Box::new(2), Box::new(3)]);
+}
+
+#[test]
+fn drain_all() {
+  let mut v = staticvec![Box::new(1), Box::new(2), Box::new(3)];
+  let mut it = v.drain_all();
+  assert!(v.is_empty());
+  assert_eq!(v.capacity(), 3);
+  assert_eq!(it.len(), 3);
+  assert_eq!(it.next(), Some(Box::new(1)));
+  v.push(Box::new(4));
+  assert_eq!(it.collect::<StaticVec<Box<i32>, 3>>(), [Box::new(2), Box::new(3)]);
+  assert_eq!(v, [Box::new(4)]);
+  let count = LifespanCounter::default();
+  let mut v2 = staticvec![count.instance(), count.instance(), count.instance()];
+  let mut it2 = v2.drain_all();
+  drop(it2.next());
+  assert_eq!(count.drop_count(), 1);
+  drop(it2);
+  assert_eq!(count.drop_count(), 3);
+  drop(v2);
+  assert_eq!(count.drop_count(), 3);
+  let mut v3 = StaticVec::<u8, 4>::new();
+  assert_eq!(v3.drain_all().next(), None);
+}
+
 #[test]
 fn drain_iter() {
   let mut v = staticvec![1, 2, 3];
@@ -593,6 +1255,71 @@ fn extend() {
   assert_eq!(c7, [vec![vec![1, 1]], vec![vec![1, 2]], vec![vec![1, 3]]]);
 }
 
+#[test]
+fn extend_excess() {
+  // Extending a near-full StaticVec from a longer StaticVec that is itself not full.
+  let mut v1 = StaticVec::<Box<i32>, 4>::from([Box::new(1), Box::new(2), Box::new(3)]);
+  let mut v2 = StaticVec::<Box<i32>, 8>::new();
+  v2.extend([Box::new(4), Box::new(5), Box::new(6)]);
+  v1.extend(v2);
+  assert_eq!(v1, [Box::new(1), Box::new(2), Box::new(3), Box::new(4)]);
+  // Extending a near-full StaticVec from a longer array.
+  let mut v3 = StaticVec::<Box<i32>, 4>::from([Box::new(1), Box::new(2), Box::new(3)]);
+  v3.extend([Box::new(4), Box::new(5), Box::new(6)]);
+  assert_eq!(v3, [Box::new(1), Box::new(2), Box::new(3), Box::new(4)]);
+  // Extending a near-full StaticVec from a longer slice.
+  let s = [Box::new(4), Box::new(5), Box::new(6)];
+  let mut v4 = StaticVec::<Box<i32>, 4>::from([Box::new(1), Box::new(2), Box::new(3)]);
+  v4.extend(s[..].iter().cloned());
+  assert_eq!(v4, [Box::new(1), Box::new(2), Box::new(3), Box::new(4)]);
+  // Extending a near-full StaticVec from a longer iterator.
+  let mut v5 = StaticVec::<Box<i32>, 4>::from([Box::new(1), Box::new(2), Box::new(3)]);
+  v5.extend((4..7).map(Box::new));
+  assert_eq!(v5, [Box::new(1), Box::new(2), Box::new(3), Box::new(4)]);
+  // Checking that exactly the un-moved elements are dropped, exactly once.
+  let counter = LifespanCounter::default();
+  let mut v6 = StaticVec::<LifespanCountingInstance, 4>::new();
+  v6.push(counter.instance());
+  v6.push(counter.instance());
+  let mut v7 = StaticVec::<LifespanCountingInstance, 8>::new();
+  for _ in 0..5 {
+    v7.push(counter.instance());
+  }
+  v6.extend(v7);
+  assert_eq!(v6.len(), 4);
+  assert_eq!(counter.init_count(), 7);
+  assert_eq!(counter.drop_count(), 3);
+  drop(v6);
+  assert_eq!(counter.drop_count(), 7);
+  let counter2 = LifespanCounter::default();
+  let mut v8 = StaticVec::<LifespanCountingInstance, 4>::new();
+  v8.push(counter2.instance());
+  v8.extend([counter2.instance(), counter2.instance(), counter2.instance(), counter2.instance()]);
+  assert_eq!(v8.len(), 4);
+  assert_eq!(counter2.drop_count(), 1);
+  drop(v8);
+  assert_eq!(counter2.drop_count(), 5);
+  // Collecting from a StaticVec that is not full into one with a smaller capacity.
+  let counter3 = LifespanCounter::default();
+  let mut v9 = StaticVec::<LifespanCountingInstance, 8>::new();
+  for _ in 0..3 {
+    v9.push(counter3.instance());
+  }
+  let v10: StaticVec<LifespanCountingInstance, 2> = v9.into_iter().collect();
+  assert_eq!(v10.len(), 2);
+  assert_eq!(counter3.drop_count(), 1);
+  let mut v11 = StaticVec::<LifespanCountingInstance, 8>::new();
+  for _ in 0..3 {
+    v11.push(counter3.instance());
+  }
+  let v12 = StaticVec::<LifespanCountingInstance, 4>::from_iter(v11);
+  assert_eq!(v12.len(), 3);
+  assert_eq!(counter3.drop_count(), 1);
+  drop(v10);
+  drop(v12);
+  assert_eq!(counter3.drop_count(), 6);
+}
+
 #[test]
 fn extend_from_slice() {
   let mut vec = StaticVec::<i32, 4>::new_from_slice(&[1]);
@@ -603,6 +1330,59 @@ fn extend_from_slice() {
   assert_eq!(vec2, []);
 }
 
+#[test]
+fn fill_default() {
+  let mut v = StaticVec::<i32, 6>::from([1, 2, 3]);
+  v.fill_default();
+  assert_eq!(v, [0, 0, 0, 0, 0, 0]);
+  assert!(v.is_full());
+  let mut v2 = staticvec![String::from("a"), String::from("b")];
+  v2.fill_default();
+  assert_eq!(v2, ["", ""]);
+  let mut v3 = StaticVec::<String, 3>::new();
+  v3.fill_default();
+  assert_eq!(v3, ["", "", ""]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn fill_default_panic_safety() {
+  static CALLS: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+  #[derive(Debug)]
+  struct PanicsOnThirdDefault(Box<i32>);
+  impl Default for PanicsOnThirdDefault {
+    fn default() -> Self {
+      if CALLS.fetch_add(1, std::sync::atomic::Ordering::Relaxed) == 2 {
+        panic!("Third default!");
+      }
+      Self(Box::new(0))
+    }
+  }
+  let mut v = staticvec![PanicsOnThirdDefault(Box::new(1))];
+  let res = panic::catch_unwind(AssertUnwindSafe(|| v.fill_default()));
+  assert!(res.is_err());
+  // Miri will catch any leaks or double drops here.
+  assert_eq!(v.len(), 2);
+}
+
+#[test]
+fn fill_from_iter() {
+  let mut v = StaticVec::<Box<i32>, 5>::from([Box::new(1), Box::new(2)]);
+  let mut it = (3..10).map(Box::new);
+  assert_eq!(v.fill_from_iter(it.by_ref()), 3);
+  assert!(v.is_full());
+  assert_eq!(v, [Box::new(1), Box::new(2), Box::new(3), Box::new(4), Box::new(5)]);
+  assert_eq!(it.next(), Some(Box::new(6)));
+  assert_eq!(v.fill_from_iter(it.by_ref()), 0);
+  assert_eq!(it.next(), Some(Box::new(7)));
+  let mut v2 = StaticVec::<Box<i32>, 5>::from([Box::new(1)]);
+  assert_eq!(v2.fill_from_iter([Box::new(2), Box::new(3)]), 2);
+  assert_eq!(v2, [Box::new(1), Box::new(2), Box::new(3)]);
+  assert_eq!(v2.remaining_capacity(), 2);
+  assert_eq!(v2.fill_from_iter(core::iter::empty()), 0);
+  assert_eq!(v2.len(), 3);
+}
+
 #[test]
 fn filled_with() {
   let mut i = 0;
@@ -635,6 +1415,14 @@ fn filled_with_by_index() {
   assert_eq!(v2.remaining_capacity(), 0);
 }
 
+#[test]
+fn find() {
+  let v = staticvec![Box::new(1), Box::new(2), Box::new(3), Box::new(4)];
+  assert_eq!(v.find(|x| **x > 2), Some(&Box::new(3)));
+  assert_eq!(v.find(|x| **x > 4), None);
+  assert_eq!(StaticVec::<i32, 4>::new().find(|_| true), None);
+}
+
 #[test]
 fn first() {
   let v = staticvec![1, 2, 3];
@@ -647,6 +1435,27 @@ fn first_mut() {
   assert_eq!(*v.first_mut().unwrap(), 1);
 }
 
+#[test]
+fn flatten_into() {
+  let v = staticvec![[Box::new(1), Box::new(2)], [Box::new(3), Box::new(4)], [Box::new(5), Box::new(6)]];
+  let flat: StaticVec<Box<i32>, 6> = v.flatten_into();
+  assert_eq!(flat, [Box::new(1), Box::new(2), Box::new(3), Box::new(4), Box::new(5), Box::new(6)]);
+  let mut v2 = StaticVec::<[Box<i32>; 2], 3>::new();
+  v2.push([Box::new(1), Box::new(2)]);
+  let flat2: StaticVec<Box<i32>, 3> = v2.flatten_into();
+  assert_eq!(flat2, [Box::new(1), Box::new(2)]);
+  let v3 = staticvec![[0u8; 0], [0u8; 0]];
+  let flat3: StaticVec<u8, 0> = v3.flatten_into();
+  assert_eq!(flat3, []);
+}
+
+#[test]
+#[should_panic]
+fn flatten_into_panic() {
+  let v = staticvec![[1, 2], [3, 4], [5, 6]];
+  let _flat: StaticVec<i32, 5> = v.flatten_into();
+}
+
 #[test]
 fn from() {
   assert_eq!(
@@ -688,6 +1497,34 @@ fn from() {
   );
 }
 
+#[test]
+fn from_fn() {
+  let v = StaticVec::<Box<usize>, 5>::from_fn(|i| Box::new(i * 2));
+  assert_eq!(v.len(), 5);
+  assert!(v.is_full());
+  assert_eq!(v, [Box::new(0), Box::new(2), Box::new(4), Box::new(6), Box::new(8)]);
+  let v2 = StaticVec::<usize, 0>::from_fn(|i| i);
+  assert!(v2.is_empty());
+}
+
+#[test]
+fn from_fn_n() {
+  let v = StaticVec::<Box<usize>, 5>::from_fn_n(3, |i| Box::new(i + 1));
+  assert_eq!(v.len(), 3);
+  assert_eq!(v.remaining_capacity(), 2);
+  assert_eq!(v, [Box::new(1), Box::new(2), Box::new(3)]);
+  let v2 = StaticVec::<usize, 5>::from_fn_n(5, |i| i);
+  assert_eq!(v2, [0, 1, 2, 3, 4]);
+  let v3 = StaticVec::<usize, 5>::from_fn_n(0, |_| unreachable!());
+  assert!(v3.is_empty());
+}
+
+#[test]
+#[should_panic(expected = "Insufficient capacity in `StaticVec::from_fn_n`!")]
+fn from_fn_n_panic() {
+  let _v = StaticVec::<usize, 5>::from_fn_n(6, |i| i);
+}
+
 #[test]
 fn from_iter() {
   assert_eq!(
@@ -755,6 +1592,49 @@ fn from_vec() {
   assert_eq!(y, []);
 }
 
+#[test]
+fn get() {
+  let v = staticvec![Box::new(1), Box::new(2), Box::new(3)];
+  assert_eq!(v.get(0), Some(&Box::new(1)));
+  assert_eq!(v.get(2), Some(&Box::new(3)));
+  assert_eq!(v.get(3), None);
+  assert_eq!(v.get(1..3), Some(&[Box::new(2), Box::new(3)][..]));
+  assert_eq!(v.get(..=0), Some(&[Box::new(1)][..]));
+  assert_eq!(v.get(2..4), None);
+  assert_eq!(v.get(3..), Some(&[][..]));
+  let e = StaticVec::<i32, 4>::new();
+  assert_eq!(e.get(0), None);
+  assert_eq!(e.get(..), Some(&[][..]));
+}
+
+#[test]
+fn get_mut() {
+  let mut v = staticvec![Box::new(1), Box::new(2), Box::new(3)];
+  **v.get_mut(1).unwrap() = 4;
+  assert_eq!(v, [Box::new(1), Box::new(4), Box::new(3)]);
+  v.get_mut(1..).unwrap()[1] = Box::new(5);
+  assert_eq!(v, [Box::new(1), Box::new(4), Box::new(5)]);
+  assert!(v.get_mut(3).is_none());
+  assert!(v.get_mut(0..4).is_none());
+}
+
+#[test]
+fn get_many_mut() {
+  let mut v = staticvec![Box::new(1), Box::new(2), Box::new(3), Box::new(4)];
+  let [a, b, c] = v.get_many_mut([3, 0, 2]).unwrap();
+  **a = 40;
+  **b = 10;
+  core::mem::swap(b, c);
+  assert_eq!(v, [Box::new(3), Box::new(2), Box::new(10), Box::new(40)]);
+  assert!(v.get_many_mut([0, 1, 0]).is_none());
+  assert!(v.get_many_mut([2, 2]).is_none());
+  assert!(v.get_many_mut([1, 4]).is_none());
+  assert!(v.get_many_mut([usize::MAX]).is_none());
+  assert_eq!(v.get_many_mut([]), Some([]));
+  let mut empty = StaticVec::<i32, 4>::new();
+  assert!(empty.get_many_mut([0]).is_none());
+}
+
 #[test]
 fn get_unchecked() {
   let v = staticvec!["a", "b", "c"];
@@ -767,6 +1647,84 @@ fn get_unchecked_mut() {
   assert_eq!(unsafe { *v.get_unchecked_mut(1) }, "b");
 }
 
+#[test]
+fn halves() {
+  let v = staticvec![Box::new(1), Box::new(2), Box::new(3), Box::new(4)];
+  assert_eq!(v.halves(), (&[Box::new(1), Box::new(2)][..], &[Box::new(3), Box::new(4)][..]));
+  let v2 = staticvec![1, 2, 3, 4, 5];
+  assert_eq!(v2.halves(), (&[1, 2][..], &[3, 4, 5][..]));
+  let v3 = staticvec![1];
+  assert_eq!(v3.halves(), (&[][..], &[1][..]));
+  let v4 = StaticVec::<i32, 4>::new();
+  assert_eq!(v4.halves(), (&[][..], &[][..]));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn has_duplicates() {
+  assert!(staticvec![Box::new(1), Box::new(2), Box::new(3), Box::new(1)].has_duplicates());
+  assert!(staticvec![1, 1].has_duplicates());
+  assert!(!staticvec![Box::new(1), Box::new(2), Box::new(3)].has_duplicates());
+  assert!(!staticvec![1].has_duplicates());
+  assert!(!StaticVec::<i32, 4>::new().has_duplicates());
+  assert!(staticvec![ZST {}, ZST {}].has_duplicates());
+  // NaN is not equal to itself, so it never counts as a duplicate.
+  assert!(!staticvec![f64::NAN, f64::NAN].has_duplicates());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn has_duplicates_hashed() {
+  assert!(staticvec![Box::new(1), Box::new(2), Box::new(3), Box::new(1)].has_duplicates_hashed());
+  assert!(staticvec![String::from("a"), String::from("a")].has_duplicates_hashed());
+  assert!(!staticvec![Box::new(1), Box::new(2), Box::new(3)].has_duplicates_hashed());
+  assert!(!staticvec![1].has_duplicates_hashed());
+  assert!(!StaticVec::<i32, 4>::new().has_duplicates_hashed());
+}
+
+#[test]
+fn hash() {
+  use std::collections::hash_map::DefaultHasher;
+  use std::hash::{Hash, Hasher};
+  fn hash_of<H: Hash + ?Sized>(value: &H) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+  }
+  let v1 = StaticVec::<Box<i32>, 8>::from([Box::new(1), Box::new(2), Box::new(3)]);
+  let v2 = vec![Box::new(1), Box::new(2), Box::new(3)];
+  assert_eq!(hash_of(&v1), hash_of(&v2));
+  assert_eq!(hash_of(&v1), hash_of(&v2[..]));
+  let v3 = StaticVec::<Box<i32>, 3>::from([Box::new(1), Box::new(2), Box::new(3)]);
+  assert_eq!(hash_of(&v1), hash_of(&v3));
+  let mut v4 = staticvec![1, 2, 3, 4];
+  v4.pop();
+  assert_eq!(hash_of(&v4), hash_of(&[1, 2, 3][..]));
+  assert_eq!(hash_of(&StaticVec::<i32, 4>::new()), hash_of(&Vec::<i32>::new()));
+}
+
+#[test]
+fn index_of_max_by_key() {
+  let v = staticvec!["bb", "a", "dddd", "ccc"];
+  assert_eq!(v.index_of_max_by_key(|s| s.len()), Some(2));
+  let v2 = staticvec![Box::new(1), Box::new(5), Box::new(2), Box::new(5), Box::new(-5)];
+  assert_eq!(v2.index_of_max_by_key(|x| **x), Some(1));
+  assert_eq!(v2.index_of_max_by_key(|x| x.abs()), Some(1));
+  assert_eq!(staticvec![7].index_of_max_by_key(|x| *x), Some(0));
+  assert_eq!(StaticVec::<i32, 4>::new().index_of_max_by_key(|x| *x), None);
+}
+
+#[test]
+fn index_of_min_by_key() {
+  let v = staticvec!["bb", "a", "dddd", "ccc"];
+  assert_eq!(v.index_of_min_by_key(|s| s.len()), Some(1));
+  let v2 = staticvec![Box::new(3), Box::new(1), Box::new(2), Box::new(1), Box::new(-1)];
+  assert_eq!(v2.index_of_min_by_key(|x| **x), Some(4));
+  assert_eq!(v2.index_of_min_by_key(|x| x.abs()), Some(1));
+  assert_eq!(staticvec![7].index_of_min_by_key(|x| *x), Some(0));
+  assert_eq!(StaticVec::<i32, 4>::new().index_of_min_by_key(|x| *x), None);
+}
+
 #[test]
 fn index() {
   let vec = staticvec![0, 1, 2, 3, 4];
@@ -782,16 +1740,105 @@ fn index() {
 }
 
 #[test]
-#[cfg(feature = "std")]
-fn index_panics() {
-  let vec = staticvec![0, 1, 2, 3, 4];
-  assert_panics!(vec[10]);
+fn indexed() {
+  let v = staticvec![Box::new(5), Box::new(6), Box::new(7), Box::new(8)];
+  let pairs: StaticVec<(usize, &Box<i32>), 4> = v.indexed().collect();
+  assert_eq!(pairs, [(0, &Box::new(5)), (1, &Box::new(6)), (2, &Box::new(7)), (3, &Box::new(8))]);
+  let mut it = v.indexed();
+  assert_eq!(it.len(), 4);
+  assert_eq!(it.nth(1), Some((1, &Box::new(6))));
+  assert_eq!(it.next_back(), Some((3, &Box::new(8))));
+  assert_eq!(it.len(), 1);
+  // `Zip` over two `Enumerate`s takes the `TrustedRandomAccess` path internally.
+  let sums: StaticVec<usize, 4> = v
+    .indexed()
+    .zip(v.indexed().rev())
+    .map(|((i, _), (j, _))| i + j)
+    .collect();
+  assert_eq!(sums, [3, 3, 3, 3]);
+  assert_eq!(StaticVec::<i32, 4>::new().indexed().next(), None);
+  let zsts = staticvec![ZST {}, ZST {}, ZST {}];
+  let indices: StaticVec<usize, 3> = zsts.indexed().map(|(i, _)| i).collect();
+  assert_eq!(indices, [0, 1, 2]);
+}
+
+#[test]
+fn indexed_mut() {
+  let mut v = staticvec![Box::new(5), Box::new(6), Box::new(7)];
+  for (i, x) in v.indexed_mut() {
+    **x *= i as i32;
+  }
+  assert_eq!(v, [Box::new(0), Box::new(6), Box::new(14)]);
+  let mut it = v.indexed_mut();
+  assert_eq!(it.len(), 3);
+  let (i, last) = it.next_back().unwrap();
+  assert_eq!(i, 2);
+  **last = 1;
+  assert_eq!(v, [Box::new(0), Box::new(6), Box::new(1)]);
+  assert_eq!(StaticVec::<i32, 4>::new().indexed_mut().next(), None);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn index_panics() {
+  let vec = staticvec![0, 1, 2, 3, 4];
+  assert_panics!(vec[10]);
   assert_panics!(&vec[..10]);
   assert_panics!(&vec[10..]);
   assert_panics!(&vec[10..15]);
   assert_panics!(&vec[1..0]);
 }
 
+#[test]
+fn init_range() {
+  let mut v = StaticVec::<Box<usize>, 6>::from([Box::new(7), Box::new(8), Box::new(9)]);
+  v.init_range(1..5, |i| Box::new(i * 10));
+  assert_eq!(v, [Box::new(7), Box::new(10), Box::new(20), Box::new(30), Box::new(40)]);
+  v.init_range(5..=5, |i| Box::new(i));
+  assert_eq!(v.len(), 6);
+  assert_eq!(v[5], Box::new(5));
+  v.init_range(2..2, |_| unreachable!());
+  assert_eq!(v.len(), 6);
+  let mut v2 = StaticVec::<Box<usize>, 4>::new();
+  v2.init_range(.., |i| Box::new(i + 1));
+  assert_eq!(v2, [Box::new(1), Box::new(2), Box::new(3), Box::new(4)]);
+  let count = LifespanCounter::default();
+  let mut v3 = StaticVec::<LifespanCountingInstance, 5>::new();
+  v3.init_range(..3, |_| count.instance());
+  assert_eq!(count.drop_count(), 0);
+  v3.init_range(1..5, |_| count.instance());
+  assert_eq!(v3.len(), 5);
+  assert_eq!(count.init_count(), 7);
+  assert_eq!(count.drop_count(), 2);
+  drop(v3);
+  assert_eq!(count.drop_count(), 7);
+}
+
+#[test]
+#[should_panic(expected = "Bounds check failure in `StaticVec::init_range`!")]
+fn init_range_panic_a() {
+  let mut v = StaticVec::<i32, 6>::from([1, 2]);
+  v.init_range(3..4, |_| 0);
+}
+
+#[test]
+#[should_panic(expected = "Bounds check failure in `StaticVec::init_range`!")]
+fn init_range_panic_b() {
+  let mut v = StaticVec::<i32, 6>::from([1, 2]);
+  v.init_range(0..7, |_| 0);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn init_range_panic_safety() {
+  let mut v = StaticVec::<Box<usize>, 6>::from([Box::new(0), Box::new(0)]);
+  let result = panic::catch_unwind(AssertUnwindSafe(|| {
+    v.init_range(1..6, |i| if i == 3 { panic!() } else { Box::new(i) })
+  }));
+  assert!(result.is_err());
+  assert_eq!(v, [Box::new(0), Box::new(1), Box::new(2)]);
+}
+
 #[test]
 fn insert() {
   let mut vec = StaticVec::<i32, 5>::new_from_slice(&[1, 2, 3]);
@@ -861,6 +1908,31 @@ fn insert_from_slice() {
   assert_eq!(v2, [12]);
 }
 
+#[test]
+fn insert_from_slice_bounded() {
+  let mut v = StaticVec::<u8, 8>::from([1, 2, 7, 8]);
+  assert_eq!(v.insert_from_slice_bounded(2, &[3, 4]), 2);
+  assert_eq!(v, [1, 2, 3, 4, 7, 8]);
+  assert_eq!(v.insert_from_slice_bounded(4, &[5, 6, 9, 10]), 2);
+  assert_eq!(v, [1, 2, 3, 4, 5, 6, 7, 8]);
+  assert_eq!(v.insert_from_slice_bounded(0, &[0]), 0);
+  assert_eq!(v, [1, 2, 3, 4, 5, 6, 7, 8]);
+  let mut v2 = StaticVec::<u8, 5>::from([1, 9]);
+  assert_eq!(v2.insert_from_slice_bounded(1, &[2, 3, 4, 5, 6]), 3);
+  assert_eq!(v2, [1, 2, 3, 4, 9]);
+  let mut v3 = StaticVec::<u8, 3>::new();
+  assert_eq!(v3.insert_from_slice_bounded(0, &[]), 0);
+  assert_eq!(v3.insert_from_slice_bounded(0, &[1, 2, 3, 4]), 3);
+  assert_eq!(v3, [1, 2, 3]);
+}
+
+#[test]
+#[should_panic(expected = "Bounds check failure in `StaticVec::insert_from_slice_bounded`!")]
+fn insert_from_slice_bounded_panic() {
+  let mut v = StaticVec::<u8, 8>::from([1, 2]);
+  v.insert_from_slice_bounded(3, &[3]);
+}
+
 #[test]
 #[should_panic(
   expected = "Insufficient remaining capacity or bounds check failure in `StaticVec::insert_from_slice`!"
@@ -952,6 +2024,50 @@ fn intersection() {
   assert_eq!(staticvec![1, 2, 3].intersection(&staticvec![]), []);
 }
 
+#[test]
+fn interleave_into() {
+  let left = staticvec![1.0f32, 3.0, 5.0];
+  let right = staticvec![2.0f32, 4.0, 6.0];
+  let v: StaticVec<f32, 6> = left.interleave_into(&right);
+  assert_eq!(v, [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+  let v2: StaticVec<i32, 8> = staticvec![1, 3].interleave_into(&staticvec![2, 4, 5, 6]);
+  assert_eq!(v2, [1, 2, 3, 4, 5, 6]);
+  let v3: StaticVec<i32, 6> = staticvec![1, 3, 5, 6].interleave_into(&staticvec![2, 4]);
+  assert_eq!(v3, [1, 2, 3, 4, 5, 6]);
+  let v4: StaticVec<i32, 2> = StaticVec::<i32, 4>::new().interleave_into(&staticvec![1, 2]);
+  assert_eq!(v4, [1, 2]);
+  let empty = StaticVec::<i32, 4>::new();
+  let v5: StaticVec<i32, 0> = empty.interleave_into(&empty);
+  assert!(v5.is_empty());
+}
+
+#[test]
+#[should_panic(expected = "Insufficient capacity in `StaticVec::interleave_into`!")]
+fn interleave_into_panic() {
+  let _v: StaticVec<i32, 4> = staticvec![1, 2, 3].interleave_into(&staticvec![4, 5]);
+}
+
+#[test]
+fn interleave_clone_into() {
+  let left = staticvec![Box::new(1), Box::new(3)];
+  let right = staticvec![Box::new(2), Box::new(4)];
+  let v: StaticVec<Box<i32>, 4> = left.interleave_clone_into(&right);
+  assert_eq!(v, [Box::new(1), Box::new(2), Box::new(3), Box::new(4)]);
+  let v2: StaticVec<Box<i32>, 5> = left.interleave_clone_into(&staticvec![Box::new(2)]);
+  assert_eq!(v2, [Box::new(1), Box::new(2), Box::new(3)]);
+  let v3: StaticVec<Box<i32>, 5> = staticvec![Box::new(1)].interleave_clone_into(&right);
+  assert_eq!(v3, [Box::new(1), Box::new(2), Box::new(4)]);
+  assert_eq!(left, [Box::new(1), Box::new(3)]);
+  assert_eq!(right, [Box::new(2), Box::new(4)]);
+}
+
+#[test]
+#[should_panic(expected = "Insufficient capacity in `StaticVec::interleave_clone_into`!")]
+fn interleave_clone_into_panic() {
+  let _v: StaticVec<Box<i32>, 2> =
+    staticvec![Box::new(1), Box::new(2)].interleave_clone_into(&staticvec![Box::new(3)]);
+}
+
 #[test]
 fn intersperse() {
   assert_eq!(
@@ -981,6 +2097,52 @@ fn intersperse_clone() {
   );
 }
 
+#[test]
+fn intersperse_into() {
+  let v: StaticVec<&str, 7> = staticvec!["A", "B", "C", "D"].intersperse_into("Z");
+  assert_eq!(v, ["A", "Z", "B", "Z", "C", "Z", "D"]);
+  let v2: StaticVec<&str, 1> = staticvec![""].intersperse_into("B");
+  assert_eq!(v2, [""]);
+  let v3: StaticVec<&str, 4> = staticvec!["A"].intersperse_into("B");
+  assert_eq!(v3, ["A"]);
+  let mut x = staticvec!["A"];
+  x.clear();
+  let v4: StaticVec<&str, 0> = x.intersperse_into("B");
+  assert_eq!(v4, StaticVec::<&str, 0>::new());
+}
+
+#[test]
+#[should_panic]
+fn intersperse_into_panic() {
+  let _v: StaticVec<&str, 6> = staticvec!["A", "B", "C", "D"].intersperse_into("Z");
+}
+
+#[test]
+fn intersperse_clone_into() {
+  let v: StaticVec<Box<&str>, 7> =
+    staticvec![Box::new("A"), Box::new("B"), Box::new("C"), Box::new("D")]
+      .intersperse_clone_into(Box::new("Z"));
+  assert_eq!(
+    v,
+    [Box::new("A"), Box::new("Z"), Box::new("B"), Box::new("Z"), Box::new("C"), Box::new("Z"), Box::new("D")]
+  );
+  let v2: StaticVec<Box<&str>, 1> = staticvec![Box::new("")].intersperse_clone_into(Box::new("B"));
+  assert_eq!(v2, [Box::new("")]);
+  let v3: StaticVec<Box<&str>, 3> = staticvec![Box::new("A")].intersperse_clone_into(Box::new("B"));
+  assert_eq!(v3, [Box::new("A")]);
+  let mut x = staticvec![Box::new("A")];
+  x.clear();
+  let v4: StaticVec<Box<&str>, 0> = x.intersperse_clone_into(Box::new("B"));
+  assert_eq!(v4, StaticVec::<Box<&str>, 0>::new());
+}
+
+#[test]
+#[should_panic]
+fn intersperse_clone_into_panic() {
+  let _v: StaticVec<Box<&str>, 2> =
+    staticvec![Box::new("A"), Box::new("B")].intersperse_clone_into(Box::new("Z"));
+}
+
 #[test]
 fn is_empty() {
   let mut v = StaticVec::<i32, 1>::new();
@@ -1077,6 +2239,17 @@ fn iter() {
   }
 }
 
+#[test]
+fn iter_as_slice_lifetime() {
+  let v = staticvec![Box::new(1), Box::new(2), Box::new(3), Box::new(4), Box::new(5)];
+  let mut it3 = v.iter();
+  it3.next();
+  let s = it3.as_slice();
+  it3.next();
+  drop(it3);
+  assert_eq!(s, [Box::new(2), Box::new(3), Box::new(4), Box::new(5)]);
+}
+
 #[test]
 fn iter_nth() {
   let v3 = staticvec![ZST {}, ZST {}, ZST {}, ZST {}];
@@ -1246,6 +2419,21 @@ fn iter_nth_back2() {
   assert_eq!(v.iter().nth_back(v.len()), None);
 }
 
+#[test]
+fn iter_rev() {
+  let v = staticvec![Box::new(1), Box::new(2), Box::new(3)];
+  let r: StaticVec<&Box<i32>, 3> = v.iter_rev().collect();
+  assert_eq!(r, [&Box::new(3), &Box::new(2), &Box::new(1)]);
+  let mut it = v.iter_rev();
+  assert_eq!(it.len(), 3);
+  assert_eq!(it.next_back(), Some(&Box::new(1)));
+  assert_eq!(it.next(), Some(&Box::new(3)));
+  assert_eq!(it.len(), 1);
+  let v2 = staticvec![ZST {}, ZST {}, ZST {}, ZST {}];
+  assert_eq!(v2.iter_rev().count(), 4);
+  assert_eq!(StaticVec::<i32, 4>::new().iter_rev().next(), None);
+}
+
 #[test]
 fn iter_rev_nth() {
   let v = staticvec![0, 1, 2, 3, 4];
@@ -1328,6 +2516,29 @@ fn iter_mut() {
   }
 }
 
+#[test]
+fn iter_mut_into_slice() {
+  let mut v = staticvec![Box::new(1), Box::new(2), Box::new(3), Box::new(4), Box::new(5)];
+  let mut it = v.iter_mut();
+  it.next();
+  it.next();
+  let s = it.into_slice();
+  assert_eq!(s, [Box::new(3), Box::new(4), Box::new(5)]);
+  *s[0] = 30;
+  s[2] = Box::new(50);
+  assert_eq!(v, [Box::new(1), Box::new(2), Box::new(30), Box::new(4), Box::new(50)]);
+  let mut v2 = staticvec![ZST {}, ZST {}, ZST {}, ZST {}];
+  let mut it2 = v2.iter_mut();
+  it2.next();
+  it2.next_back();
+  assert_eq!(it2.into_slice().len(), 2);
+  let mut v3 = staticvec![1, 2];
+  let mut it3 = v3.iter_mut();
+  it3.next();
+  it3.next();
+  assert!(it3.into_slice().is_empty());
+}
+
 #[test]
 fn iter_mut_nth() {
   let mut v3 = staticvec![ZST {}, ZST {}, ZST {}, ZST {}];
@@ -1475,6 +2686,57 @@ fn iter_mut_nth_back() {
   assert_eq!(format!("{:?}", it7), "StaticVecIterMut([])");
 }
 
+#[test]
+fn into_batches() {
+  let v = staticvec![1, 2, 3, 4, 5, 6, 7];
+  let batches: StaticVec<StaticVec<i32, 3>, 3> = v.into_batches();
+  assert_eq!(batches.len(), 3);
+  assert_eq!(batches[0], [1, 2, 3]);
+  assert_eq!(batches[1], [4, 5, 6]);
+  assert_eq!(batches[2], [7]);
+  let v2 = staticvec![1, 2, 3, 4, 5, 6];
+  let batches2: StaticVec<StaticVec<i32, 3>, 2> = v2.into_batches();
+  assert_eq!(batches2, [staticvec![1, 2, 3], staticvec![4, 5, 6]]);
+  let batches3: StaticVec<StaticVec<i32, 3>, 0> = StaticVec::<i32, 4>::new().into_batches();
+  assert!(batches3.is_empty());
+  let count = LifespanCounter::default();
+  let v4 = staticvec![count.instance(), count.instance(), count.instance(), count.instance()];
+  let batches4: StaticVec<StaticVec<_, 3>, 2> = v4.into_batches();
+  assert_eq!(batches4[0].len(), 3);
+  assert_eq!(batches4[1].len(), 1);
+  assert_eq!(count.drop_count(), 0);
+  drop(batches4);
+  assert_eq!(count.drop_count(), 4);
+}
+
+#[test]
+#[should_panic(expected = "Insufficient capacity in `StaticVec::into_batches`!")]
+fn into_batches_panic() {
+  let v = staticvec![1, 2, 3, 4, 5, 6, 7];
+  let _batches: StaticVec<StaticVec<i32, 3>, 2> = v.into_batches();
+}
+
+#[test]
+fn into_empty_of() {
+  let mut v = staticvec![1u32, 2, 3, 4];
+  v.clear();
+  let mut v2: StaticVec<i32, 4> = v.into_empty_of();
+  assert!(v2.is_empty());
+  assert_eq!(v2.capacity(), 4);
+  v2.push(-1);
+  v2.push(-2);
+  assert_eq!(v2, [-1, -2]);
+  let count = LifespanCounter::default();
+  let v3 = staticvec![count.instance(), count.instance(), count.instance()];
+  let v4 = v3.into_empty_of::<usize>();
+  assert!(v4.is_empty());
+  assert_eq!(count.drop_count(), 3);
+  let v5 = staticvec![Box::new(1), Box::new(2)];
+  let mut v6 = v5.into_empty_of::<&i32>();
+  v6.push(&7);
+  assert_eq!(v6, [&7]);
+}
+
 #[test]
 fn into_inner() {
   // Someone ELI5 why "box syntax" isn't more widely used... If I'd have known about it sooner I'd
@@ -1617,6 +2879,70 @@ fn into_iter() {
   assert_eq!(i, 16);
 }
 
+#[test]
+fn into_iter_as_slice_interleaved() {
+  let mut it = staticvec![1, 2, 3, 4, 5, 6].into_iter();
+  assert_eq!(it.next(), Some(1));
+  assert_eq!(it.as_slice(), [2, 3, 4, 5, 6]);
+  assert_eq!(it.next_back(), Some(6));
+  assert_eq!(it.as_slice(), [2, 3, 4, 5]);
+  it.as_mut_slice()[0] = 20;
+  assert_eq!(it.next(), Some(20));
+  assert_eq!(it.next_back(), Some(5));
+  assert_eq!(it.as_mut_slice(), [3, 4]);
+  assert_eq!(it.next_back(), Some(4));
+  assert_eq!(it.next(), Some(3));
+  assert!(it.as_slice().is_empty());
+  assert!(it.as_mut_slice().is_empty());
+  static DROPS: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+  struct DropZST;
+  impl Drop for DropZST {
+    fn drop(&mut self) {
+      DROPS.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+    }
+  }
+  let mut it2 = staticvec![DropZST, DropZST, DropZST, DropZST, DropZST].into_iter();
+  assert_eq!(it2.as_slice().len(), 5);
+  it2.next();
+  assert_eq!(it2.as_slice().len(), 4);
+  assert_eq!(it2.as_mut_slice().len(), 4);
+  it2.next_back();
+  assert_eq!(it2.as_slice().len(), 3);
+  assert_eq!(it2.as_mut_slice().len(), 3);
+  it2.next();
+  it2.next_back();
+  assert_eq!(it2.as_slice().len(), 1);
+  assert_eq!(it2.len(), 1);
+  assert_eq!(DROPS.load(std::sync::atomic::Ordering::Relaxed), 4);
+  drop(it2);
+  assert_eq!(DROPS.load(std::sync::atomic::Ordering::Relaxed), 5);
+}
+
+#[test]
+fn into_iter_default() {
+  struct Holder {
+    iter: StaticVecIntoIter<Box<i32>, 4>,
+  }
+  let mut it = StaticVecIntoIter::<Box<i32>, 4>::default();
+  assert_eq!(it.len(), 0);
+  assert_eq!(it.as_slice(), []);
+  assert_eq!(it.next(), None);
+  assert_eq!(it.next_back(), None);
+  let mut holder = Holder {
+    iter: staticvec![Box::new(1), Box::new(2), Box::new(3), Box::new(4)].into_iter(),
+  };
+  holder.iter.next();
+  let mut taken = core::mem::take(&mut holder.iter);
+  assert_eq!(holder.iter.len(), 0);
+  assert_eq!(holder.iter.next(), None);
+  assert_eq!(taken.next(), Some(Box::new(2)));
+  assert_eq!(taken.as_slice(), [Box::new(3), Box::new(4)]);
+  let taken_again = core::mem::take(&mut holder.iter);
+  assert_eq!(taken_again.count(), 0);
+  let zst_it = StaticVecIntoIter::<ZST, 8>::default();
+  assert_eq!(zst_it.count(), 0);
+}
+
 #[test]
 fn into_iter_nth() {
   let v3 = staticvec![ZST {}, ZST {}, ZST {}, ZST {}];
@@ -1775,6 +3101,61 @@ fn into_iter_nth_back() {
   assert_eq!(format!("{:?}", it7), "StaticVecIntoIter([])");
 }
 
+#[test]
+fn into_iter_rev() {
+  let v = staticvec![Box::new(1), Box::new(2), Box::new(3), Box::new(4)];
+  let mut it = v.into_iter_rev();
+  assert_eq!(it.next(), Some(Box::new(4)));
+  assert_eq!(it.next_back(), Some(Box::new(1)));
+  // We stop here so Miri can make sure the remaining values are dropped properly.
+  assert_eq!(it.len(), 2);
+  let v2 = staticvec![ZST {}, ZST {}, ZST {}];
+  assert_eq!(v2.into_iter_rev().count(), 3);
+}
+
+#[test]
+fn into_raw_parts() {
+  let v = staticvec![Box::new(1), Box::new(2), Box::new(3)];
+  let (data, length) = v.into_raw_parts();
+  assert_eq!(length, 3);
+  // Miri will catch any double drops or leaks from the round trip here.
+  let mut v2 = unsafe { StaticVec::from_raw_parts(data, length) };
+  assert_eq!(v2, [Box::new(1), Box::new(2), Box::new(3)]);
+  v2.push(Box::new(4));
+  assert!(v2.is_full());
+  let (data2, length2) = StaticVec::<Box<i32>, 8>::new().into_raw_parts();
+  let v3 = unsafe { StaticVec::from_raw_parts(data2, length2) };
+  assert!(v3.is_empty());
+  assert_eq!(v3.capacity(), 8);
+  let count = LifespanCounter::default();
+  let v4 = staticvec![count.instance(), count.instance()];
+  let (data3, length3) = v4.into_raw_parts();
+  assert_eq!(count.drop_count(), 0);
+  drop(unsafe { StaticVec::from_raw_parts(data3, length3) });
+  assert_eq!(count.drop_count(), 2);
+}
+
+#[test]
+fn into_sorted_iter() {
+  let v = staticvec![Box::new(4), Box::new(6), Box::new(1), Box::new(5), Box::new(3), Box::new(2)];
+  let mut it = v.clone().into_sorted_iter();
+  assert_eq!(it.len(), 6);
+  let first_three = it.by_ref().take(3).collect::<StaticVec<Box<i32>, 3>>();
+  assert_eq!(first_three, [Box::new(1), Box::new(2), Box::new(3)]);
+  assert_eq!(it.len(), 3);
+  assert_eq!(it.next(), Some(Box::new(4)));
+  drop(it);
+  assert_eq!(
+    v.into_sorted_iter().collect::<StaticVec<Box<i32>, 6>>(),
+    [Box::new(1), Box::new(2), Box::new(3), Box::new(4), Box::new(5), Box::new(6)]
+  );
+  let v2 = staticvec![3, 1, 3, 2, 1];
+  assert_eq!(v2.into_sorted_iter().collect::<StaticVec<i32, 5>>(), [1, 1, 2, 3, 3]);
+  let mut it2 = StaticVec::<i32, 4>::new().into_sorted_iter();
+  assert!(it2.is_empty());
+  assert_eq!(it2.next(), None);
+}
+
 #[cfg(feature = "std")]
 #[test]
 fn into_vec() {
@@ -1788,6 +3169,84 @@ fn into_vec() {
   assert_eq!(vv.len(), 3);
 }
 
+#[test]
+fn join_into() {
+  let v = staticvec!["alpha", "beta", "gamma"];
+  let s: StaticString<16> = v.join_into(",");
+  assert_eq!(s, "alpha,beta,gamma");
+  let s2: StaticString<16> = v.join_into("");
+  assert_eq!(s2, "alphabetagamma");
+  let s3: StaticString<4> = staticvec!["x"].join_into(", ");
+  assert_eq!(s3, "x");
+  let s4: StaticString<4> = StaticVec::<&str, 4>::new().join_into(", ");
+  assert_eq!(s4, "");
+  #[cfg(feature = "std")]
+  {
+    let v2 = staticvec![String::from("a"), String::from("b")];
+    let s5: StaticString<3> = v2.join_into("-");
+    assert_eq!(s5, "a-b");
+  }
+}
+
+#[test]
+#[should_panic]
+fn join_into_panic() {
+  let _s: StaticString<15> = staticvec!["alpha", "beta", "gamma"].join_into(",");
+}
+
+#[test]
+fn keep_first() {
+  let mut v = staticvec![Box::new(1), Box::new(2), Box::new(3), Box::new(4)];
+  v.keep_first(4);
+  assert_eq!(v, [Box::new(1), Box::new(2), Box::new(3), Box::new(4)]);
+  v.keep_first(2);
+  assert_eq!(v, [Box::new(1), Box::new(2)]);
+  v.keep_first(0);
+  assert_eq!(v, []);
+}
+
+#[test]
+#[should_panic(expected = "Bounds check failure in `StaticVec::keep_first`!")]
+fn keep_first_panic() {
+  let mut v = staticvec![1, 2, 3];
+  v.keep_first(4);
+}
+
+#[test]
+fn keep_last() {
+  let mut v = staticvec![Box::new(1), Box::new(2), Box::new(3), Box::new(4), Box::new(5)];
+  v.keep_last(5);
+  assert_eq!(v, [Box::new(1), Box::new(2), Box::new(3), Box::new(4), Box::new(5)]);
+  v.keep_last(3);
+  assert_eq!(v, [Box::new(3), Box::new(4), Box::new(5)]);
+  v.push(Box::new(6));
+  assert_eq!(v, [Box::new(3), Box::new(4), Box::new(5), Box::new(6)]);
+  v.keep_last(0);
+  assert_eq!(v, []);
+  let count = LifespanCounter::default();
+  let mut v2 = staticvec![
+    count.instance(),
+    count.instance(),
+    count.instance(),
+    count.instance()
+  ];
+  v2.keep_last(1);
+  assert_eq!(v2.len(), 1);
+  assert_eq!(count.drop_count(), 3);
+  drop(v2);
+  assert_eq!(count.drop_count(), 4);
+  let mut v3 = staticvec![ZST {}, ZST {}, ZST {}];
+  v3.keep_last(2);
+  assert_eq!(v3.len(), 2);
+}
+
+#[test]
+#[should_panic(expected = "Bounds check failure in `StaticVec::keep_last`!")]
+fn keep_last_panic() {
+  let mut v = staticvec![1, 2, 3];
+  v.keep_last(4);
+}
+
 #[test]
 fn last() {
   let v = staticvec![1, 2, 3];
@@ -1833,6 +3292,120 @@ fn math_functions() {
   assert_eq!(A.subtracted(&B), [2.0, 2.0, 2.0, 2.0]);
   assert_eq!(A.multiplied(&B), [8.0, 15.0, 24.0, 35.0]);
   assert_eq!(A.divided(&B), [2.0, 1.6666666666666667, 1.5, 1.4]);
+  let c = StaticVec::<f64, 4>::new_from_slice(&[1.0, 2.0]);
+  assert_eq!(A.added(&c), [5.0, 7.0]);
+  assert_eq!(c.subtracted(&A), [-3.0, -3.0]);
+  assert_eq!(A.multiplied(&c), [4.0, 10.0]);
+  assert_eq!(A.divided(&c), [4.0, 2.5]);
+  assert_eq!(A.added(&StaticVec::new()), []);
+  assert_eq!(StaticVec::<i32, 4>::new().multiplied(&staticvec![1, 2, 3, 4]), []);
+}
+
+#[test]
+fn mean() {
+  assert_eq!(staticvec![2, 4, 4, 4, 5, 5, 7, 9].mean(), Some(5.0));
+  assert_eq!(staticvec![1.5f32, 2.5].mean(), Some(2.0));
+  assert_eq!(staticvec![-3i8].mean(), Some(-3.0));
+  assert_eq!(staticvec![u32::MAX, u32::MAX].mean(), Some(u32::MAX as f64));
+  assert_eq!(StaticVec::<f64, 4>::new().mean(), None);
+}
+
+#[test]
+fn merge_sorted_into() {
+  let a = staticvec![1, 3, 3, 5, 10];
+  let b = staticvec![2, 3, 4, 11, 12];
+  let v: StaticVec<i32, 10> = a.merge_sorted_into(&b);
+  assert_eq!(v, [1, 2, 3, 3, 3, 4, 5, 10, 11, 12]);
+  let v2: StaticVec<i32, 5> = a.merge_sorted_into(&StaticVec::<i32, 4>::new());
+  assert_eq!(v2, a);
+  let v3: StaticVec<i32, 5> = StaticVec::<i32, 4>::new().merge_sorted_into(&b);
+  assert_eq!(v3, b);
+  // Equal elements from `self` come before those from `other`.
+  let c = staticvec![(1, 'a'), (2, 'a')];
+  let d = staticvec![(1, 'b'), (2, 'b')];
+  let v4: StaticVec<(i32, char), 4> = c.merge_sorted_into(&d);
+  assert_eq!(v4, [(1, 'a'), (1, 'b'), (2, 'a'), (2, 'b')]);
+}
+
+#[test]
+#[should_panic(expected = "Insufficient capacity in `StaticVec::merge_sorted_into`!")]
+fn merge_sorted_into_panic() {
+  let _v: StaticVec<i32, 4> = staticvec![1, 2, 3].merge_sorted_into(&staticvec![4, 5]);
+}
+
+#[test]
+fn merge_sorted_clone_into() {
+  let a = staticvec![String::from("a"), String::from("c"), String::from("c")];
+  let b = staticvec![String::from("b"), String::from("c"), String::from("d")];
+  let v: StaticVec<String, 6> = a.merge_sorted_clone_into(&b);
+  assert_eq!(v, ["a", "b", "c", "c", "c", "d"]);
+  let v2: StaticVec<String, 3> = StaticVec::<String, 2>::new().merge_sorted_clone_into(&a);
+  assert_eq!(v2, a);
+  let v3: StaticVec<String, 3> = a.merge_sorted_clone_into(&StaticVec::<String, 2>::new());
+  assert_eq!(v3, a);
+}
+
+#[test]
+#[should_panic(expected = "Insufficient capacity in `StaticVec::merge_sorted_clone_into`!")]
+fn merge_sorted_clone_into_panic() {
+  let _v: StaticVec<Box<i32>, 2> =
+    staticvec![Box::new(1), Box::new(2)].merge_sorted_clone_into(&staticvec![Box::new(3)]);
+}
+
+#[test]
+fn min_max_element() {
+  let v = staticvec![Box::new(3), Box::new(1), Box::new(4), Box::new(1), Box::new(5)];
+  assert_eq!(v.min_element(), Some(&Box::new(1)));
+  assert_eq!(v.max_element(), Some(&Box::new(5)));
+  let v2 = StaticVec::<Box<i32>, 4>::new();
+  assert_eq!(v2.min_element(), None);
+  assert_eq!(v2.max_element(), None);
+  let v3 = staticvec![Box::new(7)];
+  assert_eq!(v3.min_element(), v3.max_element());
+  // `Ord::max` remains usable through method call syntax on StaticVecs themselves.
+  assert_eq!(staticvec![1, 2].max(staticvec![1, 3]), [1, 3]);
+}
+
+#[test]
+fn move_to_back() {
+  let mut v = staticvec![Box::new(1), Box::new(2), Box::new(3), Box::new(4)];
+  v.move_to_back(1);
+  assert_eq!(v, [Box::new(1), Box::new(3), Box::new(4), Box::new(2)]);
+  v.move_to_back(3);
+  assert_eq!(v, [Box::new(1), Box::new(3), Box::new(4), Box::new(2)]);
+  v.move_to_back(0);
+  assert_eq!(v, [Box::new(3), Box::new(4), Box::new(2), Box::new(1)]);
+  let mut v2 = staticvec![ZST {}, ZST {}];
+  v2.move_to_back(0);
+  assert_eq!(v2.len(), 2);
+}
+
+#[test]
+#[should_panic(expected = "Bounds check failure in `StaticVec::move_to_back`!")]
+fn move_to_back_panic() {
+  let mut v = staticvec![1, 2, 3];
+  v.move_to_back(3);
+}
+
+#[test]
+fn move_to_front() {
+  let mut v = staticvec![Box::new(1), Box::new(2), Box::new(3), Box::new(4)];
+  v.move_to_front(2);
+  assert_eq!(v, [Box::new(3), Box::new(1), Box::new(2), Box::new(4)]);
+  v.move_to_front(0);
+  assert_eq!(v, [Box::new(3), Box::new(1), Box::new(2), Box::new(4)]);
+  v.move_to_front(3);
+  assert_eq!(v, [Box::new(4), Box::new(3), Box::new(1), Box::new(2)]);
+  let mut v2 = staticvec![ZST {}, ZST {}];
+  v2.move_to_front(1);
+  assert_eq!(v2.len(), 2);
+}
+
+#[test]
+#[should_panic(expected = "Bounds check failure in `StaticVec::move_to_front`!")]
+fn move_to_front_panic() {
+  let mut v = StaticVec::<i32, 4>::new();
+  v.move_to_front(0);
 }
 
 #[test]
@@ -1922,6 +3495,63 @@ fn new_from_slice() {
   assert_eq!(vec4, []);
 }
 
+#[test]
+fn nth_smallest() {
+  let mut v = staticvec![Box::new(5), Box::new(9), Box::new(1), Box::new(7), Box::new(3)];
+  let median = v.nth_smallest(2);
+  assert_eq!(**median, 5);
+  **median = 6;
+  assert!(v[..2].iter().all(|x| **x < 6));
+  assert_eq!(v[2], Box::new(6));
+  assert!(v[3..].iter().all(|x| **x > 6));
+  let mut v2 = staticvec![4, 4, 2, 4, 8, 1, 4];
+  assert_eq!(*v2.nth_smallest(0), 1);
+  assert_eq!(*v2.nth_smallest(6), 8);
+  assert_eq!(*v2.nth_smallest(3), 4);
+  assert!(v2[..3].iter().all(|x| *x <= 4));
+  assert!(v2[4..].iter().all(|x| *x >= 4));
+  let mut sorted = v2.clone();
+  sorted.sort();
+  assert_eq!(sorted, [1, 2, 4, 4, 4, 4, 8]);
+}
+
+#[test]
+#[should_panic(expected = "Bounds check failure in `StaticVec::nth_smallest`!")]
+fn nth_smallest_panic() {
+  let mut v = staticvec![1, 2, 3];
+  v.nth_smallest(3);
+}
+
+#[test]
+fn ord() {
+  use core::cmp::Ordering;
+  assert_eq!(staticvec![1, 2, 3].cmp(&staticvec![1, 2, 3]), Ordering::Equal);
+  assert_eq!(staticvec![1, 2, 3].cmp(&staticvec![1, 2, 4]), Ordering::Less);
+  assert_eq!(staticvec![1, 3, 0].cmp(&staticvec![1, 2, 4]), Ordering::Greater);
+  let a = StaticVec::<i32, 8>::from([1, 2]);
+  let b = StaticVec::<i32, 8>::from([1, 2, 0]);
+  assert_eq!(a.cmp(&b), Ordering::Less);
+  assert_eq!(b.cmp(&a), Ordering::Greater);
+  let empty = StaticVec::<i32, 8>::new();
+  assert_eq!(empty.cmp(&empty), Ordering::Equal);
+  assert_eq!(empty.cmp(&a), Ordering::Less);
+  assert_eq!(a.cmp(&empty), Ordering::Greater);
+  assert_eq!(core::cmp::max(a.clone(), b.clone()), b);
+  assert_eq!(staticvec![ZST {}, ZST {}].cmp(&staticvec![ZST {}, ZST {}]), Ordering::Equal);
+  let z1 = StaticVec::<ZST, 4>::from([ZST {}]);
+  let z2 = StaticVec::<ZST, 4>::from([ZST {}, ZST {}]);
+  assert_eq!(z1.cmp(&z2), Ordering::Less);
+  assert_eq!(StaticVec::<ZST, 4>::new().cmp(&z1), Ordering::Less);
+  let mut sorted = staticvec![
+    staticvec![2, 1],
+    staticvec![1, 2],
+    staticvec![1, 0],
+    staticvec![0, 9]
+  ];
+  sorted.sort();
+  assert_eq!(sorted, [[0, 9], [1, 0], [1, 2], [2, 1]]);
+}
+
 #[test]
 fn partial_eq() {
   assert_eq!(StaticVec::<i32, 0>::new(), [0; 0]);
@@ -2005,6 +3635,60 @@ fn partial_ord() {
   assert!(staticvec![vec![1.23]] <= &staticvec![vec![2.33]]);
   assert!(staticvec![vec![1.23]] >= &[]);
   assert!(staticvec![vec![1.23]] > &mut []);
+  // Comparisons across differing capacities.
+  let a = StaticVec::<i32, 4>::from([1, 2]);
+  let b = StaticVec::<i32, 16>::from([1, 2, 3]);
+  let c = StaticVec::<i32, 2>::from([1, 3]);
+  assert!(a < b);
+  assert!(b > a);
+  assert!(b < c);
+  assert!(c > a);
+  assert_eq!(a.partial_cmp(&StaticVec::<i32, 9>::from([1, 2])), Some(core::cmp::Ordering::Equal));
+  let empty = StaticVec::<i32, 0>::new();
+  assert!(empty < a);
+  assert!(a > empty);
+  assert!(empty <= StaticVec::<i32, 7>::new());
+  assert!(empty >= StaticVec::<i32, 7>::new());
+  assert_eq!(staticvec![f64::NAN].partial_cmp(&StaticVec::<f64, 4>::from([1.0])), None);
+  let z1 = StaticVec::<ZST, 2>::from([ZST {}]);
+  let z2 = StaticVec::<ZST, 8>::from([ZST {}, ZST {}]);
+  assert!(z1 < z2);
+  assert!(StaticVec::<ZST, 0>::new() < z1);
+}
+
+#[test]
+fn partition() {
+  let v = staticvec![1, 2, 3, 4, 5, 6, 7];
+  let (evens, odds) = v.partition(|x| *x % 2 == 0);
+  assert_eq!(evens, [2, 4, 6]);
+  assert_eq!(odds, [1, 3, 5, 7]);
+  assert_eq!(evens.capacity(), 7);
+  let v2 = staticvec![Box::new(1), Box::new(2), Box::new(3)];
+  let (all, none) = v2.partition(|_| true);
+  assert_eq!(all, [Box::new(1), Box::new(2), Box::new(3)]);
+  assert_eq!(none, []);
+  let (a, b) = StaticVec::<Box<i32>, 4>::new().partition(|_| true);
+  assert!(a.is_empty() && b.is_empty());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn partition_panic() {
+  let counter = LifespanCounter::default();
+  let v = StaticVec::<LifespanCountingInstance, 6>::filled_with(|| counter.instance());
+  let mut i = 0;
+  let result = panic::catch_unwind(AssertUnwindSafe(|| {
+    v.partition(|_| {
+      i += 1;
+      if i == 4 {
+        panic!("Panicking in the middle of `partition`!");
+      }
+      i % 2 == 0
+    })
+  }));
+  assert!(result.is_err());
+  assert_eq!(counter.init_count(), 6);
+  assert_eq!(counter.drop_count(), 6);
 }
 
 #[test]
@@ -2019,6 +3703,66 @@ fn pop() {
   assert_eq!(vec.pop(), None);
 }
 
+#[test]
+fn pop_front() {
+  let mut v = staticvec![Box::new(1), Box::new(2), Box::new(3)];
+  assert_eq!(v.pop_front(), Some(Box::new(1)));
+  assert_eq!(v, [Box::new(2), Box::new(3)]);
+  v.push(Box::new(4));
+  assert_eq!(v.pop_front(), Some(Box::new(2)));
+  assert_eq!(v.pop_front(), Some(Box::new(3)));
+  assert_eq!(v.pop_front(), Some(Box::new(4)));
+  assert_eq!(v.pop_front(), None);
+  assert_eq!(v, []);
+  let counter = LifespanCounter::default();
+  let mut v2 = StaticVec::<LifespanCountingInstance, 3>::filled_with(|| counter.instance());
+  drop(v2.pop_front());
+  assert_eq!(counter.drop_count(), 1);
+  drop(v2);
+  assert_eq!(counter.drop_count(), 3);
+}
+
+#[test]
+fn pop_front_unchecked() {
+  let mut v = staticvec![Box::new(1), Box::new(2)];
+  assert_eq!(unsafe { v.pop_front_unchecked() }, Box::new(1));
+  assert_eq!(unsafe { v.pop_front_unchecked() }, Box::new(2));
+  assert_eq!(v, []);
+}
+
+#[test]
+fn position() {
+  let v = staticvec![Box::new(1), Box::new(2), Box::new(3), Box::new(2)];
+  assert_eq!(v.position(|x| **x == 2), Some(1));
+  assert_eq!(v.position(|x| **x == 1), Some(0));
+  assert_eq!(v.position(|x| **x == 4), None);
+  assert_eq!(StaticVec::<i32, 4>::new().position(|_| true), None);
+}
+
+#[test]
+fn position_max_by() {
+  let v = staticvec![0.5f64, -3.0, 9.75, 2.0, 9.75, -7.5];
+  // Ties go to the last occurrence.
+  assert_eq!(v.position_max_by(|a, b| a.partial_cmp(b).unwrap()), Some(4));
+  assert_eq!(v.position_max_by(|a, b| b.partial_cmp(a).unwrap()), Some(5));
+  assert_eq!(v.position_max_by(|a, b| a.abs().partial_cmp(&b.abs()).unwrap()), Some(4));
+  assert_eq!(staticvec![1.0f64].position_max_by(|a, b| a.partial_cmp(b).unwrap()), Some(0));
+  let empty = StaticVec::<f64, 4>::new();
+  assert_eq!(empty.position_max_by(|a, b| a.partial_cmp(b).unwrap()), None);
+}
+
+#[test]
+fn position_min_by() {
+  let v = staticvec![0.5f64, -7.5, 9.75, 2.0, -7.5, 9.75];
+  // Ties go to the first occurrence.
+  assert_eq!(v.position_min_by(|a, b| a.partial_cmp(b).unwrap()), Some(1));
+  assert_eq!(v.position_min_by(|a, b| b.partial_cmp(a).unwrap()), Some(2));
+  assert_eq!(v.position_min_by(|a, b| a.abs().partial_cmp(&b.abs()).unwrap()), Some(0));
+  assert_eq!(staticvec![1.0f64].position_min_by(|a, b| a.partial_cmp(b).unwrap()), Some(0));
+  let empty = StaticVec::<f64, 4>::new();
+  assert_eq!(empty.position_min_by(|a, b| a.partial_cmp(b).unwrap()), None);
+}
+
 #[test]
 fn ptr_at() {
   let v = staticvec![1, 2, 3];
@@ -2049,6 +3793,50 @@ fn push_panic() {
   v.push(Box::new(12));
 }
 
+#[test]
+fn push_array() {
+  let mut v = StaticVec::<Box<i32>, 5>::new();
+  v.push_array([Box::new(1), Box::new(2), Box::new(3)]);
+  assert_eq!(v, [Box::new(1), Box::new(2), Box::new(3)]);
+  v.push_array([]);
+  assert_eq!(v.len(), 3);
+  v.push_array([Box::new(4), Box::new(5)]);
+  assert_eq!(v, [Box::new(1), Box::new(2), Box::new(3), Box::new(4), Box::new(5)]);
+  let count = LifespanCounter::default();
+  let mut v2 = StaticVec::<LifespanCountingInstance, 4>::new();
+  v2.push_array([count.instance(), count.instance(), count.instance()]);
+  assert_eq!(count.drop_count(), 0);
+  drop(v2);
+  assert_eq!(count.drop_count(), 3);
+  let mut v3 = StaticVec::<ZST, 2>::new();
+  v3.push_array([ZST {}, ZST {}]);
+  assert_eq!(v3.len(), 2);
+}
+
+#[test]
+#[should_panic(expected = "Insufficient capacity in `StaticVec::push_array`!")]
+fn push_array_panic() {
+  let mut v = StaticVec::<Box<i32>, 3>::from([Box::new(1)]);
+  v.push_array([Box::new(2), Box::new(3), Box::new(4)]);
+}
+
+#[test]
+fn push_front() {
+  let mut v = StaticVec::<Box<i32>, 4>::new();
+  v.push_front(Box::new(1));
+  v.push_front(Box::new(2));
+  v.push(Box::new(0));
+  v.push_front(Box::new(3));
+  assert_eq!(v, [Box::new(3), Box::new(2), Box::new(1), Box::new(0)]);
+}
+
+#[test]
+#[should_panic]
+fn push_front_panic() {
+  let mut v = staticvec![Box::new(1), Box::new(2)];
+  v.push_front(Box::new(3));
+}
+
 #[test]
 fn quicksorted_unstable() {
   let v: StaticVec<StaticVec<i32, 3>, 2> = staticvec![staticvec![1, 2, 3], staticvec![6, 5, 4]];
@@ -2260,6 +4048,40 @@ mod read_tests {
   }
 }
 
+#[test]
+fn rarray_chunks() {
+  let v = staticvec![1, 2, 3, 4, 5, 6];
+  let mut it = v.rarray_chunks::<2>();
+  assert_eq!(it.len(), 3);
+  assert_eq!(it.remainder(), &[]);
+  assert_eq!(it.next(), Some(&[5, 6]));
+  assert_eq!(it.next_back(), Some(&[1, 2]));
+  assert_eq!(it.len(), 1);
+  assert_eq!(it.next(), Some(&[3, 4]));
+  assert_eq!(it.next(), None);
+  assert_eq!(it.next_back(), None);
+  let v2 = staticvec![1, 2, 3, 4, 5, 6, 7, 8];
+  let mut it2 = v2.rarray_chunks::<3>();
+  assert_eq!(it2.len(), 2);
+  assert_eq!(it2.remainder(), &[1, 2]);
+  assert_eq!(it2.clone().rev().collect::<StaticVec<&[i32; 3], 2>>(), [&[3, 4, 5], &[6, 7, 8]]);
+  assert_eq!(it2.nth(1), Some(&[3, 4, 5]));
+  assert!(it2.is_empty());
+  assert_eq!(it2.remainder(), &[1, 2]);
+  let v3 = staticvec![1, 2];
+  let it3 = v3.rarray_chunks::<4>();
+  assert_eq!(it3.len(), 0);
+  assert_eq!(it3.remainder(), &[1, 2]);
+  assert_eq!(StaticVec::<i32, 4>::new().rarray_chunks::<1>().count(), 0);
+}
+
+#[test]
+#[should_panic(expected = "`StaticVec::rarray_chunks` was called with a chunk size of zero!")]
+fn rarray_chunks_panic() {
+  let v = staticvec![1, 2, 3];
+  let _ = v.rarray_chunks::<0>();
+}
+
 #[test]
 fn remaining_capacity() {
   let mut v = StaticVec::<i32, 3>::new();
@@ -2296,6 +4118,90 @@ fn remove_item() {
   assert_eq!(vec, staticvec![2, 3, 1]);
 }
 
+#[test]
+fn replace_all() {
+  let mut v = staticvec![
+    String::from("a"),
+    String::from("b"),
+    String::from("a"),
+    String::from("a")
+  ];
+  assert_eq!(v.replace_all(&String::from("a"), String::from("z")), 3);
+  assert_eq!(v, ["z", "b", "z", "z"]);
+  assert_eq!(v.replace_all(&String::from("q"), String::from("y")), 0);
+  assert_eq!(v, ["z", "b", "z", "z"]);
+  let mut v2 = StaticVec::<i32, 4>::new();
+  assert_eq!(v2.replace_all(&1, 2), 0);
+}
+
+#[test]
+fn replace_first() {
+  let mut v = staticvec![String::from("a"), String::from("b"), String::from("a")];
+  assert!(v.replace_first(&String::from("a"), String::from("c")));
+  assert_eq!(v, ["c", "b", "a"]);
+  assert!(v.replace_first(&String::from("a"), String::from("d")));
+  assert_eq!(v, ["c", "b", "d"]);
+  assert!(!v.replace_first(&String::from("a"), String::from("e")));
+  assert_eq!(v, ["c", "b", "d"]);
+  let mut v2 = StaticVec::<i32, 4>::new();
+  assert!(!v2.replace_first(&1, 2));
+}
+
+#[test]
+fn replace_range() {
+  let mut v = StaticVec::<Box<i32>, 6>::from([Box::new(1), Box::new(2), Box::new(3), Box::new(4)]);
+  v.replace_range(1..3, [Box::new(5)]);
+  assert_eq!(v, [Box::new(1), Box::new(5), Box::new(4)]);
+  v.replace_range(1..2, [Box::new(6), Box::new(7), Box::new(8)]);
+  assert_eq!(v, [Box::new(1), Box::new(6), Box::new(7), Box::new(8), Box::new(4)]);
+  v.replace_range(.., core::iter::empty());
+  assert_eq!(v, []);
+  v.replace_range(.., (1..4).map(Box::new));
+  assert_eq!(v, [Box::new(1), Box::new(2), Box::new(3)]);
+  // Anything from `replace_with` that doesn't fit is dropped.
+  v.replace_range(3.., (4..10).map(Box::new));
+  assert_eq!(
+    v,
+    [Box::new(1), Box::new(2), Box::new(3), Box::new(4), Box::new(5), Box::new(6)]
+  );
+  assert!(v.is_full());
+  let counter = LifespanCounter::default();
+  let mut v2 = StaticVec::<LifespanCountingInstance, 3>::new();
+  v2.push(counter.instance());
+  v2.push(counter.instance());
+  v2.replace_range(1.., [counter.instance(), counter.instance(), counter.instance()]);
+  assert_eq!(v2.len(), 3);
+  assert_eq!(counter.init_count(), 5);
+  assert_eq!(counter.drop_count(), 2);
+  drop(v2);
+  assert_eq!(counter.drop_count(), 5);
+}
+
+#[test]
+#[should_panic]
+fn replace_range_panic() {
+  let mut v = staticvec![1, 2, 3];
+  v.replace_range(2..4, [1]);
+}
+
+#[test]
+fn reset_to_default_len() {
+  let mut v = staticvec![Box::new(1), Box::new(2), Box::new(3)];
+  v.reset_to_default_len(2);
+  assert_eq!(v, [Box::new(0), Box::new(0)]);
+  v.reset_to_default_len(3);
+  assert_eq!(v, [Box::new(0), Box::new(0), Box::new(0)]);
+  v.reset_to_default_len(0);
+  assert!(v.is_empty());
+}
+
+#[test]
+#[should_panic]
+fn reset_to_default_len_panic() {
+  let mut v = StaticVec::<i32, 4>::new();
+  v.reset_to_default_len(5);
+}
+
 #[test]
 fn retain() {
   let mut vec = staticvec![1, 2, 3, 4, 5];
@@ -2305,6 +4211,108 @@ fn retain() {
   assert_eq!(vec, [2, 3, 5]);
 }
 
+#[test]
+fn retain_counting() {
+  let mut v = staticvec![
+    Box::new(1),
+    Box::new(2),
+    Box::new(3),
+    Box::new(4),
+    Box::new(5),
+    Box::new(6)
+  ];
+  let old_length = v.len();
+  let removed = v.retain_counting(|x| **x % 3 != 0 && **x != 1);
+  assert_eq!(removed, 3);
+  assert_eq!(removed, old_length - v.len());
+  assert_eq!(v, [Box::new(2), Box::new(4), Box::new(5)]);
+  assert_eq!(v.retain_counting(|_| true), 0);
+  assert_eq!(v.retain_counting(|_| false), 3);
+  assert!(v.is_empty());
+  assert_eq!(v.retain_counting(|_| false), 0);
+  let count = LifespanCounter::default();
+  let mut v2 = staticvec![count.instance(), count.instance(), count.instance()];
+  let mut i = 0;
+  assert_eq!(v2.retain_counting(|_| (i != 1, i += 1).0), 1);
+  assert_eq!(count.drop_count(), 1);
+  drop(v2);
+  assert_eq!(count.drop_count(), 3);
+}
+
+#[test]
+fn retain_range() {
+  let mut v = staticvec![1, 2, 3, 4, 5, 6, 7, 8];
+  v.retain_range(2..6, |x| *x % 2 == 0);
+  assert_eq!(v, [1, 2, 4, 6, 7, 8]);
+  v.retain_range(.., |x| *x > 1);
+  assert_eq!(v, [2, 4, 6, 7, 8]);
+  v.retain_range(3..3, |_| false);
+  assert_eq!(v, [2, 4, 6, 7, 8]);
+  v.retain_range(..=1, |_| false);
+  assert_eq!(v, [6, 7, 8]);
+  let mut v2 = staticvec![
+    Box::new(1),
+    Box::new(3),
+    Box::new(5),
+    Box::new(6),
+    Box::new(7),
+    Box::new(9)
+  ];
+  v2.retain_range(1..5, |x| **x > 5);
+  assert_eq!(v2, [Box::new(1), Box::new(6), Box::new(7), Box::new(9)]);
+  v2.retain_range(2.., |_| false);
+  assert_eq!(v2, [Box::new(1), Box::new(6)]);
+  let counter = LifespanCounter::default();
+  let mut v3 = StaticVec::<LifespanCountingInstance, 6>::filled_with(|| counter.instance());
+  let mut i = 0;
+  v3.retain_range(1..5, |_| (i % 2 == 0, i += 1).0);
+  assert_eq!(v3.len(), 4);
+  assert_eq!(counter.drop_count(), 2);
+  drop(v3);
+  assert_eq!(counter.drop_count(), 6);
+}
+
+#[test]
+#[should_panic]
+fn retain_range_panic() {
+  let mut v = staticvec![1, 2, 3];
+  v.retain_range(2..4, |_| true);
+}
+
+#[test]
+#[should_panic(expected = "Bounds check failure in `StaticVec::retain_range`!")]
+fn retain_range_overflow_panic() {
+  let mut v = staticvec![1, 2, 3];
+  v.retain_range(..=usize::MAX, |_| true);
+}
+
+#[test]
+fn reverse_range() {
+  let mut v = staticvec![Box::new(1), Box::new(2), Box::new(3), Box::new(4), Box::new(5)];
+  v.reverse_range(1..4);
+  assert_eq!(v, [Box::new(1), Box::new(4), Box::new(3), Box::new(2), Box::new(5)]);
+  v.reverse_range(..);
+  assert_eq!(v, [Box::new(5), Box::new(2), Box::new(3), Box::new(4), Box::new(1)]);
+  v.reverse_range(2..2);
+  v.reverse_range(4..=4);
+  assert_eq!(v, [Box::new(5), Box::new(2), Box::new(3), Box::new(4), Box::new(1)]);
+  let mut v2 = staticvec![1, 2, 3, 4, 5, 6];
+  let mut v3 = v2.clone();
+  v2.reverse_range(..);
+  v3.reverse();
+  assert_eq!(v2, v3);
+  let mut v4 = staticvec![ZST {}, ZST {}, ZST {}];
+  v4.reverse_range(0..3);
+  assert_eq!(v4.len(), 3);
+}
+
+#[test]
+#[should_panic]
+fn reverse_range_panic() {
+  let mut v = staticvec![1, 2, 3];
+  v.reverse_range(1..4);
+}
+
 #[test]
 fn reversed() {
   let v = staticvec![1, 2, 3].reversed();
@@ -2322,6 +4330,104 @@ fn reversed() {
   );
 }
 
+#[test]
+fn rle_into() {
+  let v = staticvec![1, 1, 2, 3, 3, 3];
+  let runs: StaticVec<(i32, usize), 3> = v.rle_into();
+  assert_eq!(runs, [(1, 2), (2, 1), (3, 3)]);
+  let v2 = staticvec![Box::new(1), Box::new(2), Box::new(3)];
+  let runs2: StaticVec<(Box<i32>, usize), 4> = v2.rle_into();
+  assert_eq!(runs2, [(Box::new(1), 1), (Box::new(2), 1), (Box::new(3), 1)]);
+  let v3 = staticvec![Box::new(4), Box::new(4), Box::new(4)];
+  let runs3: StaticVec<(Box<i32>, usize), 1> = v3.rle_into();
+  assert_eq!(runs3, [(Box::new(4), 3)]);
+  let runs4: StaticVec<(i32, usize), 0> = StaticVec::<i32, 4>::new().rle_into();
+  assert_eq!(runs4, []);
+}
+
+#[test]
+#[should_panic]
+fn rle_into_panic() {
+  let v = staticvec![1, 1, 2, 3, 3, 3];
+  let _runs: StaticVec<(i32, usize), 2> = v.rle_into();
+}
+
+#[test]
+fn rolling_push() {
+  let mut v = StaticVec::<Box<i32>, 3>::new();
+  assert_eq!(v.rolling_push(Box::new(1)), None);
+  assert_eq!(v.rolling_push(Box::new(2)), None);
+  assert_eq!(v.rolling_push(Box::new(3)), None);
+  assert!(v.is_full());
+  assert_eq!(v.rolling_push(Box::new(4)), Some(Box::new(1)));
+  assert_eq!(v.rolling_push(Box::new(5)), Some(Box::new(2)));
+  assert_eq!(v.rolling_push(Box::new(6)), Some(Box::new(3)));
+  assert_eq!(v.rolling_push(Box::new(7)), Some(Box::new(4)));
+  assert_eq!(v, [Box::new(5), Box::new(6), Box::new(7)]);
+  let mut v2 = StaticVec::<Box<i32>, 1>::new();
+  assert_eq!(v2.rolling_push(Box::new(1)), None);
+  assert_eq!(v2.rolling_push(Box::new(2)), Some(Box::new(1)));
+  assert_eq!(v2, [Box::new(2)]);
+  let mut v3 = StaticVec::<Box<i32>, 0>::new();
+  assert_eq!(v3.rolling_push(Box::new(1)), Some(Box::new(1)));
+  assert_eq!(v3, []);
+}
+
+#[test]
+fn rposition() {
+  let v = staticvec![Box::new(2), Box::new(1), Box::new(2), Box::new(3)];
+  assert_eq!(v.rposition(|x| **x == 2), Some(2));
+  assert_eq!(v.rposition(|x| **x == 3), Some(3));
+  assert_eq!(v.rposition(|x| **x == 4), None);
+  assert_eq!(StaticVec::<i32, 4>::new().rposition(|_| true), None);
+}
+
+#[test]
+fn rsplit_once() {
+  let v = StaticVec::<u8, 16>::from(*b"a=b=c");
+  let (before, after) = v.rsplit_once(|&b| b == b'=').unwrap();
+  assert_eq!(before, b"a=b");
+  assert_eq!(after, b"c");
+  assert_eq!(v.rsplit_once(|&b| b == b'a'), Some((&b""[..], &b"=b=c"[..])));
+  assert_eq!(v.rsplit_once(|&b| b == b'c'), Some((&b"a=b="[..], &b""[..])));
+  assert_eq!(v.rsplit_once(|&b| b == b'&'), None);
+  assert_eq!(StaticVec::<u8, 4>::new().rsplit_once(|_| true), None);
+}
+
+#[test]
+fn saturating_arithmetic() {
+  let a = staticvec![i32::MAX - 1, i32::MIN + 1, 2, 3];
+  let b = staticvec![2, 2, 2];
+  assert_eq!(a.added_saturating(&b), [i32::MAX, i32::MIN + 3, 4]);
+  assert_eq!(a.added_saturating(&a), [i32::MAX, i32::MIN, 4, 6]);
+  assert_eq!(a.subtracted_saturating(&b), [i32::MAX - 3, i32::MIN, 0]);
+  assert_eq!(b.subtracted_saturating(&a), [i32::MIN + 4, i32::MAX, 0]);
+  assert_eq!(a.multiplied_saturating(&b), [i32::MAX, i32::MIN, 4]);
+  assert_eq!(staticvec![250u8, 5].added_saturating(&staticvec![10, 5]), [255, 10]);
+  assert_eq!(staticvec![5u8, 5].subtracted_saturating(&staticvec![10, 5]), [0, 0]);
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn shuffle() {
+  use rand::{rngs::StdRng, SeedableRng};
+  let mut v1 = StaticVec::<Box<i32>, 16>::filled_with_by_index(|i| Box::new(i as i32));
+  let mut v2 = v1.clone();
+  v1.shuffle(&mut StdRng::seed_from_u64(1234));
+  v2.shuffle(&mut StdRng::seed_from_u64(1234));
+  // The same seed must always produce the same permutation.
+  assert_eq!(v1, v2);
+  assert_ne!(v1, StaticVec::<Box<i32>, 16>::filled_with_by_index(|i| Box::new(i as i32)));
+  v1.sort_unstable();
+  assert_eq!(v1, StaticVec::<Box<i32>, 16>::filled_with_by_index(|i| Box::new(i as i32)));
+  let mut v3 = staticvec![Box::new(1)];
+  v3.shuffle(&mut StdRng::seed_from_u64(1234));
+  assert_eq!(v3, [Box::new(1)]);
+  let mut v4 = StaticVec::<Box<i32>, 4>::new();
+  v4.shuffle(&mut StdRng::seed_from_u64(1234));
+  assert_eq!(v4, []);
+}
+
 #[test]
 fn size_in_bytes() {
   let x = StaticVec::<u8, 8>::from([1, 2, 3, 4, 5, 6, 7, 8]);
@@ -2342,6 +4448,76 @@ fn set_len() {
   assert_eq!(v.len(), 0);
 }
 
+#[test]
+fn set_len_drop_partial() {
+  // Only the slots within `0..length` are initialized, so `Drop` must never touch the rest. Miri
+  // will flag any attempt to drop the uninitialized `Box`es in the tail.
+  let mut v = StaticVec::<Box<i32>, 5>::new();
+  unsafe {
+    v.mut_ptr_at_unchecked(0).write(Box::new(1));
+    v.mut_ptr_at_unchecked(1).write(Box::new(2));
+    v.set_len(2);
+  }
+  assert_eq!(v, [Box::new(1), Box::new(2)]);
+  drop(v);
+  let count = LifespanCounter::default();
+  let mut v2 = StaticVec::<_, 8>::new();
+  unsafe {
+    for i in 0..3 {
+      v2.mut_ptr_at_unchecked(i).write(count.instance());
+    }
+    v2.set_len(3);
+  }
+  assert_eq!(count.init_count(), 3);
+  drop(v2);
+  assert_eq!(count.drop_count(), 3);
+}
+
+#[test]
+fn set_len_drop_zero() {
+  let count = LifespanCounter::default();
+  let mut v = staticvec![count.instance(), count.instance(), count.instance(), count.instance()];
+  assert!(v.is_full());
+  // After this the StaticVec no longer considers itself to own any of the four instances, so
+  // dropping it must not drop any of them. The instances don't own any heap memory, so simply
+  // leaking them is fine.
+  unsafe { v.set_len(0) };
+  drop(v);
+  assert_eq!(count.init_count(), 4);
+  assert_eq!(count.drop_count(), 0);
+}
+
+#[test]
+fn set_len_filling() {
+  let mut v = StaticVec::<Box<i32>, 6>::from([Box::new(1), Box::new(2)]);
+  v.set_len_filling(5, Box::new(0));
+  assert_eq!(v, [Box::new(1), Box::new(2), Box::new(0), Box::new(0), Box::new(0)]);
+  v.set_len_filling(5, Box::new(9));
+  assert_eq!(v, [Box::new(1), Box::new(2), Box::new(0), Box::new(0), Box::new(0)]);
+  v.set_len_filling(6, Box::new(9));
+  assert_eq!(v.len(), 6);
+  assert_eq!(v[5], Box::new(9));
+  v.set_len_filling(1, Box::new(9));
+  assert_eq!(v, [Box::new(1)]);
+  v.set_len_filling(0, Box::new(9));
+  assert!(v.is_empty());
+  let count = LifespanCounter::default();
+  let mut v2 = StaticVec::<_, 4>::new();
+  v2.set_len_filling(4, count.instance());
+  assert_eq!(count.init_count(), 4);
+  v2.set_len_filling(1, count.instance());
+  assert_eq!(count.drop_count(), 4);
+  drop(v2);
+  assert_eq!(count.drop_count(), 5);
+}
+
+#[test]
+#[should_panic(expected = "Insufficient capacity in `StaticVec::set_len_filling`!")]
+fn set_len_filling_panic() {
+  let mut v = StaticVec::<i32, 4>::new();
+  v.set_len_filling(5, 0);
+}
+
 #[cfg(feature = "std")]
 #[test]
 fn sorted() {
@@ -2463,6 +4639,29 @@ fn splice_single_item_iterator() {
   assert_eq!(v, [Box::new(1), Box::new(1), Box::new(5), Box::new(6)]);
 }
 
+#[test]
+fn splice_size_hint() {
+  let mut v = StaticVec::<Box<i32>, 8>::from([Box::new(1), Box::new(2), Box::new(3), Box::new(4), Box::new(5)]);
+  let mut s = v.splice(1..4, staticvec![Box::new(10)].into_iter());
+  assert_eq!(s.size_hint(), (3, Some(3)));
+  assert_eq!(s.len(), 3);
+  assert_eq!(s.next(), Some(Box::new(2)));
+  assert_eq!(s.size_hint(), (2, Some(2)));
+  assert_eq!(s.len(), 2);
+  assert_eq!(s.next_back(), Some(Box::new(4)));
+  assert_eq!(s.len(), 1);
+  assert_eq!(s.next(), Some(Box::new(3)));
+  assert_eq!(s.len(), 0);
+  assert_eq!(s.next(), None);
+  drop(s);
+  assert_eq!(v, [Box::new(1), Box::new(10), Box::new(5)]);
+  let mut v2 = StaticVec::<i32, 8>::from([1, 2, 3, 4, 5, 6]);
+  let removed: StaticVec<i32, 4> = v2.splice(1..5, [7, 8, 9, 10, 11, 12]).collect();
+  assert_eq!(removed, [2, 3, 4, 5]);
+  assert!(removed.is_full());
+  assert_eq!(v2, [1, 7, 8, 9, 10, 11, 12, 6]);
+}
+
 #[test]
 fn splice_unbounded() {
   let mut vec = staticvec![1, 2, 3, 4, 5];
@@ -2497,6 +4696,47 @@ fn split_at_assert() {
   assert_eq!(t.1, []);
 }
 
+#[test]
+fn split_at_mut() {
+  let mut v = staticvec![Box::new(1), Box::new(2), Box::new(3), Box::new(4)];
+  let (a, b) = v.split_at_mut(1);
+  *a[0] = 10;
+  b[2] = Box::new(40);
+  assert_eq!(v, [Box::new(10), Box::new(2), Box::new(3), Box::new(40)]);
+  let (c, d) = v.split_at_mut(0);
+  assert_eq!(c.len(), 0);
+  assert_eq!(d.len(), 4);
+  let (e, f) = v.split_at_mut(4);
+  assert_eq!(e.len(), 4);
+  assert_eq!(f.len(), 0);
+}
+
+#[test]
+#[should_panic]
+fn split_at_mut_panic() {
+  let mut v = staticvec![1, 2, 3];
+  v.split_at_mut(4);
+}
+
+#[test]
+fn split_at_ref() {
+  let v = staticvec![1, 2, 3, 4, 5];
+  assert_eq!(v.split_at_ref(0), (&[][..], &[1, 2, 3, 4, 5][..]));
+  assert_eq!(v.split_at_ref(3), (&[1, 2, 3][..], &[4, 5][..]));
+  assert_eq!(v.split_at_ref(5), (&[1, 2, 3, 4, 5][..], &[][..]));
+  let v2 = staticvec![ZST {}, ZST {}, ZST {}];
+  let (a, b) = v2.split_at_ref(1);
+  assert_eq!(a.len(), 1);
+  assert_eq!(b.len(), 2);
+}
+
+#[test]
+#[should_panic]
+fn split_at_ref_panic() {
+  let v = staticvec![1, 2, 3];
+  v.split_at_ref(4);
+}
+
 #[test]
 fn split_off() {
   let mut vec = staticvec![1, 2, 3];
@@ -2516,6 +4756,96 @@ fn split_off_assert() {
   assert_eq!(vec3.split_off(9000), []);
 }
 
+#[test]
+fn split_off_into() {
+  let mut v1 = StaticVec::<Box<i32>, 16>::from([Box::new(1), Box::new(2), Box::new(3)]);
+  let v2: StaticVec<Box<i32>, 2> = v1.split_off_into(1);
+  assert_eq!(v1, [Box::new(1)]);
+  assert_eq!(v2, [Box::new(2), Box::new(3)]);
+  assert_eq!(v2.capacity(), 2);
+  let v3: StaticVec<Box<i32>, 0> = v1.split_off_into(1);
+  assert_eq!(v1, [Box::new(1)]);
+  assert_eq!(v3, []);
+  let v4: StaticVec<Box<i32>, 4> = v1.split_off_into(0);
+  assert_eq!(v1, []);
+  assert_eq!(v4, [Box::new(1)]);
+}
+
+#[test]
+#[should_panic]
+fn split_off_into_panic() {
+  let mut v = staticvec![Box::new(1), Box::new(2), Box::new(3)];
+  let _v2: StaticVec<Box<i32>, 1> = v.split_off_into(1);
+}
+
+#[test]
+fn split_off_when() {
+  let mut v = staticvec![Box::new(1), Box::new(2), Box::new(-1), Box::new(3), Box::new(-1)];
+  let v2 = v.split_off_when(|x| **x < 0).unwrap();
+  assert_eq!(v, [Box::new(1), Box::new(2)]);
+  assert_eq!(v2, [Box::new(-1), Box::new(3), Box::new(-1)]);
+  assert_eq!(v2.capacity(), 5);
+  assert_eq!(v.split_off_when(|x| **x < 0), None);
+  assert_eq!(v, [Box::new(1), Box::new(2)]);
+  let v3 = v.split_off_when(|x| **x == 1).unwrap();
+  assert!(v.is_empty());
+  assert_eq!(v3, [Box::new(1), Box::new(2)]);
+  assert_eq!(v.split_off_when(|_| true), None);
+  let mut v4 = staticvec![1, 2, 3];
+  assert_eq!(v4.split_off_when(|x| *x == 3), Some(staticvec![3]));
+  assert_eq!(v4, [1, 2]);
+}
+
+#[test]
+fn split_once() {
+  let v = StaticVec::<u8, 16>::from(*b"key=value=more");
+  let (before, after) = v.split_once(|&b| b == b'=').unwrap();
+  assert_eq!(before, b"key");
+  assert_eq!(after, b"value=more");
+  assert_eq!(v.split_once(|&b| b == b'k'), Some((&b""[..], &b"ey=value=more"[..])));
+  assert_eq!(v.split_once(|&b| b == b'e'), Some((&b"k"[..], &b"y=value=more"[..])));
+  assert_eq!(v.split_once(|&b| b == b'&'), None);
+  assert_eq!(StaticVec::<u8, 4>::new().split_once(|_| true), None);
+  let v2 = staticvec![Box::new(1), Box::new(0), Box::new(2)];
+  assert_eq!(v2.split_once(|x| **x == 0), Some((&v2[..1], &v2[2..])));
+}
+
+#[test]
+fn stride() {
+  let v = staticvec![Box::new(1), Box::new(2), Box::new(3), Box::new(4), Box::new(5)];
+  let s1: StaticVec<&Box<i32>, 5> = v.stride(1).collect();
+  assert_eq!(s1, [&Box::new(1), &Box::new(2), &Box::new(3), &Box::new(4), &Box::new(5)]);
+  let mut s2 = v.stride(2);
+  assert_eq!(s2.len(), 3);
+  assert_eq!(s2.next(), Some(&Box::new(1)));
+  assert_eq!(s2.next_back(), Some(&Box::new(5)));
+  assert_eq!(s2.len(), 1);
+  assert_eq!(s2.next(), Some(&Box::new(3)));
+  assert_eq!(s2.next(), None);
+  let mut s3 = v.stride(10);
+  assert_eq!(s3.len(), 1);
+  assert_eq!(s3.next(), Some(&Box::new(1)));
+  assert_eq!(s3.next(), None);
+  assert_eq!(StaticVec::<i32, 4>::new().stride(2).len(), 0);
+}
+
+#[test]
+#[should_panic]
+fn stride_panic() {
+  let v = staticvec![1, 2, 3];
+  let _ = v.stride(0);
+}
+
+#[test]
+fn sum_product() {
+  assert_eq!(staticvec![4.0, 5.0, 6.0, 7.0].sum(), 22.0);
+  assert_eq!(staticvec![2.0, 3.0, 4.0, 5.0].product(), 120.0);
+  assert_eq!(staticvec![1, 2, 3, 4].sum(), 10);
+  assert_eq!(staticvec![1, 2, 3, 4].product(), 24);
+  assert_eq!(StaticVec::<u32, 4>::new().sum(), 0);
+  assert_eq!(StaticVec::<u32, 4>::new().product(), 1);
+}
+
 #[test]
 fn symmetric_difference() {
   assert_eq!(
@@ -2552,6 +4882,61 @@ fn swap_remove() {
   assert_eq!(v, ["baz", "qux"]);
 }
 
+#[test]
+fn swap_with_slice() {
+  let mut v = staticvec![Box::new(1), Box::new(2), Box::new(3)];
+  let mut a = [Box::new(4), Box::new(5), Box::new(6)];
+  v.swap_with_slice(&mut a);
+  assert_eq!(v, [Box::new(4), Box::new(5), Box::new(6)]);
+  assert_eq!(a, [Box::new(1), Box::new(2), Box::new(3)]);
+  let mut v2 = StaticVec::<Box<i32>, 4>::from([Box::new(7), Box::new(8), Box::new(9)]);
+  v.swap_with_slice(&mut v2);
+  assert_eq!(v, [Box::new(7), Box::new(8), Box::new(9)]);
+  assert_eq!(v2, [Box::new(4), Box::new(5), Box::new(6)]);
+  let mut v3 = StaticVec::<Box<i32>, 4>::new();
+  v3.swap_with_slice(&mut []);
+  assert_eq!(v3, []);
+}
+
+#[test]
+#[should_panic]
+fn swap_with_slice_panic() {
+  let mut v = staticvec![Box::new(1), Box::new(2), Box::new(3)];
+  let mut a = [Box::new(4), Box::new(5)];
+  v.swap_with_slice(&mut a);
+}
+
+#[test]
+fn take_all() {
+  let mut v = staticvec![Box::new(1), Box::new(2), Box::new(3)];
+  let mut v2 = v.take_all();
+  assert!(v.is_empty());
+  assert_eq!(v2, [Box::new(1), Box::new(2), Box::new(3)]);
+  v.push(Box::new(4));
+  v2.push(Box::new(5));
+  assert_eq!(v, [Box::new(4)]);
+  assert_eq!(v2.take_all(), [Box::new(1), Box::new(2), Box::new(3), Box::new(5)]);
+  assert!(v2.is_empty());
+  assert!(StaticVec::<Box<i32>, 4>::new().take_all().is_empty());
+  let count = LifespanCounter::default();
+  let mut v3 = staticvec![count.instance(), count.instance()];
+  let v4 = v3.take_all();
+  assert_eq!(count.drop_count(), 0);
+  drop(v3);
+  assert_eq!(count.drop_count(), 0);
+  drop(v4);
+  assert_eq!(count.drop_count(), 2);
+  #[cfg(feature = "std")]
+  {
+    // Having `Read` in scope doesn't interfere with calling this on a `StaticVec<u8, N>`.
+    #[allow(unused_imports)]
+    use std::io::Read;
+    let mut v5 = staticvec![1u8, 2, 3];
+    assert_eq!(v5.take_all(), [1, 2, 3]);
+    assert!(v5.is_empty());
+  }
+}
+
 #[test]
 fn triple() {
   static V: StaticVec<usize, 4> = staticvec![4, 5, 6, 7];
@@ -2595,6 +4980,76 @@ fn try_extend_from_slice() {
   assert_eq!(w.try_extend_from_slice(&[2]), Ok(()));
 }
 
+#[test]
+fn try_fill_remaining() {
+  // `f` runs dry before the StaticVec fills up.
+  let mut v = StaticVec::<Box<i32>, 6>::from([Box::new(1)]);
+  let mut source = staticvec![Box::new(2), Box::new(3)].into_iter();
+  assert_eq!(v.try_fill_remaining(|| source.next()), 2);
+  assert_eq!(v, [Box::new(1), Box::new(2), Box::new(3)]);
+  // The StaticVec fills up before `f` runs dry, so `f` is only called as many times as needed.
+  let mut calls = 0;
+  assert_eq!(
+    v.try_fill_remaining(|| {
+      calls += 1;
+      Some(Box::new(calls))
+    }),
+    3
+  );
+  assert_eq!(calls, 3);
+  assert_eq!(v, [Box::new(1), Box::new(2), Box::new(3), Box::new(1), Box::new(2), Box::new(3)]);
+  assert_eq!(v.try_fill_remaining(|| unreachable!()), 0);
+  let mut v2 = StaticVec::<i32, 4>::new();
+  assert_eq!(v2.try_fill_remaining(|| None), 0);
+  assert!(v2.is_empty());
+}
+
+#[test]
+fn try_from_byte_slice() {
+  let v = staticvec![1u32, 2, 3, 4];
+  let v2 = StaticVec::<u32, 4>::try_from_byte_slice(v.as_byte_slice()).unwrap();
+  assert_eq!(v2, v);
+  let mut bytes = StaticVec::<u8, 9>::new();
+  bytes.push(0);
+  bytes.extend_from_slice(&0xAABBCCDDu32.to_ne_bytes());
+  bytes.extend_from_slice(&0x11223344u32.to_ne_bytes());
+  // The source bytes are deliberately at an odd offset here, so they're misaligned for `u32`.
+  let v3 = StaticVec::<u32, 2>::try_from_byte_slice(&bytes[1..]).unwrap();
+  assert_eq!(v3, [0xAABBCCDD, 0x11223344]);
+  assert!(StaticVec::<u32, 1>::try_from_byte_slice(&bytes[1..]).is_none());
+  assert!(StaticVec::<u32, 4>::try_from_byte_slice(&bytes[..]).is_none());
+  assert!(StaticVec::<u32, 4>::try_from_byte_slice(&[1, 2, 3]).is_none());
+  assert_eq!(StaticVec::<u32, 4>::try_from_byte_slice(&[]).unwrap(), []);
+  let v4 = StaticVec::<f64, 2>::try_from_byte_slice(&1.5f64.to_ne_bytes()).unwrap();
+  assert_eq!(v4, [1.5]);
+}
+
+#[test]
+fn try_from_slice() {
+  assert_eq!(StaticVec::<i32, 3>::try_from_slice(&[1, 2, 3]), Ok(staticvec![1, 2, 3]));
+  let v = StaticVec::<i32, 8>::try_from_slice(&[1, 2, 3]).unwrap();
+  assert_eq!(v, [1, 2, 3]);
+  assert_eq!(v.capacity(), 8);
+  assert_eq!(StaticVec::<i32, 2>::try_from_slice(&[1, 2, 3]), Err(CapacityError::<2> {}));
+  assert_eq!(StaticVec::<i32, 0>::try_from_slice(&[]), Ok(StaticVec::new()));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn try_from_vec() {
+  let v = vec![Box::new(1), Box::new(2), Box::new(3)];
+  let sv = StaticVec::<Box<i32>, 3>::try_from_vec(v).unwrap();
+  assert_eq!(sv, [Box::new(1), Box::new(2), Box::new(3)]);
+  let v2 = vec![Box::new(1), Box::new(2)];
+  let sv2 = StaticVec::<Box<i32>, 8>::try_from_vec(v2).unwrap();
+  assert_eq!(sv2, [Box::new(1), Box::new(2)]);
+  let v3 = vec![Box::new(1), Box::new(2), Box::new(3)];
+  let v4 = StaticVec::<Box<i32>, 2>::try_from_vec(v3).unwrap_err();
+  assert_eq!(v4, [Box::new(1), Box::new(2), Box::new(3)]);
+  assert_eq!(v4.len(), 3);
+  assert!(StaticVec::<Box<i32>, 0>::try_from_vec(Vec::new()).unwrap().is_empty());
+}
+
 #[allow(unused_must_use)]
 #[test]
 fn try_insert() {
@@ -2615,6 +5070,29 @@ fn try_insert_from_slice() {
   assert!(v2.try_insert_from_slice(207, &[5, 6]).is_err());
 }
 
+#[test]
+fn try_into_array() {
+  let v = StaticVec::<i32, 8>::from([1, 2, 3]);
+  assert_eq!(v.try_into_array::<3>(), Ok([1, 2, 3]));
+  let v2 = StaticVec::<Box<i32>, 8>::from([Box::new(1), Box::new(2), Box::new(3)]);
+  let v2 = v2.try_into_array::<2>().unwrap_err();
+  assert_eq!(v2, [Box::new(1), Box::new(2), Box::new(3)]);
+  let v2 = v2.try_into_array::<8>().unwrap_err();
+  assert_eq!(v2.len(), 3);
+  assert_eq!(v2.try_into_array::<3>(), Ok([Box::new(1), Box::new(2), Box::new(3)]));
+  assert_eq!(StaticVec::<Box<i32>, 4>::new().try_into_array::<0>(), Ok([]));
+  let v3 = staticvec![ZST {}, ZST {}];
+  assert_eq!(v3.try_into_array::<2>(), Ok([ZST {}, ZST {}]));
+}
+
+#[test]
+fn try_join_into() {
+  let v = staticvec!["alpha", "beta", "gamma"];
+  assert_eq!(v.try_join_into::<16>(",").unwrap(), "alpha,beta,gamma");
+  assert_eq!(v.try_join_into::<15>(","), Err(CapacityError::<15> {}));
+  assert_eq!(v.try_join_into::<5>(","), Err(CapacityError::<5> {}));
+}
+
 #[test]
 fn try_push() {
   let mut vec = staticvec![1, 2, 3, 4, 5];
@@ -2625,6 +5103,66 @@ fn try_push() {
   assert_eq!(vec2, [1, 2, 3, 3]);
 }
 
+#[test]
+fn try_push_array() {
+  let mut v = StaticVec::<Box<i32>, 4>::new();
+  assert!(v.try_push_array([Box::new(1), Box::new(2), Box::new(3)]).is_ok());
+  let err = v.try_push_array([Box::new(4), Box::new(5)]).unwrap_err();
+  assert_eq!(err.into_value(), [Box::new(4), Box::new(5)]);
+  assert_eq!(v, [Box::new(1), Box::new(2), Box::new(3)]);
+  assert!(v.try_push_array([Box::new(4)]).is_ok());
+  assert_eq!(v, [Box::new(1), Box::new(2), Box::new(3), Box::new(4)]);
+  assert!(v.try_push_array([]).is_ok());
+  assert!(v.try_push_array([Box::new(5)]).is_err());
+}
+
+#[test]
+fn try_push_front() {
+  let mut v = StaticVec::<Box<i32>, 2>::new();
+  assert!(v.try_push_front(Box::new(1)).is_ok());
+  assert!(v.try_push_front(Box::new(2)).is_ok());
+  let err = v.try_push_front(Box::new(3)).unwrap_err();
+  assert_eq!(err.into_value(), Box::new(3));
+  assert_eq!(v, [Box::new(2), Box::new(1)]);
+  let mut v2 = StaticVec::<Box<i32>, 0>::new();
+  assert!(v2.try_push_front(Box::new(1)).is_err());
+}
+
+#[test]
+fn try_staticvec_macro() {
+  let v = try_staticvec![cap: 4; 1, 2, 3];
+  assert_eq!(v, Ok(StaticVec::<i32, 4>::from([1, 2, 3])));
+  let v2 = try_staticvec![cap: 3; Box::new(1), Box::new(2), Box::new(3),];
+  assert_eq!(v2.unwrap(), [Box::new(1), Box::new(2), Box::new(3)]);
+  let v3: Result<StaticVec<i32, 0>, _> = try_staticvec![cap: 0;];
+  assert!(v3.unwrap().is_empty());
+  let count = LifespanCounter::default();
+  let v4 = try_staticvec![
+    cap: 2;
+    count.instance(),
+    count.instance(),
+    count.instance(),
+    count.instance()
+  ];
+  assert_eq!(v4.unwrap_err(), CapacityError::<2> {});
+  // The third instance is created and dropped, and the fourth is never evaluated at all.
+  assert_eq!(count.init_count(), 3);
+  assert_eq!(count.drop_count(), 3);
+}
+
+#[test]
+fn variance() {
+  assert_eq!(staticvec![2, 4, 4, 4, 5, 5, 7, 9].variance(), Some(4.0));
+  assert_eq!(staticvec![1u8, 2, 3, 4, 5].variance(), Some(2.0));
+  assert_eq!(staticvec![1.5f32, 2.5].variance(), Some(0.25));
+  assert_eq!(staticvec![3.0, 3.0, 3.0].variance(), Some(0.0));
+  assert_eq!(staticvec![7].variance(), Some(0.0));
+  assert_eq!(StaticVec::<f64, 4>::new().variance(), None);
+  // A large constant offset shouldn't cause catastrophic cancellation.
+  let v = staticvec![1.0e9 + 4.0, 1.0e9 + 7.0, 1.0e9 + 13.0, 1.0e9 + 16.0];
+  assert!((v.variance().unwrap() - 22.5).abs() < 1.0e-6);
+}
+
 #[test]
 fn union() {
   assert_eq!(
@@ -2637,6 +5175,105 @@ fn union() {
   );
 }
 
+#[test]
+fn windows_step() {
+  let v = staticvec![1, 2, 3, 4, 5, 6, 7];
+  let mut it = v.windows_step(3, 2);
+  assert_eq!(it.len(), 3);
+  assert_eq!(it.next(), Some(&[1, 2, 3][..]));
+  assert_eq!(it.next(), Some(&[3, 4, 5][..]));
+  assert_eq!(it.next(), Some(&[5, 6, 7][..]));
+  assert_eq!(it.next(), None);
+  // The trailing `[7, 8]` here is shorter than `size`, so it should be dropped.
+  let v2 = staticvec![1, 2, 3, 4, 5, 6, 7, 8];
+  let mut it2 = v2.windows_step(3, 2);
+  assert_eq!(it2.len(), 3);
+  assert_eq!(it2.next_back(), Some(&[5, 6, 7][..]));
+  assert_eq!(it2.next(), Some(&[1, 2, 3][..]));
+  assert_eq!(it2.next_back(), Some(&[3, 4, 5][..]));
+  assert_eq!(it2.next_back(), None);
+  assert_eq!(
+    v2.windows_step(2, 3).collect::<StaticVec<&[i32], 3>>(),
+    [&[1, 2][..], &[4, 5][..], &[7, 8][..]]
+  );
+  assert_eq!(v2.windows_step(3, 1).count(), v2.windows(3).count());
+  assert_eq!(v2.windows_step(3, 2).nth(1), Some(&[3, 4, 5][..]));
+  assert_eq!(v2.windows_step(3, 2).nth(3), None);
+  assert_eq!(v2.windows_step(3, 2).last(), Some(&[5, 6, 7][..]));
+  assert_eq!(v2.windows_step(8, 100).count(), 1);
+  assert_eq!(v2.windows_step(9, 1).count(), 0);
+  assert_eq!(StaticVec::<i32, 4>::new().windows_step(1, 1).count(), 0);
+}
+
+#[test]
+#[should_panic(expected = "`StaticVec::windows_step` was called with a `size` or `step` of zero!")]
+fn windows_step_panic_a() {
+  let v = staticvec![1, 2, 3];
+  let _ = v.windows_step(0, 1);
+}
+
+#[test]
+#[should_panic(expected = "`StaticVec::windows_step` was called with a `size` or `step` of zero!")]
+fn windows_step_panic_b() {
+  let v = staticvec![1, 2, 3];
+  let _ = v.windows_step(1, 0);
+}
+
+#[test]
+fn zip_with() {
+  let a = staticvec![Box::new(1), Box::new(2), Box::new(3), Box::new(4)];
+  let b = staticvec!["a", "b", "c"];
+  let c: StaticVec<(i32, &str), 3> = a.zip_with(&b, |x, y| (**x, *y));
+  assert_eq!(c, [(1, "a"), (2, "b"), (3, "c")]);
+  let d: StaticVec<Box<i32>, 8> = b.zip_with(&a, |_, y| y.clone());
+  assert_eq!(d, [Box::new(1), Box::new(2), Box::new(3)]);
+  assert_eq!(d.capacity(), 8);
+  let e: StaticVec<i32, 0> = a.zip_with(&StaticVec::<i32, 4>::new(), |x, y| **x + *y);
+  assert_eq!(e, []);
+}
+
+#[test]
+#[should_panic]
+fn zip_with_panic() {
+  let a = staticvec![1, 2, 3];
+  let _b: StaticVec<i32, 2> = a.zip_with(&a, |x, y| *x + *y);
+}
+
+#[test]
+fn zst_reverse_consumption() {
+  let mut v = staticvec![ZST {}, ZST {}, ZST {}, ZST {}, ZST {}];
+  let mut count = 0;
+  let mut it = v.iter();
+  while let Some(_) = it.next_back() {
+    count += 1;
+  }
+  assert_eq!(count, 5);
+  assert_eq!(it.len(), 0);
+  count = 0;
+  let mut it2 = v.iter_mut();
+  while let Some(_) = it2.next_back() {
+    count += 1;
+  }
+  assert_eq!(count, 5);
+  assert_eq!(it2.len(), 0);
+  count = 0;
+  let mut it3 = v.drain_iter(1..);
+  while let Some(_) = it3.next_back() {
+    count += 1;
+  }
+  assert_eq!(count, 4);
+  drop(it3);
+  assert_eq!(v.len(), 1);
+  v.extend([ZST {}, ZST {}, ZST {}]);
+  count = 0;
+  let mut it4 = v.into_iter();
+  while let Some(_) = it4.next_back() {
+    count += 1;
+  }
+  assert_eq!(count, 4);
+  assert_eq!(it4.as_slice().len(), 0);
+}
+
 mod fmt_write_tests {
   use core::fmt::{self, Write};
   use core::str::from_utf8;